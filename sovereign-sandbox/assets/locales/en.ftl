@@ -0,0 +1,326 @@
+# The Sovereign Sandbox — English (reference catalog)
+#
+# Every other catalog translates these ids. `{ $name }` placeables are filled
+# in by the game and must be kept as written; indented lines continue the
+# message above on a new line.
+
+## Common
+
+press-any-key = Press any key to continue...
+press-any-key-bracketed = [Press any key to continue]
+list-or = { $rest }, or { $last }
+
+## Inventory
+
+inventory-header = ═ INVENTORY ═
+inventory-hint = Tools unlock as you progress
+inventory-caps-header = ═ THINKING CAPS ═
+inventory-acquired = 🎁 TOOL ACQUIRED! { $icon }
+mirror-header = 🪞 FEEDBACK MIRROR (Recent Memories)
+mirror-empty = (No memories found in sovereign store)
+
+tool-ollama-compass = Ollama Compass
+tool-ollama-compass-desc = Navigate the local AI landscape. Points the way to your current objective (B toggles the trail).
+tool-logic-lens = Logic Lens
+tool-logic-lens-desc = Translates teaching intent into game logic. The bridge between imagination and code.
+tool-feedback-mirror = Feedback Mirror
+tool-feedback-mirror-desc = Reflects student interactions back to you. The key to iterative design.
+tool-cap-phi = The Tiny Beret (Phi-3)
+tool-cap-phi-desc = Fast, lightweight, and perfect for edge devices. A smart choice for quick thoughts.
+tool-cap-llama = The Llama Top Hat (Llama-3)
+tool-cap-llama-desc = Powerful and versatile. The standard for heavy lifting and complex reasoning.
+tool-cap-mistral = The Mistral Fedora (Mistral)
+tool-cap-mistral-desc = Efficient and open. A balanced choice for the modern sovereign architect.
+
+## Quest log
+
+loading = Loading...
+game-title = The Sovereign Sandbox
+quest-log-header = ═══ QUEST LOG ═══
+quest-log-start = 🗺️ Walk to the Teacher
+quest-new-objective = NEW OBJECTIVE
+quest-complete-banner = QUEST COMPLETE
+quest-complete = ✅ Quest Complete!
+quest-goal = GOAL: { $goal }
+quest-objectives = OBJECTIVES
+
+phase-explore = EXPLORE
+phase-listen = LISTEN
+phase-do = DO
+phase-reflect = REFLECT
+phase-quiz = QUIZ
+phase-done = DONE
+phase-goal-explore = 🗺️ Walk to the { $target }
+phase-goal-complete = 🏆 Complete!
+# Exploration targets by trigger id; ids without one are shown as written
+place-teacher = Teacher
+place-archive = Archive
+place-server = Server
+
+mission-complete = 🏆  MISSION COMPLETE
+mission-complete-body = You have demonstrated mastery over local AI tools.
+    The sovereign classroom is yours to shape.
+mission-complete-return = ← Return to the E-Learning Module to continue your journey
+stat-time = ⏱  Time
+stat-xp = ⚡  XP Earned
+stat-fragments = 📜  Fragments
+stat-rooms = 🗺️  Rooms
+stat-puzzles = 🧩  Puzzles
+stat-objectives = 🎯  Objectives
+stat-objectives-value = { $mastered }/{ $total } mastered
+stat-level = ⭐  Level
+stat-level-value = Lv.{ $level } — { $title }
+
+## Scoring
+
+xp-level = ⚡ Lv.{ $level } { $title }
+rank-novice = Novice
+rank-student = Student
+rank-architect = Architect
+rank-sovereign = Sovereign
+rank-grandmaster = Grandmaster
+
+## Narration
+
+narrate-level-up = Level up! Level { $level }, { $title }
+narrate-fragment = Knowledge fragment: { $title }. { $content } Plus { $xp } XP. Press any key to continue.
+narrate-option = Option { $n } of { $count }: { $option }
+focus-hint = ▶ { $n }/{ $count }  { $option }   [{ $key }] choose
+
+## Story mode
+
+story-default-speaker = 🧙 The Gamification Architect
+story-intro = Walk to the Teacher to begin your quest...
+story-controls = [WASD] Move  [{ $interact }] Interact  [{ $talk }] Dialogue  [{ $first }-{ $last }] Choices
+story-logic-prompt = Architect! It is time to construct your first logic script. Type a natural language command below and press { $key }:
+story-thinking = Thinking
+story-logic-accepted = Excellent construction! You commanded: "{ $input }". The environment has absorbed your logic.
+story-quiz-correct = Correct! Your understanding of the sovereign grid is deepening.
+story-quiz-incorrect = Not quite, Architect. Consider the core principles again. (Try another option)
+
+## Offline teacher
+
+ai-reflect = 🪞 Reflect, Architect: { $question }
+ai-options = Options:
+ai-answer-hint = Press 1, 2, or 3 to answer.
+ai-nudge = 🧙 The path awaits, Architect. Come find me when you're ready to continue your training.
+ai-welcome = 🧙 Welcome, Architect! The sovereign classroom awaits your command. Walk up and press T to begin.
+ai-quest-welcome = 🧙 Welcome to the quest: { $quest }. Follow the objectives in your Quest Log, Architect.
+ai-idle = 🧙 The Architect awaits your next move.
+ai-recall = 📜 You said before: { $memory }
+
+## Teacher
+
+teacher-level-gate = Architect, your understanding is still novice. You must engage the Glitch Slimes in the hall to grind until you reach Level 2 before we proceed.
+teacher-default-lesson = Welcome, Architect.
+lens-scanning = SCANNING LOGIC...
+lens-context =
+    🔍 LOGIC LENS
+    QUEST: { $quest }
+    PHASE: [{ $phase }] { $label }
+    INTENT: { $framework } Protocol
+lens-searching =
+    🔍 LOGIC LENS
+    Searching for intent...
+lens-no-syllabus =
+    🔍 LOGIC LENS
+    No syllabus context found.
+
+## Title screen
+
+title-name = THE SOVEREIGN SANDBOX
+title-subtitle = An AI Academy Adventure
+title-start = [ { $key } ]  Start Game
+title-review-none = [ { $key } ]  Review — nothing due
+title-review-due = [ { $key } ]  Review — { $count } card(s) due
+title-language = [ { $key } ]  🌐 Language: { $language }
+title-controls =
+    WASD / Arrow Keys  — Move
+    [{ $interact }]  — Interact with objects
+    [{ $talk }]  — Talk to the AI Architect
+    [{ $choices }]  — Dialogue choices
+title-credits = Built with Rust & Bevy  |  The Local AI Architect  |  v0.2.0
+menu-start = Start game
+menu-review = Review cards
+menu-language = Language: { $language }
+
+## Controls HUD
+
+controls-header = ⌨ CONTROLS  (click to rebind)
+controls-press-key = Press a key (ESC cancels)
+controls-move = Move
+controls-attack = Attack / Swing
+controls-interact = Interact
+controls-talk = Talk to AI
+controls-choices = Choices
+controls-focus = Focus option
+controls-tools = Tools
+controls-breadcrumbs = Compass Trail
+controls-map = Map
+controls-spawn-slime = Spawn Slime
+controls-close = Close
+controls-pause = Pause menu
+controls-speak = Hold to speak
+controls-command = Type a command
+
+## Pause menu
+
+pause-title = ⏸ PAUSED
+pause-hint = [{ $choices }] choose   [{ $left } { $right }] adjust   [{ $back }] back
+pause-resume = Resume
+pause-back = Back
+pause-audio = Audio
+pause-display = Display
+pause-controls = Controls
+pause-accessibility = Accessibility
+pause-ai = AI backend
+pause-setting = { $name }: { $value }
+pause-on = On
+pause-off = Off
+pause-percent = { $value }%
+pause-master-volume = Master volume
+pause-music-volume = Music
+pause-sfx-volume = Sound effects
+pause-voice-volume = Voice
+pause-ambient-volume = Ambience
+pause-mute = Mute
+pause-muted = { $value } (muted)
+pause-muted-on = Muted
+pause-muted-off = Playing
+pause-window = Window
+pause-windowed = Windowed
+pause-fullscreen = Fullscreen
+pause-resizable = Resizable window
+pause-scale-with-window = Scale UI with window
+pause-rebind-waiting = { $name }: press a key (ESC cancels)
+pause-reset-bindings = Reset controls to defaults
+pause-shake = Screen shake
+pause-particles = Particles
+pause-reduced-motion = Reduced motion
+pause-text-speed = Text speed
+pause-speed-slow = Slow
+pause-speed-normal = Normal
+pause-speed-fast = Fast
+pause-speed-instant = Instant
+pause-ui-scale = UI scale
+pause-high-contrast = High contrast
+pause-dyslexia-font = Dyslexia-friendly font
+pause-narration = Read text aloud
+pause-teacher-voice = Teacher's voice
+pause-backend-lessons = Lesson text (offline)
+pause-backend-moshi = Moshi voice (local model)
+pause-not-loaded = { $name } — not loaded
+
+## World
+
+tutorial-walk = → Walk to the Teacher to begin your quest
+prompt-waiting = { $name } is waiting for you
+prompt-awaits = { $name } awaits...
+prompt-continue = Press { $key } to continue
+prompt-reflect = Press { $key } to reflect
+prompt-answer = Press { $keys } to answer
+prompt-complete = 🏆 Quest Complete!
+prompt-talk = Press { $key } to talk
+prompt-task = ⚡ Press { $key }: { $task }
+prompt-reached = 🗺️ You've reached the { $place }!
+prompt-explore = 📍 { $place } — Explore this area
+prompt-interact = Press { $key } to interact with { $place }
+room-discovered = 🗺️ { $room } Discovered! +{ $xp }XP
+quest-advanced = ⚡ Quest Advanced! +{ $xp }XP
+
+## Victory
+
+victory-title = MODULE COMPLETE
+victory-body =
+    You have successfully architected the Sovereign Sandbox.
+    The Textbook Trap has been dismantled.
+victory-gain = Pretest { $pre }% → Posttest { $post }%   Learning gain: { $gain }
+victory-gain-na = n/a
+victory-objective-gain = { "  " }{ $objective } — gain { $gain }
+victory-restart = Press [{ $key }] to Restart
+
+## Knowledge fragments
+
+fragment-header = 📜  KNOWLEDGE FRAGMENT
+
+## Terminal puzzle
+
+puzzle-title = 💻 TERMINAL PUZZLE
+puzzle-instructions =
+    Build the correct command to start your local AI.
+    Press { $keys } to select tokens in order.
+puzzle-wrong-order = ⚠ Wrong order! Try again...
+puzzle-solved = ✅ COMPILED! +{ $xp } XP
+
+## Review
+
+review-title = 🔁 REVIEW TERMINAL
+review-back = [{ $key }] Back to title
+review-forgot = Forgot
+review-hard = Hard
+review-good = Good
+review-easy = Easy
+review-answer-was = ✗ The answer was: { $answer }
+review-recalled = ✓ Recalled! +{ $xp } XP
+review-again-soon = ✗ We'll see this one again soon.
+review-feedback =
+    { $verdict }
+    Next review in { $days } day(s).
+
+    [Press anything]
+review-progress = Card { $n }/{ $count }
+review-nothing-due =
+    Nothing is due for review. Come back later!
+
+    [{ $key }] Back to title
+review-complete =
+    Review complete: { $recalled }/{ $count } recalled.
+
+    [{ $key }] Back to title
+review-reveal =
+    What did this fragment teach you?
+
+    [{ $key }] Reveal
+
+## Pre/post tests
+
+test-pre-title = 📋 PRE-ASSESSMENT
+test-post-title = 📋 POST-ASSESSMENT
+test-hint = Answer honestly — this is not graded. Press the option's number.
+test-progress = Question { $n }/{ $count }
+
+## Touch controls
+
+touch-interact = Interact
+touch-attack = Attack
+touch-talk = Talk
+touch-map = Map
+touch-confirm = OK
+touch-back = Back
+touch-review = Review
+touch-send = Send
+touch-pause = Pause
+
+## Speech input
+
+speech-listening = 🎙️ Listening…
+speech-transcribing = 🎙️ Transcribing…
+speech-heard = 🎙️ “{ $text }”
+speech-not-heard = 🎙️ Didn't catch that — try again
+speech-unavailable = 🎙️ Speaking needs a microphone and the Whisper model (download_model.sh)
+
+## Voice and typed commands
+
+command-prompt = ⌨ Command — Enter runs it, Esc closes
+command-walking = ➜ Walking to { $place }
+command-no-route = ✖ No way through to { $place }
+command-stuck = ✖ Stuck on the way — stopping
+command-no-tool = ✖ You don't have the { $tool } yet
+command-not-understood = ❓ Not a command I know: “{ $text }”
+
+## Map and levels
+
+map-title-full = 🗺️ { $zone } — { $key } to close
+module-card =
+    MODULE { $n }
+    { $title }
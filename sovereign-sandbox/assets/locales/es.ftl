@@ -0,0 +1,324 @@
+# The Sovereign Sandbox — Español
+#
+# Traducción de en.ftl. Los marcadores `{ $nombre }` los rellena el juego y
+# deben quedar tal cual; las líneas sangradas continúan el mensaje anterior.
+
+## Común
+
+press-any-key = Pulsa cualquier tecla para continuar...
+press-any-key-bracketed = [Pulsa cualquier tecla para continuar]
+list-or = { $rest } o { $last }
+
+## Inventario
+
+inventory-header = ═ INVENTARIO ═
+inventory-hint = Las herramientas se desbloquean al avanzar
+inventory-caps-header = ═ GORROS DE PENSAR ═
+inventory-acquired = 🎁 ¡HERRAMIENTA OBTENIDA! { $icon }
+mirror-header = 🪞 ESPEJO DE RETROALIMENTACIÓN (Recuerdos recientes)
+mirror-empty = (No hay recuerdos en el almacén soberano)
+
+tool-ollama-compass = Brújula Ollama
+tool-ollama-compass-desc = Recorre el paisaje de la IA local. Señala tu objetivo actual (B activa el rastro).
+tool-logic-lens = Lente Lógica
+tool-logic-lens-desc = Traduce la intención docente en lógica de juego. El puente entre la imaginación y el código.
+tool-feedback-mirror = Espejo de Retroalimentación
+tool-feedback-mirror-desc = Te devuelve las interacciones de tus estudiantes. La clave del diseño iterativo.
+tool-cap-phi = La Boina Diminuta (Phi-3)
+tool-cap-phi-desc = Rápida, ligera y perfecta para dispositivos modestos. Una buena elección para ideas rápidas.
+tool-cap-llama = La Chistera Llama (Llama-3)
+tool-cap-llama-desc = Potente y versátil. El estándar para el trabajo pesado y el razonamiento complejo.
+tool-cap-mistral = El Fedora Mistral (Mistral)
+tool-cap-mistral-desc = Eficiente y abierto. Una opción equilibrada para el arquitecto soberano moderno.
+
+## Registro de misiones
+
+loading = Cargando...
+game-title = The Sovereign Sandbox
+quest-log-header = ═══ REGISTRO DE MISIONES ═══
+quest-log-start = 🗺️ Camina hasta el Maestro
+quest-new-objective = NUEVO OBJETIVO
+quest-complete-banner = MISIÓN CUMPLIDA
+quest-complete = ✅ ¡Misión cumplida!
+quest-goal = META: { $goal }
+quest-objectives = OBJETIVOS
+
+phase-explore = EXPLORAR
+phase-listen = ESCUCHAR
+phase-do = HACER
+phase-reflect = REFLEXIONAR
+phase-quiz = PRUEBA
+phase-done = HECHO
+phase-goal-explore = 🗺️ Camina hasta { $target }
+phase-goal-complete = 🏆 ¡Completado!
+place-teacher = el Maestro
+place-archive = el Archivo
+place-server = el Servidor
+
+mission-complete = 🏆  MISIÓN COMPLETADA
+mission-complete-body = Has demostrado dominio de las herramientas de IA local.
+    El aula soberana es tuya para darle forma.
+mission-complete-return = ← Vuelve al módulo de e-learning para continuar tu viaje
+stat-time = ⏱  Tiempo
+stat-xp = ⚡  XP obtenida
+stat-fragments = 📜  Fragmentos
+stat-rooms = 🗺️  Salas
+stat-puzzles = 🧩  Acertijos
+stat-objectives = 🎯  Objetivos
+stat-objectives-value = { $mastered }/{ $total } dominados
+stat-level = ⭐  Nivel
+stat-level-value = Nv.{ $level } — { $title }
+
+## Puntuación
+
+xp-level = ⚡ Nv.{ $level } { $title }
+rank-novice = Novato
+rank-student = Estudiante
+rank-architect = Arquitecto
+rank-sovereign = Soberano
+rank-grandmaster = Gran Maestro
+
+## Narración
+
+narrate-level-up = ¡Subes de nivel! Nivel { $level }, { $title }
+narrate-fragment = Fragmento de conocimiento: { $title }. { $content } Más { $xp } XP. Pulsa cualquier tecla para continuar.
+narrate-option = Opción { $n } de { $count }: { $option }
+focus-hint = ▶ { $n }/{ $count }  { $option }   [{ $key }] elegir
+
+## Modo historia
+
+story-default-speaker = 🧙 El Arquitecto de la Gamificación
+story-intro = Camina hasta el Maestro para comenzar tu misión...
+story-controls = [WASD] Moverse  [{ $interact }] Interactuar  [{ $talk }] Diálogo  [{ $first }-{ $last }] Opciones
+story-logic-prompt = ¡Arquitecto! Es hora de construir tu primer guion lógico. Escribe una orden en lenguaje natural abajo y pulsa { $key }:
+story-thinking = Pensando
+story-logic-accepted = ¡Excelente construcción! Ordenaste: "{ $input }". El entorno ha absorbido tu lógica.
+story-quiz-correct = ¡Correcto! Tu comprensión de la red soberana se hace más profunda.
+story-quiz-incorrect = No exactamente, Arquitecto. Vuelve a pensar en los principios básicos. (Prueba otra opción)
+
+## Maestro sin conexión
+
+ai-reflect = 🪞 Reflexiona, Arquitecto: { $question }
+ai-options = Opciones:
+ai-answer-hint = Pulsa 1, 2 o 3 para responder.
+ai-nudge = 🧙 El camino te espera, Arquitecto. Ven a buscarme cuando estés listo para seguir tu entrenamiento.
+ai-welcome = 🧙 ¡Bienvenido, Arquitecto! El aula soberana espera tus órdenes. Acércate y pulsa T para empezar.
+ai-quest-welcome = 🧙 Bienvenido a la misión: { $quest }. Sigue los objetivos de tu Registro de misiones, Arquitecto.
+ai-idle = 🧙 El Arquitecto espera tu siguiente movimiento.
+ai-recall = 📜 Ya dijiste antes: { $memory }
+
+## Maestro
+
+teacher-level-gate = Arquitecto, tu comprensión aún es de novato. Debes enfrentarte a los Limos Glitch del salón y entrenar hasta alcanzar el Nivel 2 antes de continuar.
+teacher-default-lesson = Bienvenido, Arquitecto.
+lens-scanning = ANALIZANDO LA LÓGICA...
+lens-context =
+    🔍 LENTE LÓGICA
+    MISIÓN: { $quest }
+    FASE: [{ $phase }] { $label }
+    INTENCIÓN: Protocolo { $framework }
+lens-searching =
+    🔍 LENTE LÓGICA
+    Buscando la intención...
+lens-no-syllabus =
+    🔍 LENTE LÓGICA
+    No se encontró contexto del temario.
+
+## Pantalla de título
+
+title-name = THE SOVEREIGN SANDBOX
+title-subtitle = Una aventura en la Academia de IA
+title-start = [ { $key } ]  Empezar partida
+title-review-none = [ { $key } ]  Repaso — nada pendiente
+title-review-due = [ { $key } ]  Repaso — { $count } tarjeta(s) pendiente(s)
+title-language = [ { $key } ]  🌐 Idioma: { $language }
+title-controls =
+    WASD / Flechas  — Moverse
+    [{ $interact }]  — Interactuar con objetos
+    [{ $talk }]  — Hablar con el Arquitecto IA
+    [{ $choices }]  — Opciones de diálogo
+title-credits = Hecho con Rust y Bevy  |  El Arquitecto de IA Local  |  v0.2.0
+menu-start = Empezar partida
+menu-review = Repasar tarjetas
+menu-language = Idioma: { $language }
+
+## Controles
+
+controls-header = ⌨ CONTROLES  (clic para reasignar)
+controls-press-key = Pulsa una tecla (ESC cancela)
+controls-move = Moverse
+controls-attack = Atacar / Golpear
+controls-interact = Interactuar
+controls-talk = Hablar con la IA
+controls-choices = Opciones
+controls-focus = Enfocar opción
+controls-tools = Herramientas
+controls-breadcrumbs = Rastro de la brújula
+controls-map = Mapa
+controls-spawn-slime = Invocar limo
+controls-close = Cerrar
+controls-pause = Menú de pausa
+controls-speak = Mantén para hablar
+controls-command = Escribir una orden
+
+## Menú de pausa
+
+pause-title = ⏸ EN PAUSA
+pause-hint = [{ $choices }] elegir   [{ $left } { $right }] ajustar   [{ $back }] volver
+pause-resume = Continuar
+pause-back = Volver
+pause-audio = Sonido
+pause-display = Pantalla
+pause-controls = Controles
+pause-accessibility = Accesibilidad
+pause-ai = Motor de IA
+pause-setting = { $name }: { $value }
+pause-on = Sí
+pause-off = No
+pause-percent = { $value } %
+pause-master-volume = Volumen general
+pause-music-volume = Música
+pause-sfx-volume = Efectos de sonido
+pause-voice-volume = Voz
+pause-ambient-volume = Ambiente
+pause-mute = Silenciar
+pause-muted = { $value } (silenciado)
+pause-muted-on = Silenciado
+pause-muted-off = Sonando
+pause-window = Ventana
+pause-windowed = En ventana
+pause-fullscreen = Pantalla completa
+pause-resizable = Ventana redimensionable
+pause-scale-with-window = Escalar la interfaz con la ventana
+pause-rebind-waiting = { $name }: pulsa una tecla (ESC cancela)
+pause-reset-bindings = Restablecer los controles
+pause-shake = Temblor de cámara
+pause-particles = Partículas
+pause-reduced-motion = Movimiento reducido
+pause-text-speed = Velocidad del texto
+pause-speed-slow = Lenta
+pause-speed-normal = Normal
+pause-speed-fast = Rápida
+pause-speed-instant = Instantánea
+pause-ui-scale = Escala de la interfaz
+pause-high-contrast = Alto contraste
+pause-dyslexia-font = Fuente para dislexia
+pause-narration = Leer el texto en voz alta
+pause-teacher-voice = Voz del profesor
+pause-backend-lessons = Texto de la lección (sin conexión)
+pause-backend-moshi = Voz de Moshi (modelo local)
+pause-not-loaded = { $name }: no cargado
+
+## Mundo
+
+tutorial-walk = → Camina hasta el Maestro para comenzar tu misión
+prompt-waiting = { $name } te está esperando
+prompt-awaits = { $name } te espera...
+prompt-continue = Pulsa { $key } para continuar
+prompt-reflect = Pulsa { $key } para reflexionar
+prompt-answer = Pulsa { $keys } para responder
+prompt-complete = 🏆 ¡Misión completada!
+prompt-talk = Pulsa { $key } para hablar
+prompt-task = ⚡ Pulsa { $key }: { $task }
+prompt-reached = 🗺️ Destino alcanzado: { $place }
+prompt-explore = 📍 { $place } — Explora esta zona
+prompt-interact = Pulsa { $key } para interactuar con { $place }
+room-discovered = 🗺️ ¡{ $room } descubierto! +{ $xp }XP
+quest-advanced = ⚡ ¡La misión avanza! +{ $xp }XP
+
+## Victoria
+
+victory-title = MÓDULO COMPLETADO
+victory-body =
+    Has construido con éxito el Sovereign Sandbox.
+    La Trampa del Libro de Texto ha sido desmantelada.
+victory-gain = Pretest { $pre }% → Postest { $post }%   Ganancia de aprendizaje: { $gain }
+victory-gain-na = n/d
+victory-objective-gain = { "  " }{ $objective } — ganancia { $gain }
+victory-restart = Pulsa [{ $key }] para reiniciar
+
+## Fragmentos de conocimiento
+
+fragment-header = 📜  FRAGMENTO DE CONOCIMIENTO
+
+## Puzle de la terminal
+
+puzzle-title = 💻 PUZLE DE LA TERMINAL
+puzzle-instructions =
+    Construye la orden correcta para arrancar tu IA local.
+    Pulsa { $keys } para elegir las piezas en orden.
+puzzle-wrong-order = ⚠ ¡Orden incorrecto! Inténtalo de nuevo...
+puzzle-solved = ✅ ¡COMPILADO! +{ $xp } XP
+
+## Repaso
+
+review-title = 🔁 TERMINAL DE REPASO
+review-back = [{ $key }] Volver al título
+review-forgot = Olvidada
+review-hard = Difícil
+review-good = Bien
+review-easy = Fácil
+review-answer-was = ✗ La respuesta era: { $answer }
+review-recalled = ✓ ¡Recordada! +{ $xp } XP
+review-again-soon = ✗ Volveremos a ver esta pronto.
+review-feedback =
+    { $verdict }
+    Próximo repaso en { $days } día(s).
+
+    [Pulsa cualquier cosa]
+review-progress = Tarjeta { $n }/{ $count }
+review-nothing-due =
+    No hay nada pendiente de repaso. ¡Vuelve más tarde!
+
+    [{ $key }] Volver al título
+review-complete =
+    Repaso completado: { $recalled }/{ $count } recordadas.
+
+    [{ $key }] Volver al título
+review-reveal =
+    ¿Qué te enseñó este fragmento?
+
+    [{ $key }] Mostrar
+
+## Pruebas previa y posterior
+
+test-pre-title = 📋 EVALUACIÓN INICIAL
+test-post-title = 📋 EVALUACIÓN FINAL
+test-hint = Responde con sinceridad — no cuenta para la nota. Pulsa el número de la opción.
+test-progress = Pregunta { $n }/{ $count }
+
+## Controles táctiles
+
+touch-interact = Interactuar
+touch-attack = Atacar
+touch-talk = Hablar
+touch-map = Mapa
+touch-confirm = OK
+touch-back = Atrás
+touch-review = Repasar
+touch-send = Enviar
+touch-pause = Pausa
+
+## Entrada de voz
+
+speech-listening = 🎙️ Escuchando…
+speech-transcribing = 🎙️ Transcribiendo…
+speech-heard = 🎙️ «{ $text }»
+speech-not-heard = 🎙️ No te he entendido — inténtalo de nuevo
+speech-unavailable = 🎙️ Para hablar hace falta un micrófono y el modelo Whisper (download_model.sh)
+
+## Órdenes habladas y escritas
+
+command-prompt = ⌨ Orden — Intro la ejecuta, Esc la cierra
+command-walking = ➜ Caminando hacia { $place }
+command-no-route = ✖ No hay camino hasta { $place }
+command-stuck = ✖ Atascado por el camino — me detengo
+command-no-tool = ✖ Aún no tienes { $tool }
+command-not-understood = ❓ No conozco esa orden: «{ $text }»
+
+## Mapa y niveles
+
+map-title-full = 🗺️ { $zone } — { $key } para cerrar
+module-card =
+    MÓDULO { $n }
+    { $title }
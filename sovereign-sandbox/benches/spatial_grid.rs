@@ -0,0 +1,111 @@
+// Spatial grid benchmark — `cargo bench --bench spatial_grid`
+//
+// Compares one frame of collision work done the old way (every mover against
+// every wall collider, every attack against every slime) with the tile grid
+// and spatial hash in `src/spatial.rs`, at up to 10k wall tiles and 500
+// dynamic entities.
+
+use bevy::math::{IVec2, Vec2};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+#[path = "../src/spatial.rs"]
+#[allow(dead_code)]
+mod spatial;
+
+use spatial::{SpatialHash, TileGrid};
+
+const TILE: f32 = 64.0;
+const PLAYER_HALF: Vec2 = Vec2::new(16.0, 16.0);
+const HITBOX_RADIUS: f32 = 24.0;
+
+/// Hollow rooms laid out on a square grid until `count` wall tiles exist
+fn wall_tiles(count: usize) -> Vec<IVec2> {
+    let mut tiles = Vec::with_capacity(count);
+    let room = 12;
+    let mut index = 0;
+    while tiles.len() < count {
+        let side = (count as f32 / (room * 4) as f32).sqrt().ceil() as i32;
+        let origin = IVec2::new(index % side, index / side) * (room + 1);
+        for i in 0..room {
+            for tile in [
+                IVec2::new(i, 0),
+                IVec2::new(i, room - 1),
+                IVec2::new(0, i),
+                IVec2::new(room - 1, i),
+            ] {
+                if tiles.len() < count {
+                    tiles.push(origin + tile);
+                }
+            }
+        }
+        index += 1;
+    }
+    tiles
+}
+
+fn positions(rng: &mut StdRng, count: usize, extent: f32) -> Vec<Vec2> {
+    (0..count)
+        .map(|_| Vec2::new(rng.gen_range(0.0..extent), rng.gen_range(0.0..extent)))
+        .collect()
+}
+
+/// The pre-grid movement test: AABB against every wall collider
+fn naive_blocked(walls: &[Vec2], pos: Vec2) -> bool {
+    let half_tile = TILE / 2.0;
+    walls.iter().any(|wall| {
+        (pos.x - wall.x).abs() < PLAYER_HALF.x + half_tile
+            && (pos.y - wall.y).abs() < PLAYER_HALF.y + half_tile
+    })
+}
+
+/// The pre-broadphase hit test: every attack against every target
+fn naive_hits(attacks: &[Vec2], targets: &[Vec2]) -> usize {
+    attacks.iter()
+        .map(|a| targets.iter().filter(|t| a.distance(**t) < HITBOX_RADIUS * 2.0).count())
+        .sum()
+}
+
+fn bench_frame(c: &mut Criterion) {
+    let mut group = c.benchmark_group("frame");
+    let mut rng = StdRng::seed_from_u64(33);
+
+    for wall_count in [1_000, 10_000] {
+        let tiles = wall_tiles(wall_count);
+        let walls: Vec<Vec2> = tiles.iter().map(|t| t.as_vec2() * TILE).collect();
+        let grid = TileGrid::from_tiles(tiles.iter().copied(), TILE);
+        assert_eq!(grid.solid_count(), wall_count);
+        let extent = walls.iter().fold(0.0f32, |m, w| m.max(w.x).max(w.y));
+
+        for entity_count in [100, 500] {
+            let movers = positions(&mut rng, entity_count, extent);
+            let attacks = positions(&mut rng, entity_count / 10, extent);
+            let label = format!("{wall_count}w/{entity_count}e");
+
+            group.bench_with_input(BenchmarkId::new("naive", &label), &(), |b, _| {
+                b.iter(|| {
+                    let blocked = movers.iter().filter(|p| naive_blocked(&walls, **p)).count();
+                    black_box(blocked + naive_hits(&attacks, &movers))
+                })
+            });
+
+            group.bench_with_input(BenchmarkId::new("grid", &label), &(), |b, _| {
+                let mut hash = SpatialHash::new(128.0);
+                b.iter(|| {
+                    // Rebuilt every frame, as in the game
+                    hash.clear();
+                    for (i, p) in movers.iter().enumerate() {
+                        hash.insert(i, *p, HITBOX_RADIUS);
+                    }
+                    let blocked = movers.iter().filter(|p| grid.overlaps(**p, PLAYER_HALF)).count();
+                    let hits: usize = attacks.iter().map(|a| hash.query(*a, HITBOX_RADIUS).len()).sum();
+                    black_box(blocked + hits)
+                })
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_frame);
+criterion_main!(benches);
@@ -1,6 +1,7 @@
 #!/bin/bash
 mkdir -p assets/models
 
+# Also counts prompt tokens for the teacher's conversation memory
 echo "Downloading Tokenizer..."
 curl -L -o assets/models/tokenizer.json https://huggingface.co/microsoft/Phi-3-mini-4k-instruct/resolve/main/tokenizer.json
 
@@ -21,4 +22,14 @@ curl -L -o assets/models/melfilters.bytes https://raw.githubusercontent.com/hugg
 curl -L -o assets/models/whisper_tokenizer.json https://huggingface.co/openai/whisper-tiny/resolve/main/tokenizer.json
 curl -L -o assets/jfk.wav https://huggingface.co/datasets/Narsil/asr_dummy/resolve/main/jfk.wav
 
+echo "Downloading Moshi (Q8 GGUF + Mimi codec) for the moshi feature..."
+mkdir -p assets/models/moshi
+curl -L -o assets/models/moshi/model.q8.gguf https://huggingface.co/kyutai/moshiko-candle-q8/resolve/main/model.q8.gguf
+curl -L -o assets/models/moshi/tokenizer-e351c8d8-checkpoint125.safetensors https://huggingface.co/kyutai/moshiko-candle-q8/resolve/main/tokenizer-e351c8d8-checkpoint125.safetensors
+if [ -f ../moshi/tokenizer_spm_32k_3.model ]; then
+    cp ../moshi/tokenizer_spm_32k_3.model assets/models/moshi/
+else
+    curl -L -o assets/models/moshi/tokenizer_spm_32k_3.model https://huggingface.co/kyutai/moshiko-candle-q8/resolve/main/tokenizer_spm_32k_3.model
+fi
+
 echo "Download complete."
@@ -0,0 +1,60 @@
+// SFX preset renderer — `cargo run --example render_sfx -- [options] [preset...]`
+//
+// Renders the presets in `assets/audio/sfx.toml` (or only the named ones) to
+// WAV files, so a sound can be tuned and listened to without starting the
+// game. The preset file is read when this runs, not built in.
+//
+//   --presets <file>   preset table to read (assets/audio/sfx.toml)
+//   --out <dir>        where the WAVs go (target/sfx)
+//   --seed <n>         noise seed (0)
+
+#[path = "../src/sfx_synth.rs"]
+#[allow(dead_code)]
+mod sfx_synth;
+
+use anyhow::{bail, Context, Result};
+use std::path::PathBuf;
+
+fn main() -> Result<()> {
+    let mut presets_path = PathBuf::from("assets/audio/sfx.toml");
+    let mut out = PathBuf::from("target/sfx");
+    let mut seed = 0;
+    let mut names = Vec::new();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--presets" => presets_path = args.next().context("--presets needs a file")?.into(),
+            "--out" => out = args.next().context("--out needs a directory")?.into(),
+            "--seed" => seed = args.next().context("--seed needs a number")?.parse()?,
+            flag if flag.starts_with("--") => bail!("unknown option {}", flag),
+            _ => names.push(arg),
+        }
+    }
+
+    let toml = std::fs::read_to_string(&presets_path)
+        .with_context(|| format!("reading {}", presets_path.display()))?;
+    let presets = sfx_synth::parse_presets(&toml)
+        .with_context(|| format!("parsing {}", presets_path.display()))?;
+    if let Some(missing) = names.iter().find(|name| !presets.contains_key(*name)) {
+        bail!("no preset '{}' (have: {})", missing, presets.keys().cloned().collect::<Vec<_>>().join(", "));
+    }
+    std::fs::create_dir_all(&out)?;
+
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: sfx_synth::SAMPLE_RATE,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    for (name, params) in presets.iter().filter(|(name, _)| names.is_empty() || names.contains(name)) {
+        let path = out.join(format!("{}.wav", name));
+        let mut writer = hound::WavWriter::create(&path, spec)?;
+        for sample in sfx_synth::render(params, seed) {
+            writer.write_sample(sfx_synth::to_i16(sample))?;
+        }
+        writer.finalize()?;
+        println!("🔊 {} ({:.2}s) -> {}", name, params.duration(), path.display());
+    }
+    Ok(())
+}
@@ -0,0 +1,343 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use serde::{Deserialize, Serialize};
+use std::marker::PhantomData;
+
+// ============================================================================
+// Accessibility — motion, text speed, contrast and font settings
+// ============================================================================
+// One resource every effect reads: shake and particle bursts scale by it,
+// pulsing glows hold still under reduced motion, and the typewriter takes its
+// speed from it. UI scale, the high-contrast palette and the dyslexia-friendly
+// font are applied here to every text and panel as it spawns, so spawn sites
+// keep their own colours and sizes. Any change to the resource is saved, to
+// `SETTINGS_PATH` natively and to localStorage in the browser.
+
+const SETTINGS_PATH: &str = "assets/memory/accessibility.json";
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY: &str = "sovereign-sandbox.accessibility";
+
+/// Used when `dyslexia_font` is on: DejaVu Sans, a wide, open sans with a
+/// tall x-height in the Verdana mould that dyslexia style guides recommend.
+/// Falls back to `FALLBACK_FONT` if it won't load. Licences sit beside both.
+const DYSLEXIA_FONT: &str = "fonts/DejaVuSans.ttf";
+const FALLBACK_FONT: &str = "fonts/Inter-Regular.ttf";
+
+pub const UI_SCALE_RANGE: (f32, f32) = (0.75, 2.0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum TextSpeed {
+    Slow,
+    #[default]
+    Normal,
+    Fast,
+    /// Whole lines appear at once
+    Instant,
+}
+
+impl TextSpeed {
+    /// Seconds per revealed character, `None` for instant
+    pub fn delay(&self) -> Option<f32> {
+        match self {
+            TextSpeed::Slow => Some(0.06),
+            TextSpeed::Normal => Some(0.03),
+            TextSpeed::Fast => Some(0.012),
+            TextSpeed::Instant => None,
+        }
+    }
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AccessibilitySettings {
+    /// Camera shake multiplier; 0 turns shake off
+    pub shake: f32,
+    /// Fraction of each particle burst that spawns; 0 turns bursts off
+    pub particles: f32,
+    /// Holds pulsing glows and prompts steady
+    pub reduced_motion: bool,
+    pub text_speed: TextSpeed,
+    /// Multiplies every UI size, text included
+    pub ui_scale: f32,
+    pub high_contrast: bool,
+    pub dyslexia_font: bool,
+    /// Reads game text aloud with the system speech engine (native only;
+    /// the browser build always feeds the page's screen reader)
+    pub narration: bool,
+    /// Speaks the teacher's answers in its own voice (see `teacher_voice`)
+    pub teacher_voice: bool,
+}
+
+impl Default for AccessibilitySettings {
+    fn default() -> Self {
+        Self {
+            shake: 1.0,
+            particles: 1.0,
+            reduced_motion: false,
+            text_speed: TextSpeed::Normal,
+            ui_scale: 1.0,
+            high_contrast: false,
+            dyslexia_font: false,
+            narration: false,
+            teacher_voice: true,
+        }
+    }
+}
+
+impl AccessibilitySettings {
+    /// Trauma to add for an effect authored at `intensity`
+    pub fn shake(&self, intensity: f32) -> f32 {
+        intensity * self.shake.clamp(0.0, 1.0)
+    }
+
+    /// Particles to spawn for a burst authored with `count`
+    pub fn particle_count(&self, count: usize) -> usize {
+        (count as f32 * self.particles.clamp(0.0, 1.0)).round() as usize
+    }
+
+    /// 0..1 pulse at `speed`, or a steady 1 under reduced motion
+    pub fn pulse(&self, elapsed: f32, speed: f32) -> f32 {
+        if self.reduced_motion { 1.0 } else { (ops::sin(elapsed * speed) + 1.0) / 2.0 }
+    }
+
+    fn sanitized(mut self) -> Self {
+        self.shake = self.shake.clamp(0.0, 1.0);
+        self.particles = self.particles.clamp(0.0, 1.0);
+        self.ui_scale = self.ui_scale.clamp(UI_SCALE_RANGE.0, UI_SCALE_RANGE.1);
+        self
+    }
+
+    fn from_json(json: &str) -> Self {
+        match serde_json::from_str::<Self>(json) {
+            Ok(settings) => settings.sanitized(),
+            Err(e) => {
+                warn!("⚠️ Accessibility settings unreadable ({}), using defaults", e);
+                Self::default()
+            }
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load() -> Self {
+        std::fs::read_to_string(SETTINGS_PATH).map(|json| Self::from_json(&json)).unwrap_or_default()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn load() -> Self {
+        web_sys::window()
+            .and_then(|w| w.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(STORAGE_KEY).ok().flatten())
+            .map(|json| Self::from_json(&json))
+            .unwrap_or_default()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self) {
+        let path = std::path::Path::new(SETTINGS_PATH);
+        let result = serde_json::to_string_pretty(self)
+            .map_err(std::io::Error::other)
+            .and_then(|json| {
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                std::fs::write(path, json)
+            });
+        if let Err(e) = result {
+            error!("Failed to save accessibility settings: {}", e);
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn save(&self) {
+        let Ok(json) = serde_json::to_string(self) else { return };
+        let storage = web_sys::window().and_then(|w| w.local_storage().ok().flatten());
+        if storage.is_none_or(|s| s.set_item(STORAGE_KEY, &json).is_err()) {
+            error!("Failed to save accessibility settings to localStorage");
+        }
+    }
+}
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub struct AccessibilityPlugin;
+
+impl Plugin for AccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        let settings = AccessibilitySettings::load();
+        info!("♿ Accessibility: {:?}", settings);
+        app.insert_resource(settings)
+           .init_resource::<Fonts>()
+           .add_systems(PostUpdate, (
+               save_settings,
+               apply_ui_scale,
+               apply_font,
+               apply_palette::<TextColor>,
+               apply_palette::<BackgroundColor>,
+               apply_palette::<BorderColor>,
+           ).before(bevy::ui::UiSystem::Prepare));
+    }
+}
+
+/// Whatever changes the settings, they're written back
+fn save_settings(settings: Res<AccessibilitySettings>) {
+    if settings.is_changed() && !settings.is_added() {
+        settings.save();
+    }
+}
+
+/// The chosen scale, times the window's size against the layout when the
+/// display settings scale the UI with the window
+fn apply_ui_scale(
+    settings: Res<AccessibilitySettings>,
+    game_settings: Res<crate::settings::GameSettings>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut ui_scale: ResMut<UiScale>,
+) {
+    let wanted = settings.ui_scale * game_settings.display.ui_factor(windows.get_single().ok());
+    if ui_scale.0 != wanted {
+        ui_scale.0 = wanted;
+    }
+}
+
+// ============================================================================
+// Font
+// ============================================================================
+
+/// Loaded the first time the dyslexia font is turned on
+#[derive(Resource, Default)]
+struct Fonts {
+    dyslexia: Handle<Font>,
+    fallback: Handle<Font>,
+}
+
+/// Puts every text in the chosen font, as it spawns and when the setting flips
+fn apply_font(
+    settings: Res<AccessibilitySettings>,
+    mut fonts: ResMut<Fonts>,
+    asset_server: Res<AssetServer>,
+    mut texts: Query<&mut TextFont>,
+    mut chosen: Local<Option<Handle<Font>>>,
+) {
+    if settings.dyslexia_font && fonts.dyslexia == Handle::default() {
+        fonts.dyslexia = asset_server.load(DYSLEXIA_FONT);
+        fonts.fallback = asset_server.load(FALLBACK_FONT);
+    }
+    let wanted = if !settings.dyslexia_font {
+        Handle::default()
+    } else if asset_server.load_state(&fonts.dyslexia).is_failed() {
+        fonts.fallback.clone()
+    } else {
+        fonts.dyslexia.clone()
+    };
+    let switched = chosen.as_ref() != Some(&wanted);
+    if switched && settings.dyslexia_font && wanted == fonts.fallback {
+        warn!("⚠️ {} not found, using {}", DYSLEXIA_FONT, FALLBACK_FONT);
+    }
+    *chosen = Some(wanted.clone());
+
+    for mut font in &mut texts {
+        if (switched || font.is_added()) && font.font != wanted {
+            font.font = wanted.clone();
+        }
+    }
+}
+
+// ============================================================================
+// High-Contrast Palette
+// ============================================================================
+
+/// A colour component the palette can rewrite
+pub trait Recolor: Component {
+    fn color(&self) -> Color;
+    fn set_color(&mut self, color: Color);
+    /// The high-contrast version of `color` for this kind of component
+    fn contrast(color: Color) -> Color;
+}
+
+impl Recolor for TextColor {
+    fn color(&self) -> Color { self.0 }
+    fn set_color(&mut self, color: Color) { self.0 = color; }
+
+    /// Text goes bright: greys to white, colours to their lightest readable tone
+    fn contrast(color: Color) -> Color {
+        let hsla = Hsla::from(color);
+        if hsla.saturation < 0.15 {
+            return Color::Hsla(Hsla { lightness: 1.0, ..hsla });
+        }
+        Color::Hsla(Hsla { lightness: hsla.lightness.max(0.7), saturation: 1.0, ..hsla })
+    }
+}
+
+impl Recolor for BackgroundColor {
+    fn color(&self) -> Color { self.0 }
+    fn set_color(&mut self, color: Color) { self.0 = color; }
+
+    /// Dark panels go near-black and opaque so text stands off them
+    fn contrast(color: Color) -> Color {
+        let hsla = Hsla::from(color);
+        if hsla.alpha == 0.0 || hsla.lightness > 0.35 {
+            return color;
+        }
+        Color::Hsla(Hsla { lightness: hsla.lightness.min(0.04), alpha: hsla.alpha.max(0.97), ..hsla })
+    }
+}
+
+impl Recolor for BorderColor {
+    fn color(&self) -> Color { self.0 }
+    fn set_color(&mut self, color: Color) { self.0 = color; }
+
+    fn contrast(color: Color) -> Color {
+        let hsla = Hsla::from(color);
+        if hsla.alpha == 0.0 {
+            return color;
+        }
+        Color::Hsla(Hsla { lightness: hsla.lightness.max(0.75), alpha: 1.0, ..hsla })
+    }
+}
+
+/// The colour a spawn site or system asked for, before the palette
+#[derive(Component)]
+struct BaseColor<C: Recolor> {
+    color: Color,
+    _marker: PhantomData<C>,
+}
+
+/// Remembers each colour as it's set and shows its high-contrast version
+/// while the setting is on. Systems animating colours keep working: their
+/// writes become the new base.
+fn apply_palette<C: Recolor>(
+    mut commands: Commands,
+    settings: Res<AccessibilitySettings>,
+    mut query: Query<(Entity, &mut C, Option<&mut BaseColor<C>>)>,
+) {
+    let toggled = settings.is_changed();
+    for (entity, mut component, base) in &mut query {
+        let current = component.color();
+        let base_color = match base {
+            // Our own write from last time
+            Some(ref base) if !component.is_changed() || current == shown::<C>(&settings, base.color) => {
+                if !toggled { continue; }
+                base.color
+            }
+            Some(mut base) => {
+                base.color = current;
+                current
+            }
+            None => {
+                commands.entity(entity).insert(BaseColor::<C> { color: current, _marker: PhantomData });
+                current
+            }
+        };
+        let wanted = shown::<C>(&settings, base_color);
+        if wanted != current {
+            component.set_color(wanted);
+        }
+    }
+}
+
+fn shown<C: Recolor>(settings: &AccessibilitySettings, color: Color) -> Color {
+    if settings.high_contrast { C::contrast(color) } else { color }
+}
@@ -0,0 +1,840 @@
+use bevy::ecs::system::SystemParam;
+use bevy::input::InputSystem;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use crate::{GameState, PauseState};
+use crate::i18n::{localized, Locale};
+use crate::inventory::ToolId;
+
+// ============================================================================
+// Input Actions — one remappable action map for keyboard and gamepad
+// ============================================================================
+// Systems read `Res<Actions>` instead of raw keys. Each frame the active
+// `InputContext` decides who gets input: only the context on top (a test, the
+// puzzle, a scripted conversation, a quiz...) receives `Choice(n)`, and
+// inputs bound to choices don't also move the player while it does. Prompts
+// and the controls HUD show the binding for whichever device was used last.
+// Clicking a row of the controls HUD rebinds it; remaps persist to
+// `BINDINGS_PATH`. On-screen controls (see `touch`) feed `VirtualInput`,
+// which goes through the same context rules as keys and buttons; so do
+// spoken and typed commands (see `voice_commands`).
+// While options are on offer the arrow keys move a focus between them and
+// Confirm picks the focused one; WASD still walks.
+// Pause opens the pause menu (see `ui::pause_menu`); while it's up the world
+// gets no input, and left/right adjust the focused setting instead of walking.
+
+const BINDINGS_PATH: &str = "assets/memory/bindings.json";
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY: &str = "sovereign-sandbox.bindings";
+
+/// Choice slots; keyboard 1-9
+pub const MAX_CHOICES: u8 = 9;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Action {
+    MoveUp,
+    MoveDown,
+    MoveLeft,
+    MoveRight,
+    Interact,
+    Attack,
+    /// Talk to the AI / advance a scripted conversation
+    Talk,
+    Confirm,
+    Cancel,
+    /// Option `n` (0-based) in whichever context is taking choices
+    Choice(u8),
+    /// Move the focus between offered options
+    FocusNext,
+    FocusPrev,
+    ToggleTool(ToolId),
+    Breadcrumbs,
+    Map,
+    Review,
+    SpawnSlime,
+    /// Open or close the pause menu
+    Pause,
+    /// Hold to talk (see `speech`)
+    Speak,
+    /// Open the command line (see `voice_commands`)
+    Command,
+}
+
+/// Who is receiving input this frame, highest priority first
+#[derive(Resource, Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InputContext {
+    /// Waiting for a key to rebind; no actions fire
+    Rebinding,
+    /// Typing into the prompt; only Confirm, Cancel and Speak fire
+    TextEntry,
+    /// The pause menu; only choosing, Confirm, Cancel, Pause and left/right fire
+    Pause,
+    Popup,
+    Test,
+    Review,
+    Puzzle,
+    Dialogue,
+    Quiz,
+    #[default]
+    World,
+    Menu,
+}
+
+impl Action {
+    /// Bound to picking among offered options
+    fn is_choosing(&self) -> bool {
+        matches!(self, Action::Choice(_) | Action::FocusNext | Action::FocusPrev)
+    }
+}
+
+impl InputContext {
+    pub fn takes_choices(&self) -> bool {
+        matches!(self, InputContext::Pause | InputContext::Test | InputContext::Review | InputContext::Puzzle
+            | InputContext::Dialogue | InputContext::Quiz | InputContext::Menu)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Device {
+    #[default]
+    Keyboard,
+    Gamepad,
+    Touch,
+}
+
+#[derive(Debug, Clone)]
+pub struct Binding {
+    pub action: Action,
+    pub keys: Vec<KeyCode>,
+    pub buttons: Vec<GamepadButton>,
+}
+
+impl Binding {
+    fn new(action: Action, keys: &[KeyCode], buttons: &[GamepadButton]) -> Self {
+        Self { action, keys: keys.to_vec(), buttons: buttons.to_vec() }
+    }
+}
+
+/// Bindings plus this frame's resolved action state
+#[derive(Resource)]
+pub struct Actions {
+    bindings: Vec<Binding>,
+    pressed: HashSet<Action>,
+    just_pressed: HashSet<Action>,
+    movement: Vec2,
+    any_just_pressed: bool,
+    context: InputContext,
+    device: Device,
+    /// Offered option the arrow keys are on
+    focus: Option<usize>,
+    /// On-screen button names in the current language, for touch labels
+    touch_names: HashMap<Action, String>,
+}
+
+/// Actions held by on-screen controls and speech, filled each frame in
+/// `VirtualInputSet`
+#[derive(Resource, Default)]
+pub struct VirtualInput {
+    pub pressed: HashSet<Action>,
+    pub just_pressed: HashSet<Action>,
+    /// Virtual stick, y up, at most unit length
+    pub stick: Vec2,
+    /// A touch started this frame
+    pub touched: bool,
+}
+
+/// The options the context taking choices has on screen, in order
+#[derive(Resource, Default)]
+pub struct OfferedChoices(pub Vec<String>);
+
+/// Systems writing `VirtualInput` run in this set, before actions resolve
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct VirtualInputSet;
+
+/// The action whose binding is being captured, if any
+#[derive(Resource, Default)]
+pub struct Rebinding(pub Option<Action>);
+
+// ============================================================================
+// Default Bindings
+// ============================================================================
+
+const CHOICE_KEYS: [KeyCode; MAX_CHOICES as usize] = [
+    KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3,
+    KeyCode::Digit4, KeyCode::Digit5, KeyCode::Digit6,
+    KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
+];
+
+/// The D-pad picks the first four choices while a context takes them
+const CHOICE_BUTTONS: [GamepadButton; 4] = [
+    GamepadButton::DPadUp, GamepadButton::DPadRight, GamepadButton::DPadDown, GamepadButton::DPadLeft,
+];
+
+fn default_bindings() -> Vec<Binding> {
+    use GamepadButton as B;
+    let mut bindings = vec![
+        Binding::new(Action::MoveUp, &[KeyCode::KeyW, KeyCode::ArrowUp], &[B::DPadUp]),
+        Binding::new(Action::MoveDown, &[KeyCode::KeyS, KeyCode::ArrowDown], &[B::DPadDown]),
+        Binding::new(Action::MoveLeft, &[KeyCode::KeyA, KeyCode::ArrowLeft], &[B::DPadLeft]),
+        Binding::new(Action::MoveRight, &[KeyCode::KeyD, KeyCode::ArrowRight], &[B::DPadRight]),
+        Binding::new(Action::Interact, &[KeyCode::KeyT], &[B::North]),
+        Binding::new(Action::Attack, &[KeyCode::KeyF], &[B::West]),
+        Binding::new(Action::Talk, &[KeyCode::Space], &[B::South]),
+        Binding::new(Action::Confirm, &[KeyCode::Enter], &[B::South, B::Start]),
+        Binding::new(Action::Cancel, &[KeyCode::Escape], &[B::East]),
+        Binding::new(Action::ToggleTool(ToolId::OllamaCompass), &[KeyCode::KeyC], &[B::LeftTrigger]),
+        Binding::new(Action::ToggleTool(ToolId::LogicLens), &[KeyCode::KeyL], &[B::RightTrigger]),
+        Binding::new(Action::ToggleTool(ToolId::FeedbackMirror), &[KeyCode::KeyM], &[B::LeftTrigger2]),
+        Binding::new(Action::Breadcrumbs, &[KeyCode::KeyB], &[B::RightThumb]),
+        Binding::new(Action::Map, &[KeyCode::Tab], &[B::Select]),
+        Binding::new(Action::Review, &[KeyCode::KeyR], &[B::North]),
+        Binding::new(Action::SpawnSlime, &[KeyCode::KeyG], &[]),
+        Binding::new(Action::Pause, &[KeyCode::Escape, KeyCode::KeyP], &[B::Start]),
+        Binding::new(Action::Speak, &[KeyCode::F2], &[B::LeftThumb]),
+        Binding::new(Action::Command, &[KeyCode::Slash], &[]),
+        Binding::new(Action::FocusNext, &[KeyCode::ArrowDown], &[]),
+        Binding::new(Action::FocusPrev, &[KeyCode::ArrowUp], &[]),
+    ];
+    for n in 0..MAX_CHOICES {
+        let buttons = CHOICE_BUTTONS.get(n as usize).map(std::slice::from_ref).unwrap_or_default();
+        bindings.push(Binding::new(Action::Choice(n), &[CHOICE_KEYS[n as usize]], buttons));
+    }
+    bindings
+}
+
+// ============================================================================
+// Actions API
+// ============================================================================
+
+impl Actions {
+    pub fn pressed(&self, action: Action) -> bool {
+        self.pressed.contains(&action)
+    }
+
+    pub fn just_pressed(&self, action: Action) -> bool {
+        self.just_pressed.contains(&action)
+    }
+
+    /// Keyboard and left stick, at most unit length
+    pub fn movement(&self) -> Vec2 {
+        self.movement
+    }
+
+    /// Any key or button at all, for "press anything" screens
+    pub fn any_just_pressed(&self) -> bool {
+        self.any_just_pressed
+    }
+
+    /// The option picked this frame, if `context` is the one taking choices
+    pub fn choice(&self, context: InputContext) -> Option<usize> {
+        if self.context != context { return None; }
+        (0..MAX_CHOICES).find(|n| self.just_pressed(Action::Choice(*n))).map(usize::from)
+    }
+
+    /// The offered option with keyboard focus
+    pub fn focused(&self) -> Option<usize> {
+        self.focus
+    }
+
+    /// The device used last
+    pub fn device(&self) -> Device {
+        self.device
+    }
+
+    pub fn set_touch_names(&mut self, names: HashMap<Action, String>) {
+        self.touch_names = names;
+    }
+
+    /// The first binding for the device used last, e.g. "T" or "Y"
+    pub fn label(&self, action: Action) -> String {
+        let Some(binding) = self.bindings.iter().find(|b| b.action == action) else { return "?".to_string() };
+        let key = binding.keys.first().map(|k| key_label(*k));
+        let button = binding.buttons.first().map(|b| button_label(*b));
+        match self.device {
+            Device::Gamepad => button.or(key),
+            Device::Keyboard => key.or(button),
+            Device::Touch => self.touch_names.get(&action).cloned().or(key).or(button),
+        }
+        .unwrap_or_else(|| "—".to_string())
+    }
+
+    /// "1, 2, or 3" for the first `count` choices
+    pub fn choices_label(&self, count: usize, locale: &Locale) -> String {
+        let labels: Vec<String> = (0..count.min(MAX_CHOICES as usize) as u8)
+            .map(|n| self.label(Action::Choice(n)))
+            .collect();
+        match labels.as_slice() {
+            [] => String::new(),
+            [one] => one.clone(),
+            [rest @ .., last] => locale.fmt("list-or", &[("rest", &rest.join(", ")), ("last", last)]),
+        }
+    }
+
+    /// Replaces the binding for one device, keeping the other
+    pub fn rebind_key(&mut self, action: Action, key: KeyCode) {
+        if let Some(binding) = self.bindings.iter_mut().find(|b| b.action == action) {
+            binding.keys = vec![key];
+        }
+    }
+
+    pub fn rebind_button(&mut self, action: Action, button: GamepadButton) {
+        if let Some(binding) = self.bindings.iter_mut().find(|b| b.action == action) {
+            binding.buttons = vec![button];
+        }
+    }
+
+    /// Back to the default bindings, saved
+    pub fn reset_bindings(&mut self) {
+        self.bindings = default_bindings();
+        self.save();
+        info!("🎮 Bindings reset to defaults");
+    }
+}
+
+// ============================================================================
+// Persistence
+// ============================================================================
+// Saved by name, so the file is readable and survives enum reordering.
+// Actions missing from the file keep their defaults. The browser build keeps
+// the same JSON in localStorage.
+
+#[derive(Serialize, Deserialize)]
+struct SavedBinding {
+    action: Action,
+    keys: Vec<String>,
+    buttons: Vec<String>,
+}
+
+impl Actions {
+    fn new(bindings: Vec<Binding>) -> Self {
+        Self {
+            bindings,
+            pressed: HashSet::new(),
+            just_pressed: HashSet::new(),
+            movement: Vec2::ZERO,
+            any_just_pressed: false,
+            context: InputContext::default(),
+            device: Device::default(),
+            focus: None,
+            touch_names: HashMap::new(),
+        }
+    }
+
+    fn apply_saved(&mut self, saved: Vec<SavedBinding>) {
+        for entry in saved {
+            let Some(binding) = self.bindings.iter_mut().find(|b| b.action == entry.action) else { continue };
+            binding.keys = entry.keys.iter().filter_map(|k| key_from_name(k)).collect();
+            binding.buttons = entry.buttons.iter().filter_map(|b| button_from_name(b)).collect();
+        }
+    }
+
+    fn saved(&self) -> Vec<SavedBinding> {
+        self.bindings.iter().map(|b| SavedBinding {
+            action: b.action,
+            keys: b.keys.iter().map(|k| format!("{:?}", k)).collect(),
+            buttons: b.buttons.iter().map(|b| format!("{:?}", b)).collect(),
+        }).collect()
+    }
+
+    fn load() -> Self {
+        let mut actions = Self::new(default_bindings());
+        if let Some(json) = Self::read_saved() {
+            match serde_json::from_str(&json) {
+                Ok(saved) => actions.apply_saved(saved),
+                Err(e) => warn!("⚠️ Bindings unreadable ({}), using defaults", e),
+            }
+        }
+        actions
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn read_saved() -> Option<String> {
+        std::fs::read_to_string(BINDINGS_PATH).ok()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn read_saved() -> Option<String> {
+        web_sys::window()
+            .and_then(|w| w.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(STORAGE_KEY).ok().flatten())
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn save(&self) {
+        let path = std::path::Path::new(BINDINGS_PATH);
+        let result = serde_json::to_string_pretty(&self.saved())
+            .map_err(std::io::Error::other)
+            .and_then(|json| {
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                std::fs::write(path, json)
+            });
+        if let Err(e) = result {
+            error!("Failed to save bindings: {}", e);
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn save(&self) {
+        let Ok(json) = serde_json::to_string(&self.saved()) else { return };
+        let storage = web_sys::window().and_then(|w| w.local_storage().ok().flatten());
+        if storage.is_none_or(|s| s.set_item(STORAGE_KEY, &json).is_err()) {
+            error!("Failed to save bindings to localStorage");
+        }
+    }
+}
+
+/// Keys that can be bound, for reading names back from the bindings file
+const BINDABLE_KEYS: [KeyCode; 60] = [
+    KeyCode::KeyA, KeyCode::KeyB, KeyCode::KeyC, KeyCode::KeyD, KeyCode::KeyE, KeyCode::KeyF,
+    KeyCode::KeyG, KeyCode::KeyH, KeyCode::KeyI, KeyCode::KeyJ, KeyCode::KeyK, KeyCode::KeyL,
+    KeyCode::KeyM, KeyCode::KeyN, KeyCode::KeyO, KeyCode::KeyP, KeyCode::KeyQ, KeyCode::KeyR,
+    KeyCode::KeyS, KeyCode::KeyT, KeyCode::KeyU, KeyCode::KeyV, KeyCode::KeyW, KeyCode::KeyX,
+    KeyCode::KeyY, KeyCode::KeyZ,
+    KeyCode::Digit0, KeyCode::Digit1, KeyCode::Digit2, KeyCode::Digit3, KeyCode::Digit4,
+    KeyCode::Digit5, KeyCode::Digit6, KeyCode::Digit7, KeyCode::Digit8, KeyCode::Digit9,
+    KeyCode::ArrowUp, KeyCode::ArrowDown, KeyCode::ArrowLeft, KeyCode::ArrowRight,
+    KeyCode::Space, KeyCode::Enter, KeyCode::Escape, KeyCode::Tab, KeyCode::Backspace,
+    KeyCode::ShiftLeft, KeyCode::ShiftRight, KeyCode::ControlLeft, KeyCode::ControlRight,
+    KeyCode::AltLeft, KeyCode::Comma, KeyCode::Period, KeyCode::Slash, KeyCode::Semicolon,
+    KeyCode::Quote, KeyCode::BracketLeft, KeyCode::BracketRight, KeyCode::Minus, KeyCode::Equal,
+    KeyCode::Backquote,
+];
+
+const BINDABLE_BUTTONS: [GamepadButton; 19] = [
+    GamepadButton::South, GamepadButton::East, GamepadButton::North, GamepadButton::West,
+    GamepadButton::C, GamepadButton::Z,
+    GamepadButton::LeftTrigger, GamepadButton::LeftTrigger2,
+    GamepadButton::RightTrigger, GamepadButton::RightTrigger2,
+    GamepadButton::Select, GamepadButton::Start, GamepadButton::Mode,
+    GamepadButton::LeftThumb, GamepadButton::RightThumb,
+    GamepadButton::DPadUp, GamepadButton::DPadDown, GamepadButton::DPadLeft, GamepadButton::DPadRight,
+];
+
+fn key_from_name(name: &str) -> Option<KeyCode> {
+    BINDABLE_KEYS.into_iter().find(|k| format!("{:?}", k) == name)
+}
+
+fn button_from_name(name: &str) -> Option<GamepadButton> {
+    BINDABLE_BUTTONS.into_iter().find(|b| format!("{:?}", b) == name)
+}
+
+pub fn key_label(key: KeyCode) -> String {
+    let label = match key {
+        KeyCode::Space => "SPACE",
+        KeyCode::Enter => "ENTER",
+        KeyCode::Escape => "ESC",
+        KeyCode::Tab => "TAB",
+        KeyCode::Backspace => "BKSP",
+        KeyCode::ArrowUp => "↑",
+        KeyCode::ArrowDown => "↓",
+        KeyCode::ArrowLeft => "←",
+        KeyCode::ArrowRight => "→",
+        KeyCode::ShiftLeft | KeyCode::ShiftRight => "SHIFT",
+        KeyCode::ControlLeft | KeyCode::ControlRight => "CTRL",
+        _ => {
+            let name = format!("{:?}", key);
+            let short = name.strip_prefix("Key").or_else(|| name.strip_prefix("Digit")).unwrap_or(&name);
+            return short.to_uppercase();
+        }
+    };
+    label.to_string()
+}
+
+/// Xbox-style names, the layout most classroom pads copy
+pub fn button_label(button: GamepadButton) -> String {
+    let label = match button {
+        GamepadButton::South => "Ⓐ",
+        GamepadButton::East => "Ⓑ",
+        GamepadButton::West => "Ⓧ",
+        GamepadButton::North => "Ⓨ",
+        GamepadButton::LeftTrigger => "LB",
+        GamepadButton::LeftTrigger2 => "LT",
+        GamepadButton::RightTrigger => "RB",
+        GamepadButton::RightTrigger2 => "RT",
+        GamepadButton::Select => "VIEW",
+        GamepadButton::Start => "MENU",
+        GamepadButton::LeftThumb => "LS",
+        GamepadButton::RightThumb => "RS",
+        GamepadButton::DPadUp => "D↑",
+        GamepadButton::DPadDown => "D↓",
+        GamepadButton::DPadLeft => "D←",
+        GamepadButton::DPadRight => "D→",
+        other => return format!("{:?}", other).to_uppercase(),
+    };
+    label.to_string()
+}
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub struct ActionsPlugin;
+
+impl Plugin for ActionsPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(Actions::load())
+           .init_resource::<InputContext>()
+           .init_resource::<Rebinding>()
+           .init_resource::<VirtualInput>()
+           .init_resource::<OfferedChoices>()
+           .configure_sets(PreUpdate, VirtualInputSet.after(clear_virtual_input).before(update_actions))
+           .add_systems(Startup, spawn_controls_hud)
+           .add_systems(PreUpdate, (update_context, collect_choices, clear_virtual_input, update_actions).chain().after(InputSystem))
+           .add_systems(Update, (start_rebind, capture_rebind, update_controls_hud).chain());
+    }
+}
+
+/// Works out which context owns input this frame
+fn update_context(
+    (state, pause): (Res<State<GameState>>, Option<Res<State<PauseState>>>),
+    rebinding: Res<Rebinding>,
+    (story_state, command_line): (Res<crate::story_mode::StoryState>, Res<crate::voice_commands::CommandLine>),
+    popup: Res<crate::ui::knowledge_popup::PopupActive>,
+    puzzle: Res<crate::puzzle::PuzzleState>,
+    syllabus: Option<Res<crate::syllabus::SyllabusResource>>,
+    mut context: ResMut<InputContext>,
+) {
+    // The quiz only takes the choice keys while the NPC asking it is beside
+    // the player; on the way there the arrows and D-pad still walk
+    let quiz = syllabus.is_some_and(|syl| {
+        matches!(syl.current_phase(), crate::syllabus::QuestPhase::Quiz { .. })
+            && story_state.nearby_npc.as_ref().is_some_and(|npc| npc.id == syl.quest_script.current_npc())
+    });
+
+    let paused = pause.is_some_and(|p| *p.get() == PauseState::Paused);
+
+    let next = match state.get() {
+        _ if rebinding.0.is_some() => InputContext::Rebinding,
+        GameState::Playing if paused => InputContext::Pause,
+        GameState::PreTest | GameState::PostTest => InputContext::Test,
+        GameState::Review => InputContext::Review,
+        GameState::Playing if story_state.is_typing_prompt || command_line.open => InputContext::TextEntry,
+        GameState::Playing if popup.0 => InputContext::Popup,
+        GameState::Playing if puzzle.is_active => InputContext::Puzzle,
+        GameState::Playing if story_state.active_dialogue.is_some() => InputContext::Dialogue,
+        GameState::Playing if quiz => InputContext::Quiz,
+        GameState::Playing => InputContext::World,
+        GameState::Menu | GameState::Boot | GameState::Victory => InputContext::Menu,
+    };
+    if *context != next {
+        *context = next;
+    }
+}
+
+/// Where each context keeps the options it shows
+#[derive(SystemParam)]
+struct ChoiceSources<'w> {
+    state: Res<'w, State<GameState>>,
+    story_state: Res<'w, crate::story_mode::StoryState>,
+    syllabus: Option<Res<'w, crate::syllabus::SyllabusResource>>,
+    puzzle: Res<'w, crate::puzzle::PuzzleState>,
+    test: Option<Res<'w, crate::assessment::ActiveTest>>,
+    review: Option<Res<'w, crate::review::ReviewSession>>,
+    deck: Res<'w, crate::review::ReviewDeck>,
+    pause_menu: Res<'w, crate::ui::pause_menu::PauseMenu>,
+    locale: Res<'w, Locale>,
+}
+
+impl ChoiceSources<'_> {
+    fn choices(&self, context: InputContext) -> Vec<String> {
+        match context {
+            InputContext::Dialogue => self.story_state.active_dialogue.as_ref()
+                .map(|d| d.choices.clone())
+                .unwrap_or_default(),
+            InputContext::Quiz => match self.syllabus.as_ref().map(|syl| syl.current_phase()) {
+                Some(crate::syllabus::QuestPhase::Quiz { options, .. }) => options.clone(),
+                _ => Vec::new(),
+            },
+            InputContext::Puzzle => self.puzzle.tokens.iter().map(|t| t.to_string()).collect(),
+            InputContext::Test => self.test.as_ref().map(|t| t.options().to_vec()).unwrap_or_default(),
+            InputContext::Pause => self.pause_menu.options.clone(),
+            InputContext::Review => self.review.as_ref().map(|r| r.choices(&self.deck, &self.locale)).unwrap_or_default(),
+            InputContext::Menu if *self.state.get() == GameState::Menu => {
+                let mut options = vec![self.locale.t("menu-start")];
+                if !self.deck.cards.is_empty() {
+                    options.push(self.locale.t("menu-review"));
+                }
+                options.push(self.locale.fmt("menu-language", &[("language", &self.locale.language().native_name())]));
+                options
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+fn collect_choices(
+    context: Res<InputContext>,
+    sources: ChoiceSources,
+    mut offered: ResMut<OfferedChoices>,
+) {
+    let choices = sources.choices(*context);
+    if offered.0 != choices {
+        offered.0 = choices;
+    }
+}
+
+/// Virtual input lasts a frame; its sources fill it again in `VirtualInputSet`
+fn clear_virtual_input(mut input: ResMut<VirtualInput>) {
+    let input = &mut *input;
+    input.pressed.clear();
+    input.just_pressed.clear();
+    input.stick = Vec2::ZERO;
+    input.touched = false;
+}
+
+fn update_actions(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    virtual_input: Res<VirtualInput>,
+    context: Res<InputContext>,
+    offered: Res<OfferedChoices>,
+    mut actions: ResMut<Actions>,
+) {
+    let actions = &mut *actions;
+    let context = *context;
+
+    let pad_used = gamepads.iter().any(|g| g.get_just_pressed().next().is_some() || g.left_stick().length() > 0.5);
+    let key_used = keys.get_just_pressed().next().is_some();
+    if pad_used {
+        actions.device = Device::Gamepad;
+    } else if key_used {
+        actions.device = Device::Keyboard;
+    } else if virtual_input.touched {
+        actions.device = Device::Touch;
+    }
+    actions.any_just_pressed = pad_used || key_used || virtual_input.touched;
+    actions.context = context;
+
+    // While a context takes choices, inputs bound to choosing are theirs alone
+    let choosing = context.takes_choices();
+    let choice_bindings = actions.bindings.iter().filter(|b| b.action.is_choosing());
+    let (choice_keys, choice_buttons): (HashSet<KeyCode>, HashSet<GamepadButton>) = if choosing {
+        let bindings: Vec<&Binding> = choice_bindings.collect();
+        (
+            bindings.iter().flat_map(|b| b.keys.iter().copied()).collect(),
+            bindings.iter().flat_map(|b| b.buttons.iter().copied()).collect(),
+        )
+    } else {
+        Default::default()
+    };
+
+    actions.pressed.clear();
+    actions.just_pressed.clear();
+    for binding in &actions.bindings {
+        let allowed = match (context, binding.action) {
+            (InputContext::Rebinding, _) => false,
+            (InputContext::TextEntry, action) => matches!(action, Action::Confirm | Action::Cancel | Action::Speak),
+            (InputContext::Pause, action) => action.is_choosing() || matches!(action,
+                Action::Confirm | Action::Cancel | Action::Pause | Action::MoveLeft | Action::MoveRight),
+            (_, action) if action.is_choosing() => choosing,
+            _ => true,
+        };
+        if !allowed { continue; }
+
+        let is_choice = binding.action.is_choosing();
+        let keys_for = binding.keys.iter().filter(|k| is_choice || !choice_keys.contains(k));
+        let buttons_for: Vec<GamepadButton> = binding.buttons.iter().copied()
+            .filter(|b| is_choice || !choice_buttons.contains(b))
+            .collect();
+
+        let mut pressed = virtual_input.pressed.contains(&binding.action);
+        let mut just = virtual_input.just_pressed.contains(&binding.action);
+        for key in keys_for {
+            pressed |= keys.pressed(*key);
+            just |= keys.just_pressed(*key);
+        }
+        for gamepad in &gamepads {
+            pressed |= gamepad.any_pressed(buttons_for.iter().copied());
+            just |= gamepad.any_just_pressed(buttons_for.iter().copied());
+        }
+        if pressed { actions.pressed.insert(binding.action); }
+        if just { actions.just_pressed.insert(binding.action); }
+    }
+
+    update_focus(actions, offered.0.len(), offered.is_changed());
+
+    let mut movement = Vec2::ZERO;
+    if actions.pressed(Action::MoveUp) { movement.y += 1.0; }
+    if actions.pressed(Action::MoveDown) { movement.y -= 1.0; }
+    if actions.pressed(Action::MoveLeft) { movement.x -= 1.0; }
+    if actions.pressed(Action::MoveRight) { movement.x += 1.0; }
+    if !matches!(context, InputContext::Rebinding | InputContext::TextEntry) {
+        for gamepad in &gamepads {
+            movement += gamepad.left_stick();
+        }
+        movement += virtual_input.stick;
+    }
+    // Left/right adjust settings in the pause menu; nobody walks
+    if context == InputContext::Pause {
+        movement = Vec2::ZERO;
+    }
+    actions.movement = movement.clamp_length_max(1.0);
+}
+
+/// Arrow keys move the focus; Confirm on a focused option picks it
+fn update_focus(actions: &mut Actions, count: usize, options_changed: bool) {
+    if count == 0 || options_changed {
+        actions.focus = None;
+    }
+    if count == 0 { return; }
+
+    if actions.just_pressed.contains(&Action::FocusNext) {
+        actions.focus = Some(actions.focus.map_or(0, |i| (i + 1) % count));
+    }
+    if actions.just_pressed.contains(&Action::FocusPrev) {
+        actions.focus = Some(actions.focus.map_or(count - 1, |i| (i + count - 1) % count));
+    }
+    if let Some(i) = actions.focus.filter(|_| actions.just_pressed.contains(&Action::Confirm)) {
+        actions.just_pressed.remove(&Action::Confirm);
+        actions.just_pressed.insert(Action::Choice(i as u8));
+    }
+}
+
+// ============================================================================
+// Controls HUD — bottom-right, click a row to rebind it
+// ============================================================================
+
+#[derive(Component)]
+struct ControlsHud;
+
+/// One line of the controls HUD. Rows with a single action can be rebound.
+#[derive(Component)]
+struct ControlsRow {
+    actions: Vec<Action>,
+    /// Message id of the row's description
+    label: &'static str,
+}
+
+fn spawn_controls_hud(mut commands: Commands) {
+    let rows: Vec<(Vec<Action>, &'static str)> = vec![
+        (vec![Action::MoveUp, Action::MoveLeft, Action::MoveDown, Action::MoveRight], "controls-move"),
+        (vec![Action::Attack], "controls-attack"),
+        (vec![Action::Interact], "controls-interact"),
+        (vec![Action::Talk], "controls-talk"),
+        ((0..3).map(Action::Choice).collect(), "controls-choices"),
+        (vec![Action::FocusPrev, Action::FocusNext], "controls-focus"),
+        (vec![
+            Action::ToggleTool(ToolId::OllamaCompass),
+            Action::ToggleTool(ToolId::LogicLens),
+            Action::ToggleTool(ToolId::FeedbackMirror),
+        ], "controls-tools"),
+        (vec![Action::Breadcrumbs], "controls-breadcrumbs"),
+        (vec![Action::Map], "controls-map"),
+        (vec![Action::SpawnSlime], "controls-spawn-slime"),
+        (vec![Action::Pause], "controls-pause"),
+        (vec![Action::Speak], "controls-speak"),
+        (vec![Action::Command], "controls-command"),
+        (vec![Action::Cancel], "controls-close"),
+    ];
+    // The browser build can't listen
+    let rows = rows.into_iter().filter(|(actions, _)| crate::speech::AVAILABLE || actions[..] != [Action::Speak]);
+
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(12.0),
+            bottom: Val::Px(12.0),
+            padding: UiRect::all(Val::Px(10.0)),
+            flex_direction: FlexDirection::Column,
+            row_gap: Val::Px(2.0),
+            border: UiRect::all(Val::Px(1.0)),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.05, 0.05, 0.05, 0.7)),
+        BorderColor(Color::srgb(0.3, 0.3, 0.3)),
+        ControlsHud,
+    )).with_children(|parent| {
+        parent.spawn((
+            localized("controls-header"),
+            TextFont { font_size: 11.0, ..default() },
+            TextColor(Color::srgb(1.0, 0.75, 0.0)),
+        ));
+        for (actions, label) in rows {
+            let mut row = parent.spawn((
+                Text::new(""),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(Color::srgb(0.6, 0.6, 0.6)),
+            ));
+            if actions.len() == 1 {
+                row.insert(Button);
+            }
+            row.insert(ControlsRow { actions, label });
+        }
+    });
+}
+
+fn start_rebind(
+    rows: Query<(&Interaction, &ControlsRow), Changed<Interaction>>,
+    mut rebinding: ResMut<Rebinding>,
+) {
+    for (interaction, row) in &rows {
+        if *interaction == Interaction::Pressed {
+            rebinding.0 = row.actions.first().copied();
+        }
+    }
+}
+
+/// The next key or button pressed becomes the binding; ESC cancels
+fn capture_rebind(
+    keys: Res<ButtonInput<KeyCode>>,
+    gamepads: Query<&Gamepad>,
+    mut rebinding: ResMut<Rebinding>,
+    mut actions: ResMut<Actions>,
+) {
+    let Some(action) = rebinding.0 else { return };
+
+    if keys.just_pressed(KeyCode::Escape) {
+        rebinding.0 = None;
+        return;
+    }
+    if let Some(key) = keys.get_just_pressed().find(|k| BINDABLE_KEYS.contains(k)) {
+        actions.rebind_key(action, *key);
+    } else if let Some(button) = gamepads.iter().find_map(|g| g.get_just_pressed().next().copied()) {
+        actions.rebind_button(action, button);
+    } else {
+        return;
+    }
+    info!("🎮 Rebound {:?} to {}", action, actions.label(action));
+    actions.save();
+    rebinding.0 = None;
+}
+
+fn update_controls_hud(
+    actions: Res<Actions>,
+    rebinding: Res<Rebinding>,
+    locale: Res<Locale>,
+    mut rows: Query<(&ControlsRow, &mut Text, &mut TextColor, Option<&Interaction>)>,
+    mut hud: Query<&mut Visibility, With<ControlsHud>>,
+) {
+    // Touch has its own on-screen controls
+    let visibility = if actions.device == Device::Touch { Visibility::Hidden } else { Visibility::Inherited };
+    for mut hud_visibility in &mut hud {
+        if *hud_visibility != visibility {
+            *hud_visibility = visibility;
+        }
+    }
+
+
+    for (row, mut text, mut color, interaction) in &mut rows {
+        let capturing = rebinding.0.is_some() && row.actions.first() == rebinding.0.as_ref();
+        let content = if capturing {
+            format!("{:<10} {}", "…", locale.t("controls-press-key"))
+        } else {
+            let keys: Vec<String> = row.actions.iter().map(|a| actions.label(*a)).collect();
+            format!("{:<10} {}", keys.join("/"), locale.t(row.label))
+        };
+        if text.0 != content {
+            text.0 = content;
+        }
+
+        let hovered = matches!(interaction, Some(Interaction::Hovered | Interaction::Pressed));
+        let next = if capturing || hovered { Color::srgb(1.0, 0.75, 0.0) } else { Color::srgb(0.6, 0.6, 0.6) };
+        if color.0 != next {
+            color.0 = next;
+        }
+    }
+}
@@ -0,0 +1,501 @@
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, VecDeque};
+
+// ============================================================================
+// Conversation — what the teacher remembers, within the model's window
+// ============================================================================
+// Every NPC keeps a rolling `Transcript` of what was said to and by them.
+// Prompts go with the context in front of them, for the text model
+// (`ai::TextModel`) or the offline teacher to answer from, each part cut to
+// its share of the `ContextBudget`: the current lesson, memories recalled from
+// the `MemoryStore`, a summary of older turns and the latest turns word for
+// word. The prompt itself always goes whole. Tokens are counted with the
+// model's tokenizer once `download_model.sh` has fetched it, else estimated.
+// When the transcript outgrows its share, the oldest turns are folded into the
+// summary: by the text model when there is one, else by picking the sentences
+// that carry the most of what was talked about. No ECS here, so it can be
+// tested on its own.
+
+/// The model's tokenizer
+pub const TOKENIZER_PATH: &str = "assets/models/tokenizer.json";
+
+/// Characters per token when there's no tokenizer to ask
+const CHARS_PER_TOKEN: usize = 4;
+
+/// The smallest window a prompt can be squeezed into
+const MIN_WINDOW: usize = 256;
+
+/// Words shorter than this say little about the topic ("the", "que", "is")
+const MIN_KEYWORD_LEN: usize = 4;
+
+/// Longer than this, the start of a summary line isn't a speaker's name
+const MAX_SPEAKER_LEN: usize = 32;
+
+/// How many tokens each part of a prompt may take
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContextBudget {
+    /// The whole prompt, instruction included
+    pub window: usize,
+    /// The latest turns, word for word
+    pub transcript: usize,
+    /// What the older turns were about
+    pub summary: usize,
+    /// Memories recalled for the prompt
+    pub memories: usize,
+    /// The current quest, phase and lesson
+    pub syllabus: usize,
+    /// How many memories to recall
+    pub recall: usize,
+}
+
+impl Default for ContextBudget {
+    fn default() -> Self {
+        Self { window: 2048, transcript: 768, summary: 192, memories: 192, syllabus: 384, recall: 3 }
+    }
+}
+
+impl ContextBudget {
+    /// A window big enough for anything at all
+    pub fn sanitized(mut self) -> Self {
+        self.window = self.window.max(MIN_WINDOW);
+        self
+    }
+
+    /// `prompt` with as much context in front of it as fits. Within the
+    /// window the lesson comes first, then the latest turns, the summary and
+    /// the memories; each is cut to its own share too.
+    pub fn assemble(
+        &self,
+        prompt: &str,
+        transcript: &Transcript,
+        lesson: &str,
+        memories: &[String],
+        counter: &TokenCounter,
+    ) -> String {
+        let mut left = self.window.saturating_sub(counter.count(prompt));
+
+        let lesson = section("LESSON CONTENT", lesson, self.syllabus.min(left), counter);
+        left = left.saturating_sub(counter.count(&lesson));
+
+        // Newest first, and only whole turns
+        let mut share = self.transcript.min(left).saturating_sub(overhead("CONVERSATION SO FAR", counter));
+        let mut recent = Vec::new();
+        for turn in transcript.turns.iter().rev() {
+            let line = turn.line();
+            let tokens = counter.count(&line) + 1;
+            if tokens > share { break; }
+            share -= tokens;
+            recent.push(line);
+        }
+        recent.reverse();
+        let recent = section("CONVERSATION SO FAR", &recent.join("\n"), self.transcript.min(left), counter);
+        left = left.saturating_sub(counter.count(&recent));
+
+        let summary = section("EARLIER IN THIS CONVERSATION", &transcript.summary, self.summary.min(left), counter);
+        left = left.saturating_sub(counter.count(&summary));
+
+        let mut share = self.memories.min(left).saturating_sub(overhead("RELATED MEMORIES", counter));
+        let mut kept = Vec::new();
+        for memory in memories {
+            let line = format!("- {}", memory);
+            let tokens = counter.count(&line) + 1;
+            if tokens > share { continue; }
+            share -= tokens;
+            kept.push(line);
+        }
+        let memories = section("RELATED MEMORIES", &kept.join("\n"), self.memories.min(left), counter);
+
+        format!("{}{}{}{}{}", lesson, memories, summary, recent, prompt)
+    }
+}
+
+/// The title line and the blank line after a section
+fn overhead(title: &str, counter: &TokenCounter) -> usize {
+    counter.count(title) + 2
+}
+
+/// `body` under `title`, cut to `tokens` altogether, or nothing when empty
+fn section(title: &str, body: &str, tokens: usize, counter: &TokenCounter) -> String {
+    let body = body.trim();
+    if body.is_empty() {
+        return String::new();
+    }
+    let overhead = overhead(title, counter);
+    if tokens <= overhead {
+        return String::new();
+    }
+    let body = counter.fit(body, tokens - overhead);
+    if body.is_empty() {
+        return String::new();
+    }
+    format!("{}:\n{}\n\n", title, body)
+}
+
+// ============================================================================
+// Tokens
+// ============================================================================
+
+/// Counts tokens the way the model will
+#[derive(Default)]
+pub struct TokenCounter {
+    #[cfg(not(target_arch = "wasm32"))]
+    tokenizer: Option<tokenizers::Tokenizer>,
+}
+
+impl TokenCounter {
+    /// The model's own tokenizer, from a `tokenizer.json`
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load(path: &std::path::Path) -> anyhow::Result<Self> {
+        let tokenizer = tokenizers::Tokenizer::from_file(path).map_err(anyhow::Error::msg)?;
+        Ok(Self { tokenizer: Some(tokenizer) })
+    }
+
+    pub fn count(&self, text: &str) -> usize {
+        if text.is_empty() {
+            return 0;
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(encoding) = self.tokenizer.as_ref().and_then(|t| t.encode(text, false).ok()) {
+            return encoding.len();
+        }
+        text.chars().count().div_ceil(CHARS_PER_TOKEN)
+    }
+
+    /// As many of the first words of `text` as fit in `tokens`, marked with
+    /// an ellipsis when cut
+    pub fn fit(&self, text: &str, tokens: usize) -> String {
+        if self.count(text) <= tokens {
+            return text.to_string();
+        }
+        // Where each word ends, so a cut keeps the text's own line breaks
+        let ends: Vec<usize> = text
+            .split_whitespace()
+            .map(|word| word.as_ptr() as usize - text.as_ptr() as usize + word.len())
+            .collect();
+        let cut = |n: usize| format!("{}…", &text[..ends[n - 1]]);
+        // The longest run of words that fits; counts grow with the run
+        let (mut fits, mut over) = (0, ends.len());
+        while over - fits > 1 {
+            let mid = (fits + over) / 2;
+            if self.count(&cut(mid)) <= tokens { fits = mid } else { over = mid }
+        }
+        if fits == 0 { String::new() } else { cut(fits) }
+    }
+}
+
+// ============================================================================
+// Transcript
+// ============================================================================
+
+/// One thing said, by whoever said it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Turn {
+    /// Empty for narration
+    pub speaker: String,
+    pub text: String,
+}
+
+impl Turn {
+    pub fn new(speaker: impl Into<String>, text: impl Into<String>) -> Self {
+        Self { speaker: speaker.into(), text: text.into() }
+    }
+
+    /// "Speaker: text", as the model reads it
+    pub fn line(&self) -> String {
+        if self.speaker.is_empty() {
+            self.text.clone()
+        } else {
+            format!("{}: {}", self.speaker, self.text)
+        }
+    }
+}
+
+/// Everything said with one NPC: older turns summarized, the rest kept
+#[derive(Debug, Clone, Default)]
+pub struct Transcript {
+    pub summary: String,
+    pub turns: VecDeque<Turn>,
+}
+
+impl Transcript {
+    pub fn push(&mut self, turn: Turn) {
+        let text = turn.text.split_whitespace().collect::<Vec<_>>().join(" ");
+        if text.is_empty() { return; }
+        self.turns.push_back(Turn { text, ..turn });
+    }
+
+    pub fn tokens(&self, counter: &TokenCounter) -> usize {
+        self.turns.iter().map(|turn| counter.count(&turn.line()) + 1).sum()
+    }
+
+    /// Once the turns outgrow their share of the window, folds the oldest
+    /// into the summary until three quarters of the share is left. `write`
+    /// gets the instruction for a model-written summary; when it has none,
+    /// the summary is extracted instead. Whether anything was folded.
+    pub fn compact(
+        &mut self,
+        budget: &ContextBudget,
+        counter: &TokenCounter,
+        write: impl FnOnce(&str) -> Option<String>,
+    ) -> bool {
+        let mut tokens = self.tokens(counter);
+        if tokens <= budget.transcript {
+            return false;
+        }
+        let mut old = Vec::new();
+        while tokens > budget.transcript * 3 / 4 {
+            let Some(turn) = self.turns.pop_front() else { break };
+            tokens = tokens.saturating_sub(counter.count(&turn.line()) + 1);
+            old.push(turn);
+        }
+
+        let written = write(&summary_prompt(&self.summary, &old, budget.summary))
+            .map(|summary| counter.fit(summary.trim(), budget.summary))
+            .filter(|summary| !summary.is_empty());
+        self.summary = written.unwrap_or_else(|| extractive_summary(&self.summary, &old, budget.summary, counter));
+        true
+    }
+}
+
+/// Asks a model to fold `turns` into the summary so far
+pub fn summary_prompt(previous: &str, turns: &[Turn], tokens: usize) -> String {
+    let turns: Vec<String> = turns.iter().map(Turn::line).collect();
+    format!(
+        "Summarize this conversation between a teacher and the Architect for the teacher's own notes. \
+        Keep what the Architect asked, answered and struggled with, and what was taught. \
+        Use at most {} words, one line per speaker as 'Speaker: what they said'.\n\n\
+        SUMMARY SO FAR:\n{}\n\nNEW TURNS:\n{}",
+        tokens * 3 / 4,
+        if previous.is_empty() { "(none)" } else { previous },
+        turns.join("\n"),
+    )
+}
+
+/// The summary so far and `turns`, cut down to the sentences that share the
+/// most words with the rest, in the order they were said, within `tokens`
+pub fn extractive_summary(previous: &str, turns: &[Turn], tokens: usize, counter: &TokenCounter) -> String {
+    // The summary's own lines read back as turns, so it can be cut again
+    let earlier = previous.lines().map(|line| match line.split_once(": ") {
+        Some((speaker, text)) if (1..=MAX_SPEAKER_LEN).contains(&speaker.chars().count()) => Turn::new(speaker, text),
+        _ => Turn::new("", line),
+    });
+    let sentences: Vec<(String, String)> = earlier
+        .chain(turns.iter().cloned())
+        .flat_map(|turn| sentences(&turn.text).into_iter().map(move |s| (turn.speaker.clone(), s)))
+        .collect();
+
+    let keywords = |sentence: &str| -> Vec<String> {
+        let mut words: Vec<String> = sentence
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| w.chars().count() >= MIN_KEYWORD_LEN)
+            .map(str::to_lowercase)
+            .collect();
+        words.sort();
+        words.dedup();
+        words
+    };
+    let mut frequency: HashMap<String, usize> = HashMap::new();
+    for (_, sentence) in &sentences {
+        for word in keywords(sentence) {
+            *frequency.entry(word).or_default() += 1;
+        }
+    }
+    let scores: Vec<f32> = sentences.iter().map(|(_, sentence)| {
+        let words = keywords(sentence);
+        let shared: usize = words.iter().map(|w| frequency[w] - 1).sum();
+        shared as f32 / (words.len().max(1) as f32).sqrt()
+    }).collect();
+
+    // Best first; on a tie the later sentence, being more current
+    let mut ranked: Vec<usize> = (0..sentences.len()).collect();
+    ranked.sort_by(|&a, &b| scores[b].total_cmp(&scores[a]).then(b.cmp(&a)));
+    let mut left = tokens;
+    let mut chosen = Vec::new();
+    for i in ranked {
+        let (speaker, sentence) = &sentences[i];
+        let cost = counter.count(&Turn::new(speaker.as_str(), sentence.as_str()).line()) + 1;
+        if cost <= left {
+            left -= cost;
+            chosen.push(i);
+        }
+    }
+    chosen.sort();
+
+    // Back into lines, one per run of the same speaker
+    let mut lines: Vec<Turn> = Vec::new();
+    for i in chosen {
+        let (speaker, sentence) = &sentences[i];
+        match lines.last_mut() {
+            Some(last) if last.speaker == *speaker => {
+                last.text.push(' ');
+                last.text.push_str(sentence);
+            }
+            _ => lines.push(Turn::new(speaker.as_str(), sentence.as_str())),
+        }
+    }
+    let summary = lines.iter().map(Turn::line).collect::<Vec<_>>().join("\n");
+    counter.fit(&summary, tokens)
+}
+
+/// `text` split after each full stop, question or exclamation mark
+fn sentences(text: &str) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        current.push(c);
+        let ends = matches!(c, '.' | '!' | '?' | '…') && chars.peek().is_none_or(|next| next.is_whitespace());
+        if ends || c == '\n' {
+            let sentence = current.trim();
+            if !sentence.is_empty() {
+                out.push(sentence.to_string());
+            }
+            current.clear();
+        }
+    }
+    let rest = current.trim();
+    if !rest.is_empty() {
+        out.push(rest.to_string());
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    // The teacher's conversation memory without the game: prompts stay inside
+    // their window with the newest turns kept, old turns fold into a summary of
+    // what was talked about, and token counts use the model's tokenizer when
+    // `download_model.sh` has fetched it (that part is skipped otherwise).
+    use super::*;
+
+    use std::path::Path;
+
+    const PROMPT: &str = "ROLE: You are the Teacher.\nCURRENT LESSON (Gain Attention — 'Hook'): Every loop needs an exit.";
+
+    fn lesson_chat(turns: usize) -> Transcript {
+        let mut transcript = Transcript::default();
+        for i in 0..turns {
+            transcript.push(Turn::new("Architect", format!("Question {}: why does the loop never stop? I added a counter.", i)));
+            transcript.push(Turn::new("Teacher", format!("Answer {}: a loop stops when its exit condition is met. Check the counter.", i)));
+        }
+        transcript
+    }
+
+    #[test]
+    fn estimates_and_cuts_on_words() {
+        let counter = TokenCounter::default();
+        assert_eq!(counter.count(""), 0);
+        assert_eq!(counter.count("loop"), 1);
+        assert_eq!(counter.count("every loop"), 3);
+
+        let text = "Teacher: loops repeat.\nArchitect: until the exit condition holds.";
+        assert_eq!(counter.fit(text, 100), text);
+        let cut = counter.fit(text, 8);
+        assert!(counter.count(&cut) <= 8, "{:?}", cut);
+        assert!(cut.ends_with('…') && text.starts_with(cut.trim_end_matches('…')), "{:?}", cut);
+        // Line breaks survive the cut
+        assert_eq!(counter.fit(text, 9), "Teacher: loops repeat.\nArchitect:…");
+        assert_eq!(counter.fit(text, 0), "");
+    }
+
+    #[test]
+    fn prompt_stays_in_its_window() {
+        let counter = TokenCounter::default();
+        let transcript = lesson_chat(20);
+        let memories = vec!["A loop without an exit runs forever (Reflection, 2026-10-01)".to_string()];
+        let budget = ContextBudget { window: 400, transcript: 150, ..ContextBudget::default() };
+
+        let input = budget.assemble(PROMPT, &transcript, "Quest: Loops. Now: 💬 Hook", &memories, &counter);
+        assert!(counter.count(&input) <= budget.window, "{} tokens", counter.count(&input));
+        assert!(input.ends_with(PROMPT));
+        assert!(input.starts_with("LESSON CONTENT:\nQuest: Loops"));
+        assert!(input.contains("RELATED MEMORIES:\n- A loop without an exit"));
+        // The newest turns are there word for word, the oldest aren't
+        assert!(input.contains("Teacher: Answer 19: a loop stops"));
+        assert!(!input.contains("Question 0:"));
+
+        // No room for anything but the prompt, which is never cut
+        let tight = ContextBudget { window: counter.count(PROMPT), ..budget };
+        assert_eq!(tight.assemble(PROMPT, &transcript, "Quest: Loops", &memories, &counter), PROMPT);
+        // Nothing to add
+        assert_eq!(budget.assemble(PROMPT, &Transcript::default(), "", &[], &counter), PROMPT);
+    }
+
+    #[test]
+    fn old_turns_fold_into_a_summary() {
+        let counter = TokenCounter::default();
+        let budget = ContextBudget { transcript: 200, summary: 60, ..ContextBudget::default() };
+        let mut transcript = lesson_chat(3);
+        assert!(!transcript.compact(&budget, &counter, |_| None), "fits, nothing to fold");
+        assert!(transcript.summary.is_empty());
+
+        let mut transcript = lesson_chat(12);
+        transcript.push(Turn::new("Architect", "I like pizza."));
+        assert!(transcript.compact(&budget, &counter, |_| None));
+        assert!(transcript.tokens(&counter) <= budget.transcript * 3 / 4);
+        assert!(transcript.turns.back().is_some_and(|turn| turn.text == "I like pizza."));
+
+        let summary = transcript.summary.clone();
+        assert!(!summary.is_empty() && counter.count(&summary) <= budget.summary, "{:?}", summary);
+        // What kept coming up, under who said it
+        assert!(summary.lines().all(|line| line.starts_with("Architect: ") || line.starts_with("Teacher: ")), "{:?}", summary);
+        assert!(summary.contains("loop") || summary.contains("counter"), "{:?}", summary);
+
+        // Folding again keeps the summary in its budget
+        for i in 0..12 {
+            transcript.push(Turn::new("Teacher", format!("Hint {}: print the counter inside the loop.", i)));
+        }
+        assert!(transcript.compact(&budget, &counter, |_| None));
+        assert!(counter.count(&transcript.summary) <= budget.summary, "{:?}", transcript.summary);
+    }
+
+    #[test]
+    fn model_writes_the_summary_when_it_can() {
+        let counter = TokenCounter::default();
+        let budget = ContextBudget { transcript: 100, summary: 10, ..ContextBudget::default() };
+        let mut transcript = lesson_chat(8);
+        let mut asked = String::new();
+        transcript.compact(&budget, &counter, |prompt| {
+            asked = prompt.to_string();
+            Some("Architect: asked why the loop never stops, many times over and over again.".to_string())
+        });
+        assert!(asked.contains("NEW TURNS:\nArchitect: Question 0:"), "{}", asked);
+        assert!(transcript.summary.starts_with("Architect: asked why"));
+        assert!(counter.count(&transcript.summary) <= budget.summary);
+    }
+
+    #[test]
+    fn extracted_summary_keeps_order_and_speakers() {
+        let counter = TokenCounter::default();
+        let turns = [
+            Turn::new("Architect", "What is a loop? The weather is nice."),
+            Turn::new("Teacher", "A loop repeats steps. Loops repeat until an exit."),
+            Turn::new("", "The bell rings."),
+        ];
+        let summary = extractive_summary("", &turns, 1000, &counter);
+        assert_eq!(summary, "Architect: What is a loop? The weather is nice.\nTeacher: A loop repeats steps. Loops repeat until an exit.\nThe bell rings.");
+
+        // Short of room, off-topic sentences go first
+        let summary = extractive_summary("", &turns, 17, &counter);
+        assert_eq!(summary, "Architect: What is a loop?\nTeacher: A loop repeats steps.");
+        // The summary so far is read back with its speakers
+        let again = extractive_summary(&summary, &[], 1000, &counter);
+        assert_eq!(again, summary);
+    }
+
+    #[test]
+    fn counts_with_the_model_tokenizer() {
+        let path = Path::new(TOKENIZER_PATH);
+        if !path.exists() {
+            eprintln!("skipping: no tokenizer at {} (run download_model.sh)", path.display());
+            return;
+        }
+        let counter = TokenCounter::load(path).expect("tokenizer");
+        let words = "Every loop needs an exit, Architect.";
+        let tokens = counter.count(words);
+        assert!((5..=15).contains(&tokens), "{} tokens", tokens);
+        assert!(counter.count(&words.repeat(10)) > tokens * 5);
+        assert!(counter.count(&counter.fit(&words.repeat(10), 20)) <= 20);
+    }
+}
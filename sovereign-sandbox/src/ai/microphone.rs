@@ -0,0 +1,87 @@
+use anyhow::{bail, Context, Result};
+use bevy::log::warn;
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{FromSample, SizedSample};
+use std::sync::{Arc, Mutex};
+
+use super::stt;
+
+// ============================================================================
+// Microphone — push-to-talk capture via cpal
+// ============================================================================
+// The default input device, opened paused. Between `start` and `stop` its
+// samples collect in a buffer; `stop` hands them back as 16 kHz mono, ready
+// for `stt::Transcriber`. A cpal stream can't leave the thread that built it
+// on every platform, so this lives on the speech worker (see `speech`).
+
+pub struct Microphone {
+    stream: cpal::Stream,
+    rate: u32,
+    channels: usize,
+    buffer: Arc<Mutex<Vec<f32>>>,
+}
+
+impl Microphone {
+    pub fn open() -> Result<Self> {
+        let device = cpal::default_host().default_input_device().context("no microphone found")?;
+        let config = device.default_input_config()?;
+        let buffer = Arc::new(Mutex::new(Vec::new()));
+        let stream = match config.sample_format() {
+            cpal::SampleFormat::F32 => build::<f32>(&device, &config.config(), buffer.clone())?,
+            cpal::SampleFormat::I16 => build::<i16>(&device, &config.config(), buffer.clone())?,
+            cpal::SampleFormat::U16 => build::<u16>(&device, &config.config(), buffer.clone())?,
+            format => bail!("unsupported microphone format {:?}", format),
+        };
+        stream.pause()?;
+        Ok(Self { stream, rate: config.sample_rate().0, channels: config.channels() as usize, buffer })
+    }
+
+    pub fn start(&self) -> Result<()> {
+        self.buffer.lock().unwrap().clear();
+        self.stream.play()?;
+        Ok(())
+    }
+
+    /// What was heard since `start`, at 16 kHz
+    pub fn stop(&self) -> Result<Vec<f32>> {
+        self.stream.pause()?;
+        let samples = std::mem::take(&mut *self.buffer.lock().unwrap());
+        stt::resample(&stt::downmix(&samples, self.channels), self.rate)
+    }
+}
+
+/// Listening continuously rather than push-to-talk (see `moshi`)
+#[cfg(feature = "moshi")]
+impl Microphone {
+    /// Samples per second `drain` returns
+    pub fn rate(&self) -> u32 {
+        self.rate
+    }
+
+    /// What was heard since the last drain, mono at `rate`, still listening
+    pub fn drain(&self) -> Vec<f32> {
+        let samples = std::mem::take(&mut *self.buffer.lock().unwrap());
+        stt::downmix(&samples, self.channels)
+    }
+
+    pub fn pause(&self) -> Result<()> {
+        self.stream.pause()?;
+        Ok(())
+    }
+}
+
+fn build<T>(device: &cpal::Device, config: &cpal::StreamConfig, buffer: Arc<Mutex<Vec<f32>>>) -> Result<cpal::Stream>
+where
+    T: SizedSample,
+    f32: FromSample<T>,
+{
+    let stream = device.build_input_stream(
+        config,
+        move |data: &[T], _: &cpal::InputCallbackInfo| {
+            buffer.lock().unwrap().extend(data.iter().map(|s| f32::from_sample_(*s)));
+        },
+        |e| warn!("⚠️ Microphone error: {}", e),
+        None,
+    )?;
+    Ok(stream)
+}
@@ -1,9 +1,234 @@
 use bevy::prelude::*;
 
+// ============================================================================
+// Moshi Voice — the teacher talking back through Moshi
+// ============================================================================
+// With the `moshi` feature, a worker thread loads the model (see
+// `moshi_model`) and, while Moshi is the chosen AI backend and a game is
+// being played, streams the microphone into it and its replies out of the
+// speaker. `MoshiVoice` appears once the model has loaded; its `amplitude`
+// follows the loudness of the reply, so the teacher pulses as it talks (see
+// `teacher::update_moshi_visuals`) and the music ducks under it. A
+// conversation that fills up or breaks is replaced with a fresh one, so the
+// teacher keeps listening. Without the feature or the model files it never
+// appears, and lesson text answers.
+
 #[derive(Resource)]
 pub struct MoshiVoice {
     pub is_speaking: bool,
     pub amplitude: f32,
 }
 
+#[cfg(feature = "moshi")]
+pub use duplex::MoshiPlugin;
+
+#[cfg(feature = "moshi")]
+mod duplex {
+    use bevy::prelude::*;
+    use crossbeam_channel::{unbounded, Receiver, RecvTimeoutError, Sender};
+    use std::time::Duration;
+    use super::MoshiVoice;
+    use crate::ai::microphone::Microphone;
+    use crate::ai::moshi_model::{self, MoshiModel, StreamResampler, FRAME, SAMPLE_RATE};
+    use crate::ai::speaker::Speaker;
+    use crate::audio::Bus;
+    use crate::settings::{AiBackend, GameSettings};
+    use crate::{GameState, PauseState};
+
+    /// Above this the teacher counts as speaking
+    const SPEAKING_THRESHOLD: f32 = 0.05;
+
+    /// How fast the pulse falls back between frames, per second
+    const AMPLITUDE_DECAY: f32 = 4.0;
+
+    /// How often the worker checks for commands while listening
+    const POLL: Duration = Duration::from_millis(20);
+
+    /// Unanswered audio kept when the model falls behind, in frames; older
+    /// audio is dropped so replies stay close to real time
+    const MAX_BACKLOG: usize = 10;
+
+    enum MoshiCommand {
+        /// Start a new conversation, or end the current one
+        Listen(bool),
+        Volume(f32),
+    }
+
+    enum MoshiEvent {
+        Loaded,
+        Failed(String),
+        /// A conversation broke and a fresh one took its place
+        Restarted(String),
+        Replied { amplitude: f32, text: String },
+    }
+
+    #[derive(Resource)]
+    struct MoshiWorker {
+        commands: Sender<MoshiCommand>,
+        events: Receiver<MoshiEvent>,
+    }
 
+    pub struct MoshiPlugin;
+
+    impl Plugin for MoshiPlugin {
+        fn build(&self, app: &mut App) {
+            app.add_systems(Startup, spawn_worker)
+               .add_systems(Update, (receive_events, sync_listening).chain());
+        }
+    }
+
+    fn spawn_worker(mut commands: Commands) {
+        let (command_tx, command_rx) = unbounded();
+        let (event_tx, event_rx) = unbounded();
+        let spawned = std::thread::Builder::new()
+            .name("moshi".into())
+            .spawn(move || run_worker(command_rx, event_tx));
+        match spawned {
+            Ok(_) => commands.insert_resource(MoshiWorker { commands: command_tx, events: event_rx }),
+            Err(e) => error!("Failed to start the Moshi worker: {}", e),
+        }
+    }
+
+    /// Owns the model and the audio devices, which can't leave this thread
+    fn run_worker(commands: Receiver<MoshiCommand>, events: Sender<MoshiEvent>) {
+        let loaded = MoshiModel::load(std::path::Path::new(moshi_model::MODEL_DIR))
+            .and_then(|model| Ok((model, Microphone::open()?, Speaker::open()?)));
+        let (model, microphone, speaker) = match loaded {
+            Ok(loaded) => loaded,
+            Err(e) => {
+                let _ = events.send(MoshiEvent::Failed(format!("{:#}", e)));
+                return;
+            }
+        };
+        if events.send(MoshiEvent::Loaded).is_err() { return; }
+        if let Err(e) = converse(&model, &microphone, &speaker, &commands, &events) {
+            let _ = events.send(MoshiEvent::Failed(format!("{:#}", e)));
+        }
+    }
+
+    fn converse(
+        model: &MoshiModel,
+        microphone: &Microphone,
+        speaker: &Speaker,
+        commands: &Receiver<MoshiCommand>,
+        events: &Sender<MoshiEvent>,
+    ) -> anyhow::Result<()> {
+        let mut to_model = StreamResampler::new(microphone.rate(), SAMPLE_RATE)?;
+        let mut to_speaker = StreamResampler::new(SAMPLE_RATE, speaker.rate())?;
+        let mut conversation = None;
+        let mut heard = Vec::new();
+        loop {
+            // Nothing to do between commands unless listening
+            let command = match conversation {
+                Some(_) => match commands.recv_timeout(POLL) {
+                    Ok(command) => Some(command),
+                    Err(RecvTimeoutError::Timeout) => None,
+                    Err(RecvTimeoutError::Disconnected) => return Ok(()),
+                },
+                None => match commands.recv() {
+                    Ok(command) => Some(command),
+                    Err(_) => return Ok(()),
+                },
+            };
+            match command {
+                Some(MoshiCommand::Listen(true)) if conversation.is_none() => {
+                    conversation = Some(model.conversation()?);
+                    heard.clear();
+                    microphone.start()?;
+                }
+                Some(MoshiCommand::Listen(false)) => {
+                    conversation = None;
+                    microphone.pause()?;
+                    speaker.clear();
+                }
+                Some(MoshiCommand::Volume(volume)) => speaker.set_volume(volume),
+                _ => {}
+            }
+            let Some(conversation) = conversation.as_mut() else { continue };
+
+            heard.extend(to_model.push(&microphone.drain())?);
+            if heard.len() > MAX_BACKLOG * FRAME {
+                heard.drain(..heard.len() - MAX_BACKLOG * FRAME);
+            }
+            while heard.len() >= FRAME {
+                // The model only holds so many steps; start over before it runs out
+                if conversation.is_full() {
+                    *conversation = model.conversation()?;
+                }
+                let frame: Vec<f32> = heard.drain(..FRAME).collect();
+                let reply = match conversation.step(&frame) {
+                    Ok(reply) => reply,
+                    Err(e) => {
+                        if events.send(MoshiEvent::Restarted(format!("{:#}", e))).is_err() {
+                            return Ok(());
+                        }
+                        *conversation = model.conversation()?;
+                        heard.clear();
+                        continue;
+                    }
+                };
+                speaker.play(&to_speaker.push(&reply.pcm)?);
+                let amplitude = moshi_model::amplitude(&reply.pcm);
+                if events.send(MoshiEvent::Replied { amplitude, text: reply.text }).is_err() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    fn receive_events(
+        mut commands: Commands,
+        time: Res<Time>,
+        worker: Option<Res<MoshiWorker>>,
+        voice: Option<ResMut<MoshiVoice>>,
+    ) {
+        let Some(worker) = worker else { return };
+        let mut loudest: Option<f32> = None;
+        while let Ok(event) = worker.events.try_recv() {
+            match event {
+                MoshiEvent::Loaded => {
+                    info!("🗣️ Moshi loaded from {}", moshi_model::MODEL_DIR);
+                    commands.insert_resource(MoshiVoice { is_speaking: false, amplitude: 0.0 });
+                }
+                MoshiEvent::Failed(e) => {
+                    warn!("⚠️ Moshi unavailable: {}", e);
+                    commands.remove_resource::<MoshiVoice>();
+                }
+                MoshiEvent::Restarted(e) => warn!("⚠️ Moshi lost the conversation, starting a new one: {}", e),
+                MoshiEvent::Replied { amplitude, text } => {
+                    if !text.is_empty() {
+                        debug!("🧙 Moshi: {}", text);
+                    }
+                    loudest = Some(loudest.unwrap_or(0.0).max(amplitude));
+                }
+            }
+        }
+
+        let Some(mut voice) = voice else { return };
+        if voice.amplitude == 0.0 && loudest.is_none() { return; }
+        let decayed = (voice.amplitude - AMPLITUDE_DECAY * time.delta_secs()).max(0.0);
+        voice.amplitude = loudest.map_or(decayed, |loudest| loudest.max(decayed));
+        voice.is_speaking = voice.amplitude > SPEAKING_THRESHOLD;
+    }
+
+    /// Listens while Moshi is the backend and a game is being played, at the
+    /// voice bus volume
+    fn sync_listening(
+        settings: Res<GameSettings>,
+        (state, pause): (Res<State<GameState>>, Option<Res<State<PauseState>>>),
+        (voice, worker): (Option<Res<MoshiVoice>>, Option<Res<MoshiWorker>>),
+        mut listening: Local<bool>,
+        mut volume: Local<Option<f32>>,
+    ) {
+        let Some(worker) = worker.filter(|_| voice.is_some()) else { return };
+        let paused = pause.is_some_and(|p| *p.get() == PauseState::Paused);
+        let listen = settings.ai_backend == AiBackend::Moshi && *state.get() == GameState::Playing && !paused;
+        if listen != *listening && worker.commands.send(MoshiCommand::Listen(listen)).is_ok() {
+            *listening = listen;
+        }
+        let level = settings.audio.volume(Bus::Voice);
+        if *volume != Some(level) && worker.commands.send(MoshiCommand::Volume(level)).is_ok() {
+            *volume = Some(level);
+        }
+    }
+}
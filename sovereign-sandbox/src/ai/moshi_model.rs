@@ -0,0 +1,311 @@
+use anyhow::{Context, Result};
+use candle_core::{DType, Device, IndexOp, Tensor};
+use candle_transformers::generation::{LogitsProcessor, Sampling};
+use moshi::lm::LmModel;
+use moshi::lm_generate_multistream::{Config, State};
+use moshi::mimi::Mimi;
+use rubato::{FftFixedIn, Resampler};
+use sentencepiece::SentencePieceProcessor;
+use std::path::Path;
+
+use super::stt;
+
+// ============================================================================
+// Moshi — full-duplex speech in, speech out
+// ============================================================================
+// Loads the Moshi language model and the Mimi codec from the files
+// `download_model.sh` puts in `assets/models/moshi`. A `Conversation` takes
+// 80 ms frames of 24 kHz mono from the player and returns the teacher's
+// audio for the same stretch of time, plus any words of its inner monologue:
+// Mimi turns the frame into audio tokens, Moshi answers with text and audio
+// tokens, Mimi turns those back into sound. Both sides run at once, so the
+// teacher can be interrupted. `converse_file` runs a WAV file through the same
+// steps and writes the reply to another, for tests without audio devices.
+// Kept free of ECS types so it can be tested with no audio device.
+
+pub const MODEL_DIR: &str = "assets/models/moshi";
+
+/// Mimi's sample rate
+pub const SAMPLE_RATE: u32 = 24_000;
+
+/// Samples per Mimi frame (80 ms, 12.5 frames a second)
+pub const FRAME: usize = 1920;
+
+const LM_FILE: &str = "model.q8.gguf";
+const MIMI_FILE: &str = "tokenizer-e351c8d8-checkpoint125.safetensors";
+const TEXT_TOKENIZER_FILE: &str = "tokenizer_spm_32k_3.model";
+
+/// Longest conversation, in frames (about six minutes)
+const MAX_STEPS: usize = 4_500;
+
+/// Steps left when a conversation counts as full, more than one frame takes
+const FULL_MARGIN: usize = 8;
+
+const SEED: u64 = 299_792_458;
+
+/// Speech sits around 0.05-0.2 RMS; this brings it up to 0-1
+const AMPLITUDE_GAIN: f32 = 5.0;
+
+/// The model files, loaded once; each conversation gets its own state
+pub struct MoshiModel {
+    lm: LmModel,
+    mimi: Mimi,
+    text_tokenizer_path: std::path::PathBuf,
+    device: Device,
+}
+
+impl MoshiModel {
+    pub fn load(dir: &Path) -> Result<Self> {
+        let device = Device::Cpu;
+        let path = |file: &str| -> Result<std::path::PathBuf> {
+            let path = dir.join(file);
+            anyhow::ensure!(path.exists(), "missing {}", path.display());
+            Ok(path)
+        };
+        let lm = moshi::lm::load_lm_model(moshi::lm::Config::v0_1(), path(LM_FILE)?, DType::F32, &device)?;
+        let codebooks = Config::v0_1().generated_audio_codebooks;
+        let mimi_path = path(MIMI_FILE)?;
+        let mimi = moshi::mimi::load(&mimi_path.to_string_lossy(), Some(codebooks), &device)?;
+        Ok(Self { lm, mimi, text_tokenizer_path: path(TEXT_TOKENIZER_FILE)?, device })
+    }
+
+    /// A fresh conversation with nothing said yet
+    pub fn conversation(&self) -> Result<Conversation> {
+        let config = Config::v0_1();
+        let (text_pad, text_eop, text_start) = (config.text_pad_token, config.text_eop_token, config.text_start_token);
+        let codebooks = config.generated_audio_codebooks;
+        let mut lm = self.lm.clone();
+        lm.reset_state();
+        let mut mimi = self.mimi.clone();
+        mimi.reset_state();
+        let state = State::new(
+            lm,
+            MAX_STEPS,
+            LogitsProcessor::from_sampling(SEED, Sampling::TopK { k: 250, temperature: 0.8 }),
+            LogitsProcessor::from_sampling(SEED, Sampling::TopK { k: 25, temperature: 0.7 }),
+            None,
+            None,
+            None,
+            config,
+        );
+        let text_tokenizer = SentencePieceProcessor::open(&self.text_tokenizer_path)
+            .map_err(|e| anyhow::anyhow!("reading {}: {}", self.text_tokenizer_path.display(), e))?;
+        Ok(Conversation {
+            state,
+            mimi,
+            text_tokenizer,
+            device: self.device.clone(),
+            codebooks,
+            text_pad,
+            text_eop,
+            last_text: text_start,
+            steps: 0,
+            said: Vec::new(),
+            said_text: String::new(),
+        })
+    }
+}
+
+/// What the teacher said during one frame
+#[derive(Debug, Default)]
+pub struct Reply {
+    /// 24 kHz mono; empty while the codec is still filling up
+    pub pcm: Vec<f32>,
+    /// New words, if any
+    pub text: String,
+}
+
+pub struct Conversation {
+    state: State,
+    mimi: Mimi,
+    text_tokenizer: SentencePieceProcessor,
+    device: Device,
+    codebooks: usize,
+    text_pad: u32,
+    text_eop: u32,
+    last_text: u32,
+    /// Model steps taken, up to `MAX_STEPS`
+    steps: usize,
+    /// Text tokens spoken so far, decoded together so spacing comes out right
+    said: Vec<u32>,
+    said_text: String,
+}
+
+impl Conversation {
+    /// Hears one frame of the player and answers it
+    pub fn step(&mut self, frame: &[f32]) -> Result<Reply> {
+        let pcm = Tensor::from_slice(frame, (1, 1, frame.len()), &self.device)?;
+        let codes = self.mimi.encode_step(&pcm.into(), &().into())?;
+        let mut reply = Reply::default();
+        let Some(codes) = codes.as_option() else { return Ok(reply) };
+
+        let (_, _, steps) = codes.dims3()?;
+        for i in 0..steps {
+            let heard = codes.i((0, .., i))?.to_vec1::<u32>()?;
+            let text = self.state.step_(Some(self.last_text), &heard, None, None, None)?;
+            self.steps += 1;
+            self.last_text = text;
+            if text != self.text_pad && text != self.text_eop {
+                self.said.push(text);
+            }
+            let Some(audio) = self.state.last_audio_tokens() else { continue };
+            let audio = Tensor::new(&audio[..self.codebooks], &self.device)?.reshape((1, (), 1))?;
+            let pcm = self.mimi.decode_step(&audio.into(), &().into())?;
+            if let Some(pcm) = pcm.as_option() {
+                reply.pcm.extend(pcm.i((0, 0))?.to_vec1::<f32>()?);
+            }
+        }
+
+        let said = self.text_tokenizer.decode_piece_ids(&self.said)
+            .map_err(|e| anyhow::anyhow!("decoding Moshi text: {}", e))?;
+        if let Some(new) = said.strip_prefix(self.said_text.as_str()) {
+            reply.text = new.to_string();
+        }
+        self.said_text = said;
+        Ok(reply)
+    }
+
+    /// Whether the model is nearly out of steps; a full conversation has to
+    /// be replaced with a fresh one before the next frame
+    pub fn is_full(&self) -> bool {
+        self.steps + FULL_MARGIN >= MAX_STEPS
+    }
+
+    /// Everything the teacher has said so far
+    pub fn transcript(&self) -> &str {
+        &self.said_text
+    }
+}
+
+/// 0-1 loudness of a stretch of audio, for the teacher's pulse
+pub fn amplitude(pcm: &[f32]) -> f32 {
+    if pcm.is_empty() {
+        return 0.0;
+    }
+    let rms = (pcm.iter().map(|s| s * s).sum::<f32>() / pcm.len() as f32).sqrt();
+    (rms * AMPLITUDE_GAIN).min(1.0)
+}
+
+/// Converts a live stream between rates, a chunk at a time
+pub struct StreamResampler {
+    resampler: Option<FftFixedIn<f32>>,
+    pending: Vec<f32>,
+}
+
+impl StreamResampler {
+    pub fn new(rate: u32, target: u32) -> Result<Self> {
+        let resampler = (rate != target)
+            .then(|| FftFixedIn::<f32>::new(rate as usize, target as usize, 1024, 2, 1))
+            .transpose()?;
+        Ok(Self { resampler, pending: Vec::new() })
+    }
+
+    /// Whatever can be converted so far; the rest waits for the next push
+    pub fn push(&mut self, samples: &[f32]) -> Result<Vec<f32>> {
+        let Some(resampler) = &mut self.resampler else { return Ok(samples.to_vec()) };
+        self.pending.extend_from_slice(samples);
+        let mut out = Vec::new();
+        while self.pending.len() >= resampler.input_frames_next() {
+            let chunk: Vec<f32> = self.pending.drain(..resampler.input_frames_next()).collect();
+            out.extend_from_slice(&resampler.process(&[chunk], None)?[0]);
+        }
+        Ok(out)
+    }
+}
+
+/// Plays `input` to Moshi as if spoken and writes what it says back to
+/// `output` (24 kHz mono). Returns the reply's text and loudness per frame.
+pub fn converse_file(model: &MoshiModel, input: &Path, output: &Path) -> Result<(String, Vec<f32>)> {
+    let (samples, rate) = stt::read_wav(input)?;
+    let mut pcm = stt::resample_to(&samples, rate, SAMPLE_RATE)?;
+    pcm.resize(pcm.len().div_ceil(FRAME) * FRAME, 0.0);
+
+    let mut conversation = model.conversation()?;
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: SAMPLE_RATE,
+        bits_per_sample: 32,
+        sample_format: hound::SampleFormat::Float,
+    };
+    let mut writer = hound::WavWriter::create(output, spec).with_context(|| format!("creating {}", output.display()))?;
+    let mut amplitudes = Vec::with_capacity(pcm.len() / FRAME);
+    let mut transcript = String::new();
+    for frame in pcm.chunks_exact(FRAME) {
+        if conversation.is_full() {
+            transcript.push_str(conversation.transcript());
+            conversation = model.conversation()?;
+        }
+        let reply = conversation.step(frame)?;
+        amplitudes.push(amplitude(&reply.pcm));
+        for sample in reply.pcm {
+            writer.write_sample(sample)?;
+        }
+    }
+    writer.finalize()?;
+    transcript.push_str(conversation.transcript());
+    Ok((transcript, amplitudes))
+}
+
+#[cfg(test)]
+mod tests {
+    // Moshi without audio devices: a WAV file in, the teacher's reply out.
+    // Loudness and resampling always run; the conversation runs when the Moshi
+    // files from `download_model.sh` are in `assets/models/moshi`, and is skipped
+    // otherwise. Needs `cargo test --features moshi`.
+    use super::*;
+    use std::path::Path;
+
+    const JFK: &str = "assets/jfk.wav";
+
+    fn tone(rate: u32, seconds: f32) -> Vec<f32> {
+        (0..(rate as f32 * seconds) as usize)
+            .map(|i| 0.3 * (std::f32::consts::TAU * 220.0 * i as f32 / rate as f32).sin())
+            .collect()
+    }
+
+    #[test]
+    fn amplitude_follows_loudness() {
+        assert_eq!(amplitude(&[]), 0.0);
+        assert_eq!(amplitude(&[0.0; FRAME]), 0.0);
+        let speech = amplitude(&tone(SAMPLE_RATE, 0.08));
+        assert!((0.5..=1.0).contains(&speech), "amplitude {}", speech);
+    }
+
+    #[test]
+    fn streamed_resampling_keeps_duration() {
+        let input = tone(48_000, 2.0);
+        let mut resampler = StreamResampler::new(48_000, SAMPLE_RATE).expect("resampler");
+        let mut out = Vec::new();
+        // Microphone-sized chunks that don't line up with the resampler's
+        for chunk in input.chunks(441) {
+            out.extend(resampler.push(chunk).expect("resample"));
+        }
+        // Whatever is still waiting for a full chunk is less than one
+        let expected = SAMPLE_RATE as usize * 2;
+        assert!(out.len() <= expected && expected - out.len() < 1024, "{} samples", out.len());
+
+        let mut same = StreamResampler::new(24_000, 24_000).expect("resampler");
+        assert_eq!(same.push(&[0.1, 0.2]).expect("passthrough"), vec![0.1, 0.2]);
+    }
+
+    #[test]
+    fn answers_jfk() {
+        let dir = Path::new(MODEL_DIR);
+        if !dir.join("model.q8.gguf").exists() {
+            eprintln!("skipping: no Moshi model in {} (run download_model.sh)", dir.display());
+            return;
+        }
+        let model = MoshiModel::load(dir).expect("Moshi model");
+        let output = std::env::temp_dir().join("sovereign-moshi-reply.wav");
+        let (_, amplitudes) = converse_file(&model, Path::new(JFK), &output).expect("conversation");
+
+        let (reply, rate) = stt::read_wav(&output).expect("reply wav");
+        assert_eq!(rate, SAMPLE_RATE);
+        let (heard, heard_rate) = stt::read_wav(Path::new(JFK)).expect("jfk.wav");
+        let frames = (heard.len() as f32 / heard_rate as f32 * SAMPLE_RATE as f32 / FRAME as f32).ceil() as usize;
+        assert_eq!(amplitudes.len(), frames);
+        // The codec takes a frame or two to fill, then Moshi talks back in step
+        assert!(reply.len() >= (frames - 2) * FRAME, "{} samples for {} frames", reply.len(), frames);
+        assert!(amplitudes.iter().any(|&a| a > 0.0), "Moshi stayed silent");
+    }
+}
@@ -0,0 +1,83 @@
+use anyhow::{bail, Context, Result};
+use bevy::log::warn;
+use cpal::traits::{DeviceTrait, HostTrait, StreamTrait};
+use cpal::{FromSample, SizedSample};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+// ============================================================================
+// Speaker — streamed playback via cpal
+// ============================================================================
+// The default output device, fed from a queue: `play` appends mono samples at
+// the device's rate and the stream plays them on every channel, silence when
+// the queue runs dry. The counterpart of `microphone` for audio that's
+// generated as it plays (Moshi's replies), which a finished `AudioSource`
+// can't carry. Lives on the worker thread that fills it, like the microphone.
+
+pub struct Speaker {
+    /// Plays for as long as it's kept
+    _stream: cpal::Stream,
+    rate: u32,
+    shared: Arc<Mutex<Playback>>,
+}
+
+struct Playback {
+    queue: VecDeque<f32>,
+    volume: f32,
+}
+
+impl Speaker {
+    pub fn open() -> Result<Self> {
+        let device = cpal::default_host().default_output_device().context("no speaker found")?;
+        let config = device.default_output_config()?;
+        let shared = Arc::new(Mutex::new(Playback { queue: VecDeque::new(), volume: 1.0 }));
+        let stream = match config.sample_format() {
+            cpal::SampleFormat::F32 => build::<f32>(&device, &config.config(), shared.clone())?,
+            cpal::SampleFormat::I16 => build::<i16>(&device, &config.config(), shared.clone())?,
+            cpal::SampleFormat::U16 => build::<u16>(&device, &config.config(), shared.clone())?,
+            format => bail!("unsupported speaker format {:?}", format),
+        };
+        stream.play()?;
+        Ok(Self { _stream: stream, rate: config.sample_rate().0, shared })
+    }
+
+    /// Samples `play` expects per second
+    pub fn rate(&self) -> u32 {
+        self.rate
+    }
+
+    /// Queues mono samples after whatever is still playing
+    pub fn play(&self, samples: &[f32]) {
+        self.shared.lock().unwrap().queue.extend(samples);
+    }
+
+    /// Drops whatever hasn't played yet
+    pub fn clear(&self) {
+        self.shared.lock().unwrap().queue.clear();
+    }
+
+    pub fn set_volume(&self, volume: f32) {
+        self.shared.lock().unwrap().volume = volume;
+    }
+}
+
+fn build<T>(device: &cpal::Device, config: &cpal::StreamConfig, shared: Arc<Mutex<Playback>>) -> Result<cpal::Stream>
+where
+    T: SizedSample + FromSample<f32>,
+{
+    let channels = config.channels as usize;
+    let stream = device.build_output_stream(
+        config,
+        move |data: &mut [T], _: &cpal::OutputCallbackInfo| {
+            let mut playback = shared.lock().unwrap();
+            let volume = playback.volume;
+            for frame in data.chunks_mut(channels) {
+                let sample = playback.queue.pop_front().unwrap_or(0.0) * volume;
+                frame.fill(T::from_sample(sample));
+            }
+        },
+        |e| warn!("⚠️ Speaker error: {}", e),
+        None,
+    )?;
+    Ok(stream)
+}
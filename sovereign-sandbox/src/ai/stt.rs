@@ -0,0 +1,218 @@
+use anyhow::{Context, Result};
+use candle_core::{Device, IndexOp, Tensor};
+use candle_nn::VarBuilder;
+use candle_transformers::models::whisper::{self as m, audio, model::Whisper, Config};
+use rubato::{FftFixedIn, Resampler};
+use std::path::Path;
+use tokenizers::Tokenizer;
+
+// ============================================================================
+// Speech to Text — offline Whisper via candle
+// ============================================================================
+// Loads Whisper from the files `download_model.sh` puts in `assets/models`
+// and transcribes 16 kHz mono audio with greedy decoding, one 30-second window
+// at a time. WAV files are read and resampled here too, so transcription can
+// run on a recording as well as the microphone (see `microphone`). Kept free
+// of ECS types so it can be tested on a WAV file with no audio device.
+
+pub const MODEL_DIR: &str = "assets/models";
+
+/// Whisper listens at 16 kHz
+pub const SAMPLE_RATE: u32 = m::SAMPLE_RATE as u32;
+
+const CONFIG_FILE: &str = "config.json";
+const WEIGHTS_FILE: &str = "model.safetensors";
+const TOKENIZER_FILE: &str = "whisper_tokenizer.json";
+const MEL_FILTERS_FILE: &str = "melfilters.bytes";
+
+pub struct Transcriber {
+    model: Whisper,
+    tokenizer: Tokenizer,
+    mel_filters: Vec<f32>,
+    /// Added to the logits: minus infinity for tokens never to emit
+    suppress: Tensor,
+    device: Device,
+}
+
+impl Transcriber {
+    pub fn load(dir: &Path) -> Result<Self> {
+        let device = Device::Cpu;
+        let config: Config = serde_json::from_str(&std::fs::read_to_string(dir.join(CONFIG_FILE))
+            .with_context(|| format!("reading {}", dir.join(CONFIG_FILE).display()))?)?;
+        let tokenizer = Tokenizer::from_file(dir.join(TOKENIZER_FILE)).map_err(anyhow::Error::msg)?;
+
+        let bytes = std::fs::read(dir.join(MEL_FILTERS_FILE))
+            .with_context(|| format!("reading {}", dir.join(MEL_FILTERS_FILE).display()))?;
+        let mel_filters = bytes.chunks_exact(4).map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect();
+
+        let weights = std::fs::read(dir.join(WEIGHTS_FILE))
+            .with_context(|| format!("reading {}", dir.join(WEIGHTS_FILE).display()))?;
+        let vb = VarBuilder::from_buffered_safetensors(weights, m::DTYPE, &device)?;
+
+        let no_timestamps = token_id(&tokenizer, m::NO_TIMESTAMPS_TOKEN)?;
+        let suppress: Vec<f32> = (0..config.vocab_size as u32)
+            .map(|i| if config.suppress_tokens.contains(&i) || i == no_timestamps { f32::NEG_INFINITY } else { 0.0 })
+            .collect();
+        let suppress = Tensor::new(suppress.as_slice(), &device)?;
+
+        let model = Whisper::load(&vb, config)?;
+        Ok(Self { model, tokenizer, mel_filters, suppress, device })
+    }
+
+    /// Text spoken in `pcm` (16 kHz mono), in `language` ("en", "es"...)
+    pub fn transcribe(&mut self, pcm: &[f32], language: &str) -> Result<String> {
+        let mel = audio::pcm_to_mel(&self.model.config, pcm, &self.mel_filters);
+        let bins = self.model.config.num_mel_bins;
+        let frames = mel.len() / bins;
+        let mel = Tensor::from_vec(mel, (1, bins, frames), &self.device)?;
+
+        let prompt = [
+            token_id(&self.tokenizer, m::SOT_TOKEN)?,
+            token_id(&self.tokenizer, &format!("<|{}|>", language))?,
+            token_id(&self.tokenizer, m::TRANSCRIBE_TOKEN)?,
+            token_id(&self.tokenizer, m::NO_TIMESTAMPS_TOKEN)?,
+        ];
+        let mut text = String::new();
+        let mut seek = 0;
+        // The mel is padded with silence; windows start only where there's audio
+        let content = pcm.len() / m::HOP_LENGTH;
+        while seek < content {
+            let size = (frames - seek).min(m::N_FRAMES);
+            let segment = self.decode(&mel.narrow(2, seek, size)?, &prompt)?;
+            text.push_str(segment.trim());
+            text.push(' ');
+            seek += size;
+        }
+        Ok(text.trim().to_string())
+    }
+
+    /// Greedy decoding of one window
+    fn decode(&mut self, mel: &Tensor, prompt: &[u32]) -> Result<String> {
+        let eot = token_id(&self.tokenizer, m::EOT_TOKEN)?;
+        let features = self.model.encoder.forward(mel, true)?;
+        let max_len = self.model.config.max_target_positions / 2;
+
+        let mut tokens = prompt.to_vec();
+        for i in 0..max_len {
+            let input = Tensor::new(tokens.as_slice(), &self.device)?.unsqueeze(0)?;
+            let ys = self.model.decoder.forward(&input, &features, i == 0)?;
+            let (_, seq_len, _) = ys.dims3()?;
+            let logits = self.model.decoder.final_linear(&ys.i((..1, seq_len - 1..))?)?.i(0)?.i(0)?;
+            let next = logits.broadcast_add(&self.suppress)?.argmax(0)?.to_scalar::<u32>()?;
+            if next == eot {
+                break;
+            }
+            tokens.push(next);
+        }
+        self.tokenizer.decode(&tokens[prompt.len()..], true).map_err(anyhow::Error::msg)
+    }
+}
+
+fn token_id(tokenizer: &Tokenizer, token: &str) -> Result<u32> {
+    tokenizer.token_to_id(token).with_context(|| format!("no token {} in the Whisper tokenizer", token))
+}
+
+/// Mono samples from a WAV file, and its sample rate
+pub fn read_wav(path: &Path) -> Result<(Vec<f32>, u32)> {
+    let mut reader = hound::WavReader::open(path).with_context(|| format!("opening {}", path.display()))?;
+    let spec = reader.spec();
+    let samples: Vec<f32> = match spec.sample_format {
+        hound::SampleFormat::Float => reader.samples::<f32>().collect::<Result<_, _>>()?,
+        hound::SampleFormat::Int => {
+            let scale = 1.0 / (1_i64 << (spec.bits_per_sample - 1)) as f32;
+            reader.samples::<i32>().map(|s| s.map(|s| s as f32 * scale)).collect::<Result<_, _>>()?
+        }
+    };
+    Ok((downmix(&samples, spec.channels as usize), spec.sample_rate))
+}
+
+/// Averages interleaved channels into one
+pub fn downmix(samples: &[f32], channels: usize) -> Vec<f32> {
+    if channels <= 1 {
+        return samples.to_vec();
+    }
+    samples.chunks(channels).map(|frame| frame.iter().sum::<f32>() / channels as f32).collect()
+}
+
+/// `samples` at `rate` converted to Whisper's 16 kHz
+pub fn resample(samples: &[f32], rate: u32) -> Result<Vec<f32>> {
+    resample_to(samples, rate, SAMPLE_RATE)
+}
+
+/// `samples` at `rate` converted to `target`
+pub fn resample_to(samples: &[f32], rate: u32, target: u32) -> Result<Vec<f32>> {
+    if rate == target {
+        return Ok(samples.to_vec());
+    }
+    let mut resampler = FftFixedIn::<f32>::new(rate as usize, target as usize, 1024, 2, 1)?;
+    let expected = samples.len() * target as usize / rate as usize;
+    let mut out = Vec::with_capacity(expected + resampler.output_delay());
+
+    let mut chunks = samples.chunks_exact(resampler.input_frames_next());
+    for chunk in &mut chunks {
+        out.extend_from_slice(&resampler.process(&[chunk], None)?[0]);
+    }
+    out.extend_from_slice(&resampler.process_partial(Some(&[chunks.remainder()]), None)?[0]);
+    // Flush what the filter is still holding
+    while out.len() < expected + resampler.output_delay() {
+        out.extend_from_slice(&resampler.process_partial::<&[f32]>(None, None)?[0]);
+    }
+    Ok(out.into_iter().skip(resampler.output_delay()).take(expected).collect())
+}
+
+/// A WAV file ready for `Transcriber::transcribe`
+pub fn load_wav(path: &Path) -> Result<Vec<f32>> {
+    let (samples, rate) = read_wav(path)?;
+    resample(&samples, rate)
+}
+
+#[cfg(test)]
+mod tests {
+    // Speech-to-text from WAV files, no microphone needed. Reading and resampling
+    // always run; transcription runs when the Whisper files from
+    // `download_model.sh` are in `assets/models`, and is skipped otherwise.
+    use super::*;
+
+    use std::path::Path;
+
+    const JFK: &str = "assets/jfk.wav";
+
+    #[test]
+    fn wav_loads_at_whisper_rate() {
+        let (samples, rate) = read_wav(Path::new(JFK)).expect("jfk.wav");
+        let pcm = load_wav(Path::new(JFK)).expect("jfk.wav");
+        let seconds = samples.len() as f32 / rate as f32;
+        assert_eq!(pcm.len(), (seconds * SAMPLE_RATE as f32) as usize);
+        assert!(pcm.iter().any(|s| s.abs() > 0.1), "jfk.wav read as silence");
+    }
+
+    #[test]
+    fn resampling_keeps_duration_and_pitch() {
+        let rate = 44_100;
+        let tone: Vec<f32> = (0..rate).map(|i| (std::f32::consts::TAU * 440.0 * i as f32 / rate as f32).sin()).collect();
+        let pcm = resample(&tone, rate as u32).expect("resample");
+        assert_eq!(pcm.len(), SAMPLE_RATE as usize);
+
+        // 440 Hz crosses zero 880 times a second
+        let crossings = pcm.windows(2).filter(|w| w[0].signum() != w[1].signum()).count();
+        assert!((860..=900).contains(&crossings), "{} zero crossings", crossings);
+    }
+
+    #[test]
+    fn stereo_downmixes_to_mono() {
+        assert_eq!(downmix(&[1.0, 0.0, 0.5, 0.5], 2), vec![0.5, 0.5]);
+    }
+
+    #[test]
+    fn transcribes_jfk() {
+        let dir = Path::new(MODEL_DIR);
+        if !dir.join("model.safetensors").exists() {
+            eprintln!("skipping: no Whisper model in {} (run download_model.sh)", dir.display());
+            return;
+        }
+        let mut transcriber = Transcriber::load(dir).expect("Whisper model");
+        let pcm = load_wav(Path::new(JFK)).expect("jfk.wav");
+        let text = transcriber.transcribe(&pcm, "en").expect("transcription").to_lowercase();
+        assert!(text.contains("ask not what your country can do for you"), "heard: {}", text);
+    }
+}
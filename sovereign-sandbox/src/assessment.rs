@@ -0,0 +1,357 @@
+use bevy::prelude::*;
+use serde::Serialize;
+use crate::GameState;
+use crate::actions::{Actions, InputContext};
+use crate::syllabus::{QuizItem, SyllabusResource};
+use crate::i18n::Locale;
+
+// ============================================================================
+// Pre-Test / Post-Test — evidence that the game teaches something
+// ============================================================================
+// The pretest runs between Boot and Playing, the posttest between the last
+// module and Victory. Both are skipped when the syllabus declares no items.
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TestKind {
+    Pre,
+    Post,
+}
+
+impl TestKind {
+    /// Message id of the overlay title
+    fn title(&self) -> &'static str {
+        match self {
+            TestKind::Pre => "test-pre-title",
+            TestKind::Post => "test-post-title",
+        }
+    }
+
+    fn next_state(&self) -> GameState {
+        match self {
+            TestKind::Pre => GameState::Playing,
+            TestKind::Post => GameState::Victory,
+        }
+    }
+}
+
+/// One answered test item
+#[derive(Debug, Clone, Serialize)]
+pub struct ItemResult {
+    pub question: String,
+    pub chosen: usize,
+    pub correct: bool,
+    pub objectives: Vec<String>,
+}
+
+#[derive(Resource, Default)]
+pub struct TestResults {
+    pub pretest: Vec<ItemResult>,
+    pub posttest: Vec<ItemResult>,
+}
+
+impl TestResults {
+    /// Hake's normalized gain: (post − pre) / (1 − pre). Undefined when the
+    /// learner already scored 100% on the pretest.
+    pub fn normalized_gain(pre: f32, post: f32) -> Option<f32> {
+        if pre >= 1.0 { return None; }
+        Some((post - pre) / (1.0 - pre))
+    }
+
+    /// Fraction correct, optionally restricted to items tagged with an objective
+    fn score(results: &[ItemResult], objective: Option<&str>) -> Option<f32> {
+        let items: Vec<&ItemResult> = results.iter()
+            .filter(|r| objective.is_none_or(|id| r.objectives.iter().any(|o| o == id)))
+            .collect();
+        if items.is_empty() { return None; }
+        Some(items.iter().filter(|r| r.correct).count() as f32 / items.len() as f32)
+    }
+
+    pub fn pretest_score(&self) -> Option<f32> {
+        Self::score(&self.pretest, None)
+    }
+
+    pub fn posttest_score(&self) -> Option<f32> {
+        Self::score(&self.posttest, None)
+    }
+
+    pub fn overall_gain(&self) -> Option<f32> {
+        Self::normalized_gain(self.pretest_score()?, self.posttest_score()?)
+    }
+
+    /// Normalized gain for one objective, from the items tagged with it
+    pub fn objective_gain(&self, objective: &str) -> Option<f32> {
+        let pre = Self::score(&self.pretest, Some(objective))?;
+        let post = Self::score(&self.posttest, Some(objective))?;
+        Self::normalized_gain(pre, post)
+    }
+
+    pub fn clear(&mut self) {
+        self.pretest.clear();
+        self.posttest.clear();
+    }
+}
+
+/// The test currently on screen
+#[derive(Resource)]
+pub struct ActiveTest {
+    kind: TestKind,
+    items: Vec<QuizItem>,
+    current: usize,
+}
+
+impl ActiveTest {
+    /// Options of the item on screen
+    pub fn options(&self) -> &[String] {
+        self.items.get(self.current).map(|item| item.options.as_slice()).unwrap_or_default()
+    }
+}
+
+#[derive(Component)]
+struct TestOverlay;
+
+#[derive(Component)]
+struct TestItemText;
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub struct AssessmentPlugin;
+
+impl Plugin for AssessmentPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TestResults>()
+           .add_systems(OnEnter(GameState::PreTest), start_pretest)
+           .add_systems(OnEnter(GameState::PostTest), start_posttest)
+           .add_systems(Update, (handle_test_input, update_test_overlay)
+               .chain()
+               .run_if(in_state(GameState::PreTest).or(in_state(GameState::PostTest))));
+    }
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+fn start_pretest(
+    commands: Commands,
+    syllabus: Option<Res<SyllabusResource>>,
+    next_state: ResMut<NextState<GameState>>,
+    popup_active: ResMut<crate::ui::knowledge_popup::PopupActive>,
+    locale: Res<Locale>,
+) {
+    let items = syllabus.map(|s| s.syllabus.pretest.clone()).unwrap_or_default();
+    start_test(TestKind::Pre, items, commands, next_state, popup_active, &locale);
+}
+
+fn start_posttest(
+    commands: Commands,
+    syllabus: Option<Res<SyllabusResource>>,
+    next_state: ResMut<NextState<GameState>>,
+    popup_active: ResMut<crate::ui::knowledge_popup::PopupActive>,
+    locale: Res<Locale>,
+) {
+    let items = syllabus.map(|s| s.syllabus.posttest.clone()).unwrap_or_default();
+    start_test(TestKind::Post, items, commands, next_state, popup_active, &locale);
+}
+
+fn start_test(
+    kind: TestKind,
+    items: Vec<QuizItem>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut popup_active: ResMut<crate::ui::knowledge_popup::PopupActive>,
+    locale: &Locale,
+) {
+    if items.is_empty() {
+        next_state.set(kind.next_state());
+        return;
+    }
+
+    info!("📋 {:?}-test started: {} items", kind, items.len());
+    // Block player movement while the test is on screen
+    popup_active.0 = true;
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(20.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.02, 0.02, 0.04, 0.97)),
+        GlobalZIndex(200),
+        TestOverlay,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(locale.t(kind.title())),
+            TextFont { font_size: 28.0, ..default() },
+            TextColor(Color::srgb(0.55, 0.36, 0.96)),
+        ));
+
+        parent.spawn((
+            Text::new(""),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            Node { max_width: Val::Px(720.0), ..default() },
+            TestItemText,
+            crate::narration::Narrated::default(),
+        ));
+
+        parent.spawn((
+            Text::new(locale.t("test-hint")),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.5, 0.5)),
+        ));
+    });
+
+    commands.insert_resource(ActiveTest { kind, items, current: 0 });
+}
+
+fn handle_test_input(
+    mut commands: Commands,
+    actions: Res<Actions>,
+    test: Option<ResMut<ActiveTest>>,
+    mut results: ResMut<TestResults>,
+    mut next_state: ResMut<NextState<GameState>>,
+    mut popup_active: ResMut<crate::ui::knowledge_popup::PopupActive>,
+    overlay_query: Query<Entity, With<TestOverlay>>,
+) {
+    let Some(mut test) = test else { return };
+    let Some(item) = test.items.get(test.current).cloned() else { return };
+
+    let Some(chosen) = actions.choice(InputContext::Test) else { return };
+    if chosen >= item.options.len() { return; }
+
+    let result = ItemResult {
+        question: item.question.clone(),
+        chosen,
+        correct: chosen == item.correct_index,
+        objectives: item.objectives.clone(),
+    };
+    match test.kind {
+        TestKind::Pre => results.pretest.push(result),
+        TestKind::Post => results.posttest.push(result),
+    }
+
+    test.current += 1;
+    if test.current >= test.items.len() {
+        let score = match test.kind {
+            TestKind::Pre => results.pretest_score(),
+            TestKind::Post => results.posttest_score(),
+        };
+        info!("📋 {:?}-test complete: {:.0}% correct", test.kind, score.unwrap_or(0.0) * 100.0);
+
+        for entity in &overlay_query {
+            commands.entity(entity).despawn_recursive();
+        }
+        commands.remove_resource::<ActiveTest>();
+        popup_active.0 = false;
+        next_state.set(test.kind.next_state());
+    }
+}
+
+fn update_test_overlay(
+    test: Option<Res<ActiveTest>>,
+    locale: Res<Locale>,
+    mut query: Query<&mut Text, With<TestItemText>>,
+) {
+    let Some(test) = test else { return };
+    if !test.is_changed() { return; }
+    let Some(item) = test.items.get(test.current) else { return };
+
+    let options = item.options.iter().enumerate()
+        .map(|(i, opt)| format!("[{}] {}", i + 1, opt))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let body = format!(
+        "{}\n\n{}\n\n{}",
+        locale.fmt("test-progress", &[("n", &(test.current + 1)), ("count", &test.items.len())]),
+        item.question,
+        options
+    );
+
+    for mut text in &mut query {
+        *text = Text::new(body.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(correct: bool, objectives: &[&str]) -> ItemResult {
+        ItemResult {
+            question: String::new(),
+            chosen: 0,
+            correct,
+            objectives: objectives.iter().map(|o| o.to_string()).collect(),
+        }
+    }
+
+    fn close(gain: Option<f32>, expected: f32) -> bool {
+        gain.is_some_and(|gain| (gain - expected).abs() < 1e-6)
+    }
+
+    #[test]
+    fn typical_gain() {
+        assert!(close(TestResults::normalized_gain(0.4, 0.7), 0.5));
+        assert!(close(TestResults::normalized_gain(0.0, 1.0), 1.0));
+        assert!(close(TestResults::normalized_gain(0.5, 0.5), 0.0));
+
+        // 1 of 4 before, 3 of 4 after: (0.75 − 0.25) / 0.75
+        let results = TestResults {
+            pretest: vec![item(true, &[]), item(false, &[]), item(false, &[]), item(false, &[])],
+            posttest: vec![item(true, &[]), item(true, &[]), item(true, &[]), item(false, &[])],
+        };
+        assert!(close(results.pretest_score(), 0.25));
+        assert!(close(results.posttest_score(), 0.75));
+        assert!(close(results.overall_gain(), 2.0 / 3.0));
+    }
+
+    #[test]
+    fn perfect_pretest_has_no_gain() {
+        assert_eq!(TestResults::normalized_gain(1.0, 1.0), None);
+        assert_eq!(TestResults::normalized_gain(1.0, 0.5), None);
+
+        let results = TestResults {
+            pretest: vec![item(true, &[]), item(true, &[])],
+            posttest: vec![item(true, &[]), item(false, &[])],
+        };
+        assert_eq!(results.overall_gain(), None);
+    }
+
+    #[test]
+    fn forgetting_is_a_negative_gain() {
+        assert!(close(TestResults::normalized_gain(0.5, 0.25), -0.5));
+
+        let results = TestResults {
+            pretest: vec![item(true, &[]), item(true, &[]), item(false, &[]), item(false, &[])],
+            posttest: vec![item(false, &[]), item(false, &[]), item(false, &[]), item(false, &[])],
+        };
+        assert!(close(results.overall_gain(), -1.0));
+    }
+
+    #[test]
+    fn objectives_need_items_in_both_tests() {
+        let results = TestResults {
+            pretest: vec![item(false, &["loops"]), item(true, &["loops", "ferpa"]), item(false, &["quantization"])],
+            posttest: vec![item(true, &["loops"]), item(true, &["loops"]), item(true, &["ferpa"])],
+        };
+        // Only the items tagged with the objective count
+        assert!(close(results.objective_gain("loops"), 1.0));
+        // Already known before the game
+        assert_eq!(results.objective_gain("ferpa"), None);
+        // Tested before but not after, and never tested
+        assert_eq!(results.objective_gain("quantization"), None);
+        assert_eq!(results.objective_gain("constructivism"), None);
+
+        let empty = TestResults::default();
+        assert_eq!(empty.pretest_score(), None);
+        assert_eq!(empty.overall_gain(), None);
+    }
+}
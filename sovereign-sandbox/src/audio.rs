@@ -1,17 +1,154 @@
+use bevy::audio::Volume;
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use crate::ai::moshi::MoshiVoice;
+use crate::game_world::{ActiveMap, Player, TILE};
+use crate::map::MapData;
+use crate::settings::GameSettings;
+use crate::sfx_synth::{self, SfxParams};
+use crate::syllabus::SyllabusResource;
+use crate::teacher::TeacherState;
+
+// ============================================================================
+// Audio Mixer — buses, ducking and music cues
+// ============================================================================
+// Every sound plays on a bus (music, SFX, voice, ambient) tagged with
+// `AudioBus`; its volume and mute come from `GameSettings` and are applied to
+// every playing sink each frame. Music and ambience duck while the teacher is
+// speaking or anything plays on the voice bus. Effects are synthesized from
+// the presets in `assets/audio/sfx.toml` at startup. The music track follows
+// `assets/audio/music.toml` (quest phase type, then room, then zone) and
+// crossfades when it changes. Fades run on real time, so they finish while
+// the game is paused.
+
+/// Music and ambience level while someone is speaking
+const DUCK_LEVEL: f32 = 0.3;
+
+/// How fast ducking moves, in volume per second
+const DUCK_SPEED: f32 = 2.5;
+
+const CROSSFADE_SECS: f32 = 2.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Bus {
+    Music,
+    Sfx,
+    Voice,
+    Ambient,
+}
+
+impl Bus {
+    pub const ALL: [Bus; 4] = [Bus::Music, Bus::Sfx, Bus::Voice, Bus::Ambient];
+
+    /// Quieted while someone speaks
+    fn ducks(&self) -> bool {
+        matches!(self, Bus::Music | Bus::Ambient)
+    }
+}
+
+/// The bus a sound plays on; spawn it next to the `AudioPlayer`
+#[derive(Component, Debug, Clone, Copy)]
+pub struct AudioBus(pub Bus);
 
 #[derive(Event)]
 pub enum SfxEvent {
     Hit,
     Death,
     LevelUp,
+    QuestAdvance,
+    ToolUnlock,
+    QuizCorrect,
+    QuizWrong,
+    PuzzleError,
 }
 
-#[derive(Resource)]
+impl SfxEvent {
+    /// Its preset in `assets/audio/sfx.toml`
+    fn preset(&self) -> &'static str {
+        match self {
+            SfxEvent::Hit => "hit",
+            SfxEvent::Death => "death",
+            SfxEvent::LevelUp => "level_up",
+            SfxEvent::QuestAdvance => "quest_advance",
+            SfxEvent::ToolUnlock => "tool_unlock",
+            SfxEvent::QuizCorrect => "quiz_correct",
+            SfxEvent::QuizWrong => "quiz_wrong",
+            SfxEvent::PuzzleError => "puzzle_error",
+        }
+    }
+}
+
+/// Synthesized effects, by preset name
+#[derive(Resource, Default)]
 pub struct SfxAssets {
-    pub hit: Handle<AudioSource>,
-    pub death: Handle<AudioSource>,
-    pub level_up: Handle<AudioSource>,
+    pub sounds: HashMap<String, Handle<AudioSource>>,
+}
+
+/// Renders `params` into a playable sound
+pub fn synthesize(params: &SfxParams, seed: u64) -> AudioSource {
+    let wav = sfx_synth::to_wav(&sfx_synth::render(params, seed));
+    AudioSource { bytes: Arc::from(wav) }
+}
+
+// ============================================================================
+// Music Cues
+// ============================================================================
+
+/// Which track plays where, from `assets/audio/music.toml`
+#[derive(Resource, Debug, Default, Deserialize)]
+pub struct MusicCues {
+    /// Played when nothing more specific matches
+    pub default: String,
+    /// By quest phase type (`EXPLORE`, `LISTEN`, `DO`, `REFLECT`, `QUIZ`)
+    #[serde(default)]
+    pub phases: HashMap<String, String>,
+    /// By room, as `Zone/Room`
+    #[serde(default)]
+    pub rooms: HashMap<String, String>,
+    /// By zone name
+    #[serde(default)]
+    pub zones: HashMap<String, String>,
+}
+
+impl MusicCues {
+    fn load() -> Self {
+        match toml::from_str(include_str!("../assets/audio/music.toml")) {
+            Ok(cues) => cues,
+            Err(e) => {
+                error!("Failed to parse music cues: {}", e);
+                Self { default: "bgm.mp3".to_string(), ..default() }
+            }
+        }
+    }
+
+    /// The first cue that matches and hasn't failed to load
+    fn track(&self, phase: Option<&str>, room: Option<&str>, zone: Option<&str>, failed: &HashSet<String>) -> &str {
+        [
+            phase.and_then(|p| self.phases.get(p)),
+            room.and_then(|r| self.rooms.get(r)),
+            zone.and_then(|z| self.zones.get(z)),
+        ]
+        .into_iter()
+        .flatten()
+        .find(|path| !failed.contains(*path))
+        .unwrap_or(&self.default)
+    }
+}
+
+/// A music track fading toward `target`; faded-out tracks are despawned
+#[derive(Component)]
+struct MusicTrack {
+    path: String,
+    fade: f32,
+    target: f32,
+}
+
+/// Current ducking gain, 1 when nobody is speaking
+#[derive(Resource)]
+struct Mixer {
+    duck: f32,
 }
 
 pub struct GameAudioPlugin;
@@ -19,39 +156,152 @@ pub struct GameAudioPlugin;
 impl Plugin for GameAudioPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<SfxEvent>()
+           .insert_resource(MusicCues::load())
+           .insert_resource(Mixer { duck: 1.0 })
            .add_systems(Startup, setup_audio)
-           .add_systems(Update, play_sfx);
+           .add_systems(Update, (
+               cue_quest_advance,
+               play_sfx,
+               follow_music_cue,
+               crossfade_music,
+               mix,
+           ).chain());
     }
 }
 
-fn setup_audio(mut commands: Commands, asset_server: Res<AssetServer>) {
-    commands.spawn((
-        AudioPlayer::new(asset_server.load("bgm.mp3")),
-        PlaybackSettings::LOOP,
-    ));
+fn setup_audio(mut commands: Commands, mut sources: ResMut<Assets<AudioSource>>) {
+    let presets = match sfx_synth::parse_presets(sfx_synth::PRESETS) {
+        Ok(presets) => presets,
+        Err(e) => {
+            error!("Failed to parse SFX presets: {}", e);
+            default()
+        }
+    };
+    let sounds: HashMap<_, _> = presets.iter()
+        .enumerate()
+        .map(|(seed, (name, params))| (name.clone(), sources.add(synthesize(params, seed as u64))))
+        .collect();
+    info!("🔊 Synthesized {} sound effects", sounds.len());
+    commands.insert_resource(SfxAssets { sounds });
+}
 
-    commands.insert_resource(SfxAssets {
-        hit: asset_server.load("hit.wav"),
-        death: asset_server.load("death.wav"),
-        level_up: asset_server.load("levelup.wav"),
-    });
+fn cue_quest_advance(
+    mut events: EventReader<crate::syllabus::QuestAdvancedEvent>,
+    mut sfx_writer: EventWriter<SfxEvent>,
+) {
+    for _ in events.read() {
+        sfx_writer.send(SfxEvent::QuestAdvance);
+    }
 }
 
 fn play_sfx(
     mut commands: Commands,
     mut sfx_events: EventReader<SfxEvent>,
     sfx_assets: Res<SfxAssets>,
+    settings: Res<GameSettings>,
 ) {
     for event in sfx_events.read() {
-        let handle = match event {
-            SfxEvent::Hit => sfx_assets.hit.clone(),
-            SfxEvent::Death => sfx_assets.death.clone(),
-            SfxEvent::LevelUp => sfx_assets.level_up.clone(),
+        let Some(handle) = sfx_assets.sounds.get(event.preset()) else {
+            warn!("⚠️ No SFX preset '{}'", event.preset());
+            continue;
         };
 
         commands.spawn((
-            AudioPlayer::new(handle),
-            PlaybackSettings::DESPAWN,
+            AudioPlayer::new(handle.clone()),
+            PlaybackSettings::DESPAWN.with_volume(Volume::new(settings.audio.volume(Bus::Sfx))),
+            AudioBus(Bus::Sfx),
         ));
     }
 }
+
+/// Starts a crossfade when the cue for where the player is changes
+fn follow_music_cue(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    (cues, syllabus): (Res<MusicCues>, Option<Res<SyllabusResource>>),
+    (active_map, maps): (Option<Res<ActiveMap>>, Res<Assets<MapData>>),
+    player: Query<&Transform, With<Player>>,
+    mut tracks: Query<(Entity, &mut MusicTrack, &AudioPlayer)>,
+    mut failed: Local<HashSet<String>>,
+) {
+    // Cues naming a missing file fall back to the next match
+    for (entity, track, player) in &tracks {
+        if asset_server.load_state(&player.0).is_failed() && failed.insert(track.path.clone()) {
+            warn!("⚠️ Music track '{}' failed to load, falling back", track.path);
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let phase = syllabus.as_ref().map(|syl| syl.current_phase().phase_type_name());
+    let map = active_map.as_ref().and_then(|m| m.data(&maps));
+    let room = map.zip(player.get_single().ok())
+        .and_then(|(map, tf)| map.room_at(tf.translation.truncate(), TILE).map(|room| map.room_key(room)));
+    let wanted = cues.track(phase, room.as_deref(), map.map(|m| m.name.as_str()), &failed);
+
+    // Even the default is missing: stay quiet
+    if failed.contains(wanted) || tracks.iter().any(|(_, track, _)| track.target > 0.0 && track.path == wanted) {
+        return;
+    }
+    let first = tracks.is_empty();
+    for (_, mut track, _) in &mut tracks {
+        track.target = 0.0;
+    }
+    info!("🎵 Music: {}", wanted);
+    commands.spawn((
+        AudioPlayer::new(asset_server.load(wanted.to_string())),
+        PlaybackSettings::LOOP.with_volume(Volume::new(0.0)),
+        AudioBus(Bus::Music),
+        // The first track starts at full volume rather than fading in
+        MusicTrack { path: wanted.to_string(), fade: if first { 1.0 } else { 0.0 }, target: 1.0 },
+    ));
+}
+
+fn crossfade_music(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut tracks: Query<(Entity, &mut MusicTrack)>,
+) {
+    let step = time.delta_secs() / CROSSFADE_SECS;
+    for (entity, mut track) in &mut tracks {
+        if track.fade < track.target {
+            track.fade = (track.fade + step).min(track.target);
+        } else if track.fade > track.target {
+            track.fade = (track.fade - step).max(track.target);
+        } else if track.target == 0.0 {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Sets every sink to its bus volume, ducked and faded
+fn mix(
+    time: Res<Time<Real>>,
+    settings: Res<GameSettings>,
+    teacher: Res<TeacherState>,
+    moshi: Option<Res<MoshiVoice>>,
+    mut mixer: ResMut<Mixer>,
+    sinks: Query<(&AudioSink, &AudioBus, Option<&MusicTrack>)>,
+) {
+    let speaking = teacher.is_speaking || moshi.is_some_and(|m| m.is_speaking) || sinks.iter()
+        .any(|(sink, bus, _)| bus.0 == Bus::Voice && !sink.is_paused() && !sink.empty());
+    let duck_target = if speaking { DUCK_LEVEL } else { 1.0 };
+    let step = DUCK_SPEED * time.delta_secs();
+    mixer.duck = if mixer.duck < duck_target {
+        (mixer.duck + step).min(duck_target)
+    } else {
+        (mixer.duck - step).max(duck_target)
+    };
+
+    for (sink, bus, track) in &sinks {
+        let mut volume = settings.audio.volume(bus.0);
+        if bus.0.ducks() {
+            volume *= mixer.duck;
+        }
+        if let Some(track) = track {
+            volume *= track.fade;
+        }
+        if sink.volume() != volume {
+            sink.set_volume(volume);
+        }
+    }
+}
@@ -1,5 +1,5 @@
 use bevy::prelude::*;
-use crate::game_world::{Player, Particle, FloatingText};
+use crate::game_world::{Broadphase, Player, Particle, FloatingText};
 use crate::scoring::XpGainEvent;
 use crate::audio::SfxEvent;
 
@@ -40,6 +40,9 @@ pub struct Hitbox {
     pub radius: f32,
 }
 
+/// Extra search distance for broadphase queries made against last frame's positions
+const BROADPHASE_SLACK: f32 = 16.0;
+
 // ============================================================================
 // Systems
 // ============================================================================
@@ -48,7 +51,7 @@ fn spawn_slimes(
     mut commands: Commands,
     asset_server: Res<AssetServer>,
     time: Res<Time>,
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<crate::actions::Actions>,
     mut spawn_timer: Local<Option<Timer>>,
     slime_query: Query<&GlitchSlime>,
     player_query: Query<&Transform, With<Player>>,
@@ -57,7 +60,7 @@ fn spawn_slimes(
         *spawn_timer = Some(Timer::from_seconds(5.0, TimerMode::Repeating));
     }
 
-    let force_spawn = keys.just_pressed(KeyCode::KeyG);
+    let force_spawn = actions.just_pressed(crate::actions::Action::SpawnSlime);
 
     // Only spawn a limited number of slimes (e.g., max 10)
     if slime_query.iter().count() >= 10 && !force_spawn {
@@ -126,11 +129,11 @@ fn slime_ai(
 
 fn player_attack_input(
     mut commands: Commands,
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<crate::actions::Actions>,
     asset_server: Res<AssetServer>,
     player_query: Query<(&Transform, &Player)>,
 ) {
-    if keys.just_pressed(KeyCode::KeyF) {
+    if actions.just_pressed(crate::actions::Action::Attack) {
         if let Ok((player_tf, player)) = player_query.get_single() {
             let facing_modifier = if player.facing_left { -1.0 } else { 1.0 };
             let attack_offset = Vec3::new(40.0 * facing_modifier, 0.0, 0.1);
@@ -173,9 +176,14 @@ fn detect_hits(
     mut sfx_writer: EventWriter<SfxEvent>,
     attacks: Query<(&Transform, &Hitbox, &PlayerAttack)>,
     mut slimes: Query<(Entity, &Transform, &Hitbox, &mut GlitchSlime)>,
+    broadphase: Res<Broadphase>,
+    settings: Res<crate::accessibility::AccessibilitySettings>,
 ) {
     for (attack_tf, attack_hb, attack) in &attacks {
-        for (slime_entity, slime_tf, slime_hb, mut slime) in &mut slimes {
+        // The index is a frame old, so widen the search by a frame of slime travel
+        let candidates = broadphase.0.query(attack_tf.translation.truncate(), attack_hb.radius + BROADPHASE_SLACK);
+        for candidate in candidates {
+            let Ok((slime_entity, slime_tf, slime_hb, mut slime)) = slimes.get_mut(candidate) else { continue };
             let distance = attack_tf.translation.distance(slime_tf.translation);
             
             // If the hitboxes overlap
@@ -192,7 +200,7 @@ fn detect_hits(
                     // Spawn particle explosion
                     use rand::Rng;
                     let mut rng = rand::thread_rng();
-                    for _ in 0..8 {
+                    for _ in 0..settings.particle_count(8) {
                         let vx = rng.gen_range(-100.0..100.0);
                         let vy = rng.gen_range(-100.0..100.0);
                         commands.spawn((
@@ -0,0 +1,419 @@
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// ============================================================================
+// Command Grammar — spoken or typed text to an intent
+// ============================================================================
+// `assets/voice_commands.toml` lists what each command sounds like. A phrase
+// is plain words plus at most one slot (`{tool}`, `{option}`, `{place}`,
+// `{npc}`) filled from whatever is around when it's said: the unlocked tools,
+// the options on screen, the rooms of the zone, the NPCs in it. Before
+// matching, filler words are dropped and synonyms become the word they stand
+// for, in the text and the phrases alike; words then match if they're within
+// an edit or two of each other, so "compas" or "archives" still land. The
+// phrase matching the most words wins. Kept free of ECS types so it can be
+// tested against the shipped grammar on its own.
+
+/// The grammar the game ships with
+pub const GRAMMAR: &str = include_str!("../assets/voice_commands.toml");
+
+/// Words shorter than this must match exactly
+const FUZZY_MIN_LEN: usize = 4;
+
+/// Words at least this long may be two edits off
+const FUZZY_LONG_LEN: usize = 8;
+
+/// Fraction of a name's words that must be said for it to count
+const MIN_NAME_MATCH: f32 = 0.5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Kind {
+    /// Toggle `{tool}`
+    Tool,
+    /// Pick `{option}`
+    Choose,
+    /// Walk to `{place}` or `{npc}`
+    Walk,
+    /// Walk up to `{npc}` and interact
+    Talk,
+    /// Say the last line again
+    Repeat,
+    /// Press the named action
+    Press,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Slot {
+    Tool,
+    Option,
+    Place,
+    Npc,
+}
+
+impl Slot {
+    fn parse(token: &str) -> Option<Self> {
+        match token.strip_prefix('{')?.strip_suffix('}')? {
+            "tool" => Some(Slot::Tool),
+            "option" => Some(Slot::Option),
+            "place" => Some(Slot::Place),
+            "npc" => Some(Slot::Npc),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct CommandDef {
+    #[serde(rename = "do")]
+    kind: Kind,
+    /// For `press`: the action's name, as in the bindings file
+    #[serde(default)]
+    action: Option<String>,
+    /// For `tool`: only switch it on (`true`) or off (`false`)
+    #[serde(default)]
+    active: Option<bool>,
+    phrases: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct GrammarFile {
+    #[serde(default)]
+    fillers: Vec<String>,
+    #[serde(default)]
+    synonyms: HashMap<String, Vec<String>>,
+    /// Spoken names for options 1, 2, 3...
+    #[serde(default)]
+    numbers: Vec<Vec<String>>,
+    commands: Vec<CommandDef>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Word(String),
+    Slot(Slot),
+}
+
+/// A phrase that fit: words matched, and the slot's filling
+#[derive(Debug, Clone)]
+struct Matched {
+    score: usize,
+    slot: Option<Slot>,
+    target: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+struct Phrase {
+    command: usize,
+    tokens: Vec<Token>,
+}
+
+/// What the words in each slot can refer to. Each entry is one thing, under
+/// every name it goes by.
+#[derive(Debug, Clone, Default)]
+pub struct Candidates {
+    pub tools: Vec<Vec<String>>,
+    pub options: Vec<String>,
+    pub places: Vec<Vec<String>>,
+    pub npcs: Vec<Vec<String>>,
+}
+
+/// A recognised command; `target` indexes the candidates for `slot`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Intent {
+    pub kind: Kind,
+    pub action: Option<String>,
+    pub active: Option<bool>,
+    pub slot: Option<Slot>,
+    pub target: Option<usize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Grammar {
+    fillers: Vec<String>,
+    /// Variant → the word it stands for
+    synonyms: HashMap<String, String>,
+    numbers: Vec<Vec<String>>,
+    commands: Vec<CommandDef>,
+    phrases: Vec<Phrase>,
+}
+
+impl Grammar {
+    pub fn parse(toml: &str) -> Result<Self, toml::de::Error> {
+        let file: GrammarFile = toml::from_str(toml)?;
+        let mut grammar = Self {
+            fillers: file.fillers.iter().map(|w| w.to_lowercase()).collect(),
+            synonyms: file.synonyms.iter()
+                .flat_map(|(word, variants)| variants.iter().map(move |v| (v.to_lowercase(), word.to_lowercase())))
+                .collect(),
+            numbers: file.numbers.iter().map(|names| names.iter().map(|n| n.to_lowercase()).collect()).collect(),
+            commands: file.commands,
+            phrases: Vec::new(),
+        };
+        grammar.phrases = grammar.commands.iter().enumerate()
+            .flat_map(|(i, command)| command.phrases.iter().map(move |phrase| (i, phrase)))
+            .map(|(command, phrase)| Phrase { command, tokens: grammar.tokens(phrase) })
+            .filter(|phrase| !phrase.tokens.is_empty())
+            .collect();
+        Ok(grammar)
+    }
+
+    /// Action names used by `press` commands, to check against the game's
+    pub fn actions(&self) -> impl Iterator<Item = &str> {
+        self.commands.iter().filter_map(|c| c.action.as_deref())
+    }
+
+    /// Lowercase words, fillers dropped and synonyms replaced
+    pub fn words(&self, text: &str) -> Vec<String> {
+        text.to_lowercase()
+            .split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty() && !self.fillers.iter().any(|f| f == w))
+            .map(|w| self.synonyms.get(w).cloned().unwrap_or_else(|| w.to_string()))
+            .collect()
+    }
+
+    fn tokens(&self, phrase: &str) -> Vec<Token> {
+        phrase.split_whitespace()
+            .flat_map(|part| match Slot::parse(part) {
+                Some(slot) => vec![Token::Slot(slot)],
+                None => self.words(part).into_iter().map(Token::Word).collect(),
+            })
+            .collect()
+    }
+
+    /// The command `text` asks for, if any
+    pub fn interpret(&self, text: &str, candidates: &Candidates) -> Option<Intent> {
+        let said = self.words(text);
+        if said.is_empty() {
+            return None;
+        }
+        let best = self.phrases.iter()
+            .filter_map(|phrase| self.match_phrase(&said, &phrase.tokens, candidates).map(|m| (phrase.command, m)))
+            // Earlier commands win ties
+            .fold(None, |best: Option<(usize, Matched)>, (command, matched)| match best {
+                Some((_, ref b)) if b.score >= matched.score => best,
+                _ => Some((command, matched)),
+            });
+        best.map(|(command, matched)| {
+            let def = &self.commands[command];
+            Intent { kind: def.kind, action: def.action.clone(), active: def.active, slot: matched.slot, target: matched.target }
+        })
+    }
+
+    /// How well `said` fits `tokens`, and what fills the slot
+    fn match_phrase(&self, said: &[String], tokens: &[Token], candidates: &Candidates) -> Option<Matched> {
+        let literal = |tokens: &[Token]| -> Vec<String> {
+            tokens.iter().filter_map(|t| match t { Token::Word(w) => Some(w.clone()), Token::Slot(_) => None }).collect()
+        };
+        let Some(slot_at) = tokens.iter().position(|t| matches!(t, Token::Slot(_))) else {
+            let words = literal(tokens);
+            return find(said, &words, 0).map(|_| Matched { score: words.len(), slot: None, target: None });
+        };
+        let Token::Slot(slot) = tokens[slot_at] else { return None };
+        let (before, after) = (literal(&tokens[..slot_at]), literal(&tokens[slot_at + 1..]));
+
+        let start = find(said, &before, 0)? + before.len();
+        let end = if after.is_empty() {
+            said.len()
+        } else {
+            (start..said.len()).rev().find(|&i| matches_at(said, &after, i))?
+        };
+        let (target, score) = self.resolve(slot, &said[start..end], candidates)?;
+        Some(Matched { score: before.len() + after.len() + score, slot: Some(slot), target: Some(target) })
+    }
+
+    /// The candidate `words` name, and how many words matched
+    fn resolve(&self, slot: Slot, words: &[String], candidates: &Candidates) -> Option<(usize, usize)> {
+        if words.is_empty() {
+            return None;
+        }
+        if slot == Slot::Option {
+            let count = candidates.options.len();
+            let by_number = words.iter().find_map(|w| {
+                self.numbers.iter().take(count).position(|names| names.iter().any(|n| n == w))
+            });
+            if let Some(n) = by_number {
+                return Some((n, 1));
+            }
+        }
+        let options: Vec<Vec<String>>;
+        let names: &[Vec<String>] = match slot {
+            Slot::Tool => &candidates.tools,
+            Slot::Place => &candidates.places,
+            Slot::Npc => &candidates.npcs,
+            Slot::Option => {
+                options = candidates.options.iter().map(|o| vec![o.clone()]).collect();
+                &options
+            }
+        };
+
+        // Names are short, so most of one must be said; options can run to a
+        // sentence, so the one sharing the most words wins
+        let by_count = slot == Slot::Option;
+        let rank = |(matched, fraction): (usize, f32)| if by_count { matched as f32 } else { fraction };
+        let threshold = if by_count { 1.0 } else { MIN_NAME_MATCH };
+        let scores: Vec<(usize, f32)> = names.iter()
+            .map(|aliases| {
+                aliases.iter()
+                    .map(|name| self.name_match(words, name))
+                    .map(|m| (m.0, rank(m)))
+                    .fold((0, 0.0), |best, m| if m.1 > best.1 { m } else { best })
+            })
+            .collect();
+        let best = scores.iter().map(|s| s.1).fold(0.0, f32::max);
+        if best < threshold {
+            return None;
+        }
+        // Two things matching equally well is no answer
+        let mut tied = scores.iter().enumerate().filter(|(_, s)| s.1 == best);
+        let (index, (matched, _)) = tied.next()?;
+        tied.next().is_none().then_some((index, *matched))
+    }
+
+    /// How many of `name`'s words were said, and what fraction that is.
+    /// Words too short to carry meaning ("of", "la") don't count.
+    fn name_match(&self, said: &[String], name: &str) -> (usize, f32) {
+        let words: Vec<String> = self.words(name).into_iter().filter(|w| w.chars().count() >= 3).collect();
+        if words.is_empty() {
+            return (0, 0.0);
+        }
+        let matched = words.iter().filter(|w| said.iter().any(|s| similar(s, w))).count();
+        (matched, matched as f32 / words.len() as f32)
+    }
+}
+
+/// First position from `from` where `words` appear in a row
+fn find(said: &[String], words: &[String], from: usize) -> Option<usize> {
+    if words.is_empty() {
+        return Some(from);
+    }
+    (from..said.len()).find(|&i| matches_at(said, words, i))
+}
+
+fn matches_at(said: &[String], words: &[String], at: usize) -> bool {
+    said.len() >= at + words.len() && words.iter().zip(&said[at..]).all(|(w, s)| similar(s, w))
+}
+
+/// Equal, or close enough for a mishearing or a typo
+pub fn similar(a: &str, b: &str) -> bool {
+    if a == b {
+        return true;
+    }
+    let len = a.chars().count().min(b.chars().count());
+    let allowed = match len {
+        n if n >= FUZZY_LONG_LEN => 2,
+        n if n >= FUZZY_MIN_LEN => 1,
+        _ => 0,
+    };
+    allowed > 0 && edit_distance(a, b) <= allowed
+}
+
+/// Levenshtein distance, by characters
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (above + 1).min(row[j] + 1).min(diagonal + usize::from(ca != *cb));
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    // The shipped voice command grammar against phrasings players actually use,
+    // in both languages, with the rooms, NPCs and tools of the academy.
+    use super::*;
+
+    fn grammar() -> Grammar {
+        Grammar::parse(GRAMMAR).expect("assets/voice_commands.toml parses")
+    }
+
+    fn academy() -> Candidates {
+        let names = |list: &[&[&str]]| list.iter().map(|names| names.iter().map(|n| n.to_string()).collect()).collect();
+        Candidates {
+            tools: names(&[&["Ollama Compass", "ollama compass"], &["Logic Lens", "logic lens"], &["Feedback Mirror", "feedback mirror"]]),
+            options: Vec::new(),
+            places: names(&[&["Academy Hall"], &["Terminal Lab"], &["Archive Vault"], &["Server Core"]]),
+            npcs: names(&[&["Teacher", "The Architect"], &["Archivist", "The Archivist"]]),
+        }
+    }
+
+    fn quiz() -> Candidates {
+        Candidates {
+            options: ["A loop that never ends", "A function calling itself", "A variable holding a list"]
+                .map(String::from)
+                .to_vec(),
+            ..academy()
+        }
+    }
+
+    fn heard(text: &str, candidates: &Candidates) -> Option<(Kind, Option<Slot>, Option<usize>)> {
+        grammar().interpret(text, candidates).map(|i| (i.kind, i.slot, i.target))
+    }
+
+    #[test]
+    fn switches_tools_by_name() {
+        let open = grammar().interpret("Open the compass", &academy()).expect("a command");
+        assert_eq!(open, Intent { kind: Kind::Tool, action: None, active: Some(true), slot: Some(Slot::Tool), target: Some(0) });
+        let close = grammar().interpret("hide the logic lens please", &academy()).expect("a command");
+        assert_eq!((close.kind, close.active, close.target), (Kind::Tool, Some(false), Some(1)));
+        assert_eq!(heard("espejo", &academy()), Some((Kind::Tool, Some(Slot::Tool), Some(2))));
+    }
+
+    #[test]
+    fn walks_to_rooms_and_people() {
+        assert_eq!(heard("Take me to the archive.", &academy()), Some((Kind::Walk, Some(Slot::Place), Some(2))));
+        assert_eq!(heard("llévame al laboratorio", &academy()), Some((Kind::Walk, Some(Slot::Place), Some(1))));
+        assert_eq!(heard("go to the server core", &academy()), Some((Kind::Walk, Some(Slot::Place), Some(3))));
+        assert_eq!(heard("Talk to the teacher", &academy()), Some((Kind::Talk, Some(Slot::Npc), Some(0))));
+        assert_eq!(heard("habla con el archivero", &academy()), Some((Kind::Talk, Some(Slot::Npc), Some(1))));
+    }
+
+    #[test]
+    fn tolerates_mishearing() {
+        assert_eq!(heard("open the compas", &academy()), Some((Kind::Tool, Some(Slot::Tool), Some(0))));
+        assert_eq!(heard("walk to the archives", &academy()), Some((Kind::Walk, Some(Slot::Place), Some(2))));
+    }
+
+    #[test]
+    fn picks_options_by_number_or_words() {
+        assert_eq!(heard("the second one", &quiz()), Some((Kind::Choose, Some(Slot::Option), Some(1))));
+        assert_eq!(heard("opción tres", &quiz()), Some((Kind::Choose, Some(Slot::Option), Some(2))));
+        assert_eq!(heard("a function calling itself", &quiz()), Some((Kind::Choose, Some(Slot::Option), Some(1))));
+        // Number words mean nothing without options on offer
+        assert_eq!(heard("two", &academy()), None);
+    }
+
+    #[test]
+    fn presses_actions_and_repeats() {
+        let map = grammar().interpret("show me the map", &academy()).expect("a command");
+        assert_eq!((map.kind, map.action.as_deref()), (Kind::Press, Some("Map")));
+        assert_eq!(heard("say that again", &academy()).map(|h| h.0), Some(Kind::Repeat));
+        assert_eq!(heard("repite", &academy()).map(|h| h.0), Some(Kind::Repeat));
+    }
+
+    #[test]
+    fn ambiguity_and_noise_are_not_commands() {
+        let two_archives = Candidates {
+            places: vec![vec!["Archive Hall".to_string()], vec!["Archive Vault".to_string()]],
+            ..academy()
+        };
+        assert_eq!(heard("go to the archive", &two_archives), None);
+        assert_eq!(heard("the weather is nice today", &academy()), None);
+        assert_eq!(heard("", &academy()), None);
+    }
+
+    #[test]
+    fn fuzzy_matching_scales_with_length() {
+        assert!(similar("lens", "lense"));
+        assert!(!similar("map", "mop"));
+        assert!(similar("archivist", "archivest"));
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+}
@@ -0,0 +1,296 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::GameState;
+use crate::actions::{Action, Actions, InputContext, MAX_CHOICES};
+use crate::ai::TurnEvent;
+use crate::ai::conversation::Turn;
+use crate::story_mode::{DialogueNode, StoryState, TypewriterState};
+use crate::syllabus::SyllabusResource;
+
+pub mod script;
+
+use script::{Effect, Runner, Script, Step, Value, Variables};
+
+// ============================================================================
+// Authored Dialogue — `.yarn` scripts driving the bottom DialogueBox
+// ============================================================================
+// NPCs with a `script` in the syllabus run it from its `Start` node when the
+// player presses Talk (SPACE) beside them, instead of asking the LLM. Talk
+// moves through lines, the choice bindings (1-9) pick an option. Scripts can branch on their own
+// variables and on these, set when a conversation starts:
+//   * `$npc`    — the NPC's id
+//   * `$module` — the current module's id
+//   * `$phase`  — the current phase type (EXPLORE, LISTEN, DO, REFLECT, QUIZ, DONE)
+
+/// Node every conversation starts at
+pub const START_NODE: &str = "Start";
+
+#[derive(Asset, TypePath, Debug)]
+pub struct DialogueScript(pub Script);
+
+/// Script variables for the whole run, so flags outlast conversations
+#[derive(Resource, Default)]
+pub struct DialogueVariables(pub Variables);
+
+/// Something a script asked for, applied by `apply_dialogue_effects`
+#[derive(Event)]
+pub struct DialogueEffectEvent {
+    pub npc: String,
+    pub effect: Effect,
+}
+
+/// Handles for every script an NPC names, by asset path
+#[derive(Resource, Default)]
+struct DialogueScripts(HashMap<String, Handle<DialogueScript>>);
+
+/// The scripted conversation in progress
+#[derive(Resource, Default)]
+struct Conversation {
+    runner: Option<Runner>,
+    npc: String,
+    /// Step on without waiting for input (just started)
+    resume: bool,
+}
+
+// ============================================================================
+// Asset Loader & Plugin
+// ============================================================================
+
+#[derive(Default)]
+pub struct DialogueScriptLoader;
+
+impl AssetLoader for DialogueScriptLoader {
+    type Asset = DialogueScript;
+    type Settings = ();
+    type Error = anyhow::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<DialogueScript, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let path = load_context.path().display().to_string();
+        let script = Script::parse(std::str::from_utf8(&bytes)?).map_err(|e| anyhow::anyhow!("{}: {}", path, e))?;
+        info!("💬 Loaded dialogue '{}': {} nodes", path, script.nodes.len());
+        Ok(DialogueScript(script))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["yarn"]
+    }
+}
+
+pub struct DialoguePlugin;
+
+impl Plugin for DialoguePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<DialogueScript>()
+           .init_asset_loader::<DialogueScriptLoader>()
+           .init_resource::<DialogueScripts>()
+           .init_resource::<DialogueVariables>()
+           .init_resource::<Conversation>()
+           .add_event::<DialogueEffectEvent>()
+           .add_systems(Update, load_scripts.run_if(resource_exists_and_changed::<SyllabusResource>))
+           .add_systems(Update, (
+               start_conversation,
+               end_conversation_on_leave,
+               advance_conversation,
+               apply_dialogue_effects,
+           ).chain().run_if(in_state(GameState::Playing)))
+           .add_systems(OnExit(GameState::Playing), end_conversation);
+    }
+}
+
+fn load_scripts(
+    asset_server: Res<AssetServer>,
+    syllabus: Option<Res<SyllabusResource>>,
+    mut scripts: ResMut<DialogueScripts>,
+) {
+    let Some(syl) = syllabus else { return };
+    for path in syl.syllabus.npcs.iter().filter_map(|n| n.script.as_ref()) {
+        scripts.0.entry(path.clone()).or_insert_with(|| asset_server.load(path.clone()));
+    }
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+fn start_conversation(
+    actions: Res<Actions>,
+    story_state: Res<StoryState>,
+    scripts: Res<DialogueScripts>,
+    assets: Res<Assets<DialogueScript>>,
+    syllabus: Option<Res<SyllabusResource>>,
+    mut variables: ResMut<DialogueVariables>,
+    mut conversation: ResMut<Conversation>,
+) {
+    if conversation.runner.is_some() || story_state.is_typing_prompt || !actions.just_pressed(Action::Talk) { return; }
+    let Some(ref npc) = story_state.nearby_npc else { return };
+    let Some(ref path) = npc.script else { return };
+
+    let Some(script) = scripts.0.get(path).and_then(|h| assets.get(h)) else {
+        warn!("⚠️ Dialogue script '{}' for {} isn't loaded", path, npc.name);
+        return;
+    };
+    let runner = match Runner::new(script.0.clone(), START_NODE) {
+        Ok(runner) => runner,
+        Err(e) => {
+            warn!("⚠️ Can't start '{}': {}", path, e);
+            return;
+        }
+    };
+
+    let vars = &mut variables.0;
+    vars.insert("npc".to_string(), Value::Text(npc.id.clone()));
+    if let Some(syl) = syllabus {
+        if let Some(quest) = syl.current_quest() {
+            vars.insert("module".to_string(), Value::Text(quest.id.clone()));
+        }
+        vars.insert("phase".to_string(), Value::Text(syl.current_phase().phase_type_name().to_string()));
+    }
+
+    info!("💬 Conversation with {} started", npc.name);
+    *conversation = Conversation { runner: Some(runner), npc: npc.id.clone(), resume: true };
+}
+
+/// Walking away ends the conversation
+fn end_conversation_on_leave(
+    conversation: ResMut<Conversation>,
+    story_state: ResMut<StoryState>,
+) {
+    if conversation.runner.is_none() { return; }
+    if story_state.nearby_npc.as_ref().is_some_and(|npc| npc.id == conversation.npc) { return; }
+    end_conversation(conversation, story_state);
+}
+
+fn end_conversation(
+    mut conversation: ResMut<Conversation>,
+    mut story_state: ResMut<StoryState>,
+) {
+    if conversation.runner.take().is_some() {
+        info!("💬 Conversation with {} ended", conversation.npc);
+    }
+    story_state.active_dialogue = None;
+}
+
+fn advance_conversation(
+    actions: Res<Actions>,
+    mut conversation: ResMut<Conversation>,
+    mut variables: ResMut<DialogueVariables>,
+    mut story_state: ResMut<StoryState>,
+    mut typewriter: ResMut<TypewriterState>,
+    mut effects: EventWriter<DialogueEffectEvent>,
+    mut turns: EventWriter<TurnEvent>,
+) {
+    let conversation = &mut *conversation;
+    let Some(ref mut runner) = conversation.runner else { return };
+
+    if !conversation.resume {
+        if runner.is_choosing() {
+            let Some(chosen) = actions.choice(InputContext::Dialogue) else { return };
+            if runner.choose(chosen).is_err() { return; }
+            if let Some(option) = story_state.active_dialogue.as_ref().and_then(|node| node.choices.get(chosen)) {
+                turns.send(TurnEvent(Turn::new(story_state.player_name.clone(), option.clone())));
+            }
+        } else if !actions.just_pressed(Action::Talk) {
+            return;
+        } else if typewriter.is_active {
+            // The first press finishes the line, the next moves on
+            typewriter.revealed_chars = typewriter.full_text.len();
+            return;
+        }
+    }
+    conversation.resume = false;
+
+    loop {
+        match runner.next(&mut variables.0) {
+            Ok(Step::Line { speaker, text }) => {
+                turns.send(TurnEvent(Turn::new(speaker.clone().unwrap_or_default(), text.clone())));
+                let node = DialogueNode { speaker: speaker.unwrap_or_default(), text, choices: Vec::new() };
+                show(&mut story_state, &mut typewriter, node, false);
+                return;
+            }
+            Ok(Step::Choices(options)) => {
+                // Options appear under the line that led to them
+                let mut node = story_state.active_dialogue.take().unwrap_or_default();
+                node.choices = options.into_iter().take(MAX_CHOICES as usize).collect();
+                show(&mut story_state, &mut typewriter, node, true);
+                return;
+            }
+            Ok(Step::Effect(effect)) => {
+                effects.send(DialogueEffectEvent { npc: conversation.npc.clone(), effect });
+            }
+            Ok(Step::End) => break,
+            Err(e) => {
+                warn!("⚠️ Dialogue with {} stopped: {}", conversation.npc, e);
+                break;
+            }
+        }
+    }
+
+    // The last line stays on screen
+    info!("💬 Conversation with {} ended", conversation.npc);
+    conversation.runner = None;
+    story_state.active_dialogue = None;
+}
+
+/// Puts a line (and its options) in the dialogue box. Options appear at
+/// once; lines are typed out.
+fn show(story_state: &mut StoryState, typewriter: &mut TypewriterState, node: DialogueNode, instant: bool) {
+    let mut text = node.text.clone();
+    if !node.choices.is_empty() {
+        text.push('\n');
+    }
+    for (i, choice) in node.choices.iter().enumerate() {
+        text.push_str(&format!("\n[{}] {}", i + 1, choice));
+    }
+
+    typewriter.revealed_chars = if instant { text.len() } else { 0 };
+    typewriter.full_text = text;
+    typewriter.is_active = true;
+    typewriter.voiced_chars = None;
+    typewriter.timer.reset();
+    story_state.active_dialogue = Some(node);
+}
+
+fn apply_dialogue_effects(
+    mut events: EventReader<DialogueEffectEvent>,
+    mut syllabus: Option<ResMut<SyllabusResource>>,
+    mut xp_writer: EventWriter<crate::scoring::XpGainEvent>,
+    mut reward_writer: EventWriter<crate::inventory::ItemGetEvent>,
+    mut quest_writer: EventWriter<crate::syllabus::QuestAdvancedEvent>,
+) {
+    for event in events.read() {
+        match event.effect {
+            Effect::Xp(amount) => {
+                xp_writer.send(crate::scoring::XpGainEvent {
+                    amount,
+                    reason: format!("Talked with {}", event.npc),
+                });
+            }
+            Effect::AdvancePhase => {
+                let Some(ref mut syl) = syllabus else { continue };
+                syl.complete_current_task();
+                if let Some(tools) = syl.advance_phase() {
+                    for tool in tools {
+                        reward_writer.send(crate::inventory::ItemGetEvent(tool));
+                    }
+                }
+                quest_writer.send(crate::syllabus::QuestAdvancedEvent {
+                    module_index: syl.current_module_index,
+                    step_index: syl.quest_script.current_phase,
+                });
+                info!("📜 {} advanced the quest", event.npc);
+            }
+            Effect::Command { ref name, ref args } => {
+                warn!("⚠️ Unknown dialogue command <<{} {}>> from {}", name, args.join(" "), event.npc);
+            }
+        }
+    }
+}
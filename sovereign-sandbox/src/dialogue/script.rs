@@ -0,0 +1,831 @@
+use std::collections::HashMap;
+use std::fmt;
+
+// ============================================================================
+// Dialogue Scripts — a small Yarn-like format, its parser and its runtime
+// ============================================================================
+// Kept free of Bevy types so scripts can be parsed and stepped through on
+// their own. A script is a list of nodes:
+//
+//     title: Start
+//     ---
+//     Archivist: Welcome back, {$name}.
+//     <<if $visits > 1>>
+//         Archivist: Twice in one day!
+//     <<endif>>
+//     -> What is a logic template?
+//         Archivist: A lesson plan written as if-then rules.
+//         <<set $asked = true>>
+//     -> I'm ready. <<if $asked>>
+//         <<xp 25>>
+//         <<advance>>
+//         <<jump Farewell>>
+//     -> Goodbye.
+//     ===
+//
+// Lines are `Speaker: text` or plain narration, with `{$var}` interpolated.
+// Consecutive `->` options form one choice; an option's body is the lines
+// indented under it, and a trailing `<<if expr>>` hides the option unless
+// the condition holds. Commands: `set`, `if`/`elseif`/`else`/`endif`,
+// `jump`, `stop`, `xp <amount>` and `advance` (advance the quest phase);
+// any other command is handed to the game as `Effect::Command`.
+
+/// A variable's value. Unset variables read as `false`, and booleans count
+/// as 0 or 1 in arithmetic, so counters can start from nothing.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Bool(bool),
+    Number(f64),
+    Text(String),
+}
+
+impl Value {
+    pub fn truthy(&self) -> bool {
+        match self {
+            Value::Bool(b) => *b,
+            Value::Number(n) => *n != 0.0,
+            Value::Text(s) => !s.is_empty(),
+        }
+    }
+
+    fn number(&self) -> Option<f64> {
+        match self {
+            Value::Number(n) => Some(*n),
+            Value::Bool(b) => Some(f64::from(u8::from(*b))),
+            Value::Text(_) => None,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Bool(b) => write!(f, "{}", b),
+            Value::Number(n) => write!(f, "{}", n),
+            Value::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+/// Script variables, keyed without the leading `$`
+pub type Variables = HashMap<String, Value>;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryOp {
+    Or,
+    And,
+    Eq,
+    NotEq,
+    Less,
+    LessEq,
+    Greater,
+    GreaterEq,
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Literal(Value),
+    Var(String),
+    Not(Box<Expr>),
+    Neg(Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    pub fn eval(&self, vars: &Variables) -> Value {
+        match self {
+            Expr::Literal(v) => v.clone(),
+            Expr::Var(name) => vars.get(name).cloned().unwrap_or(Value::Bool(false)),
+            Expr::Not(e) => Value::Bool(!e.eval(vars).truthy()),
+            Expr::Neg(e) => Value::Number(-e.eval(vars).number().unwrap_or(0.0)),
+            Expr::Binary(op, a, b) => {
+                // Short-circuit the logical operators
+                match op {
+                    BinaryOp::Or => return Value::Bool(a.eval(vars).truthy() || b.eval(vars).truthy()),
+                    BinaryOp::And => return Value::Bool(a.eval(vars).truthy() && b.eval(vars).truthy()),
+                    _ => {}
+                }
+                let (a, b) = (a.eval(vars), b.eval(vars));
+                let nums = a.number().zip(b.number());
+                match op {
+                    BinaryOp::Eq => Value::Bool(nums.map_or(a == b, |(a, b)| a == b)),
+                    BinaryOp::NotEq => Value::Bool(nums.map_or(a != b, |(a, b)| a != b)),
+                    BinaryOp::Less => Value::Bool(nums.is_some_and(|(a, b)| a < b)),
+                    BinaryOp::LessEq => Value::Bool(nums.is_some_and(|(a, b)| a <= b)),
+                    BinaryOp::Greater => Value::Bool(nums.is_some_and(|(a, b)| a > b)),
+                    BinaryOp::GreaterEq => Value::Bool(nums.is_some_and(|(a, b)| a >= b)),
+                    BinaryOp::Add => match nums {
+                        Some((a, b)) => Value::Number(a + b),
+                        None => Value::Text(format!("{}{}", a, b)),
+                    },
+                    BinaryOp::Sub => Value::Number(nums.map_or(0.0, |(a, b)| a - b)),
+                    BinaryOp::Mul => Value::Number(nums.map_or(0.0, |(a, b)| a * b)),
+                    BinaryOp::Div => Value::Number(nums.filter(|(_, b)| *b != 0.0).map_or(0.0, |(a, b)| a / b)),
+                    BinaryOp::Or | BinaryOp::And => unreachable!(),
+                }
+            }
+        }
+    }
+}
+
+/// Something the script asks the game to do
+#[derive(Debug, Clone, PartialEq)]
+pub enum Effect {
+    Xp(u32),
+    AdvancePhase,
+    Command { name: String, args: Vec<String> },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Choice {
+    pub text: String,
+    pub condition: Option<Expr>,
+    pub body: Vec<Statement>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Line { speaker: Option<String>, text: String },
+    Choices(Vec<Choice>),
+    If { branches: Vec<(Expr, Vec<Statement>)>, otherwise: Vec<Statement> },
+    Set(String, Expr),
+    Jump(String),
+    Stop,
+    Effect(Effect),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Node {
+    pub title: String,
+    pub tags: Vec<String>,
+    pub body: Vec<Statement>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Script {
+    pub nodes: HashMap<String, Node>,
+}
+
+/// A parse or runtime error, with the 1-based source line when known
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScriptError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl ScriptError {
+    fn new(line: usize, message: impl Into<String>) -> Self {
+        Self { line, message: message.into() }
+    }
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.line == 0 {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "line {}: {}", self.line, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {}
+
+// ============================================================================
+// Parser
+// ============================================================================
+
+impl Script {
+    pub fn parse(source: &str) -> Result<Self, ScriptError> {
+        let lines: Vec<&str> = source.lines().collect();
+        let mut nodes = HashMap::new();
+        let mut jumps = Vec::new();
+        let mut i = 0;
+
+        while i < lines.len() {
+            // Header: `key: value` lines up to `---`
+            let start = i;
+            let mut title = None;
+            let mut tags = Vec::new();
+            while i < lines.len() && lines[i].trim() != "---" {
+                let line = lines[i].trim();
+                i += 1;
+                if line.is_empty() || line.starts_with("//") { continue; }
+                let Some((key, value)) = line.split_once(':') else {
+                    return Err(ScriptError::new(i, format!("expected a `key: value` header, found '{}'", line)));
+                };
+                match key.trim() {
+                    "title" => title = Some(value.trim().to_string()),
+                    "tags" => tags = value.split_whitespace().map(str::to_string).collect(),
+                    _ => {}
+                }
+            }
+            if i >= lines.len() {
+                if title.is_none() { break; } // Trailing comments or blank lines
+                return Err(ScriptError::new(start + 1, "node header is missing `---`"));
+            }
+            let Some(title) = title else {
+                return Err(ScriptError::new(start + 1, "node is missing a `title:`"));
+            };
+            i += 1;
+
+            // Body up to `===`
+            let body_start = i;
+            while i < lines.len() && lines[i].trim() != "===" {
+                i += 1;
+            }
+            if i >= lines.len() {
+                return Err(ScriptError::new(body_start, format!("node '{}' is missing `===`", title)));
+            }
+            let body_lines: Vec<SourceLine> = (body_start..i)
+                .filter_map(|n| SourceLine::new(lines[n], n + 1))
+                .collect();
+            i += 1;
+
+            let mut parser = BodyParser { lines: &body_lines, pos: 0, jumps: &mut jumps };
+            let body = parser.block(0)?;
+            if let Some(extra) = body_lines.get(parser.pos) {
+                return Err(ScriptError::new(extra.number, format!("unexpected '{}'", extra.text)));
+            }
+            if nodes.contains_key(&title) {
+                return Err(ScriptError::new(start + 1, format!("duplicate node '{}'", title)));
+            }
+            nodes.insert(title.clone(), Node { title, tags, body });
+        }
+
+        if let Some((line, target)) = jumps.into_iter().find(|(_, t)| !nodes.contains_key(t)) {
+            return Err(ScriptError::new(line, format!("jump to unknown node '{}'", target)));
+        }
+        Ok(Self { nodes })
+    }
+}
+
+struct SourceLine<'a> {
+    number: usize,
+    indent: usize,
+    text: &'a str,
+}
+
+impl<'a> SourceLine<'a> {
+    /// Skips blank lines and `//` comments
+    fn new(raw: &'a str, number: usize) -> Option<Self> {
+        let text = raw.trim();
+        if text.is_empty() || text.starts_with("//") { return None; }
+        let indent = raw.chars()
+            .take_while(|c| c.is_whitespace())
+            .map(|c| if c == '\t' { 4 } else { 1 })
+            .sum();
+        Some(Self { number, indent, text })
+    }
+
+    /// Name and arguments of a `<<command ...>>` line
+    fn command(&self) -> Option<(&'a str, &'a str)> {
+        let inner = self.text.strip_prefix("<<")?.strip_suffix(">>")?.trim();
+        Some(inner.split_once(char::is_whitespace).map_or((inner, ""), |(n, a)| (n, a.trim())))
+    }
+}
+
+struct BodyParser<'a, 'b> {
+    lines: &'b [SourceLine<'a>],
+    pos: usize,
+    /// Every `<<jump>>` seen, checked once all nodes are known
+    jumps: &'b mut Vec<(usize, String)>,
+}
+
+impl BodyParser<'_, '_> {
+    /// Statements until a line indented less than `indent`, or an
+    /// `elseif`/`else`/`endif` that belongs to an enclosing `if`
+    fn block(&mut self, indent: usize) -> Result<Vec<Statement>, ScriptError> {
+        let mut statements = Vec::new();
+        while let Some(line) = self.lines.get(self.pos) {
+            if line.indent < indent { break; }
+            if matches!(line.command(), Some(("elseif" | "else" | "endif", _))) { break; }
+
+            if line.text.starts_with("->") {
+                statements.push(self.choices(line.indent)?);
+                continue;
+            }
+            self.pos += 1;
+            let statement = match line.command() {
+                Some(("if", cond)) => self.conditional(line.number, cond, indent)?,
+                Some(("set", args)) => {
+                    // `<<set $var = expr>>` or Yarn's `<<set $var to expr>>`
+                    let split = [args.find('=').map(|at| (at, 1)), args.find(" to ").map(|at| (at, 4))]
+                        .into_iter().flatten().min();
+                    let (at, len) = split
+                        .ok_or_else(|| ScriptError::new(line.number, "expected `<<set $var = expr>>`"))?;
+                    let (name, value) = (&args[..at], &args[at + len..]);
+                    let name = name.trim().strip_prefix('$')
+                        .ok_or_else(|| ScriptError::new(line.number, "variables start with `$`"))?;
+                    Statement::Set(name.to_string(), parse_expr(value, line.number)?)
+                }
+                Some(("jump", target)) if !target.is_empty() => {
+                    self.jumps.push((line.number, target.to_string()));
+                    Statement::Jump(target.to_string())
+                }
+                Some(("stop", _)) => Statement::Stop,
+                Some(("xp", amount)) => {
+                    let amount = amount.parse()
+                        .map_err(|_| ScriptError::new(line.number, format!("`xp` needs a whole number, found '{}'", amount)))?;
+                    Statement::Effect(Effect::Xp(amount))
+                }
+                Some(("advance", _)) => Statement::Effect(Effect::AdvancePhase),
+                Some((name, args)) => Statement::Effect(Effect::Command {
+                    name: name.to_string(),
+                    args: args.split_whitespace().map(str::to_string).collect(),
+                }),
+                None => {
+                    let (speaker, text) = split_speaker(line.text);
+                    Statement::Line { speaker: speaker.map(str::to_string), text: text.to_string() }
+                }
+            };
+            statements.push(statement);
+        }
+        Ok(statements)
+    }
+
+    /// `<<if>>` … `<<endif>>`; the opening line has already been consumed
+    fn conditional(&mut self, number: usize, cond: &str, indent: usize) -> Result<Statement, ScriptError> {
+        let mut branches = vec![(parse_expr(cond, number)?, self.block(indent)?)];
+        let mut otherwise = Vec::new();
+        loop {
+            let Some(line) = self.lines.get(self.pos) else {
+                return Err(ScriptError::new(number, "`<<if>>` without `<<endif>>`"));
+            };
+            self.pos += 1;
+            match line.command() {
+                Some(("elseif", cond)) => {
+                    let cond = parse_expr(cond, line.number)?;
+                    branches.push((cond, self.block(indent)?));
+                }
+                Some(("else", _)) => otherwise = self.block(indent)?,
+                Some(("endif", _)) => break,
+                _ => return Err(ScriptError::new(line.number, "`<<if>>` without `<<endif>>`")),
+            }
+        }
+        Ok(Statement::If { branches, otherwise })
+    }
+
+    /// A run of `->` options at the same indentation
+    fn choices(&mut self, indent: usize) -> Result<Statement, ScriptError> {
+        let mut choices = Vec::new();
+        while let Some(line) = self.lines.get(self.pos) {
+            if line.indent != indent { break; }
+            let Some(option) = line.text.strip_prefix("->") else { break };
+            self.pos += 1;
+
+            let option = option.trim();
+            let (text, condition) = match option.rfind("<<if ") {
+                Some(at) if option.ends_with(">>") => {
+                    let cond = &option[at + 5..option.len() - 2];
+                    (option[..at].trim(), Some(parse_expr(cond, line.number)?))
+                }
+                _ => (option, None),
+            };
+            if text.is_empty() {
+                return Err(ScriptError::new(line.number, "option has no text"));
+            }
+            let body = self.block(indent + 1)?;
+            choices.push(Choice { text: text.to_string(), condition, body });
+        }
+        Ok(Statement::Choices(choices))
+    }
+}
+
+/// `Speaker: text`, or plain narration when there's no short name before a colon
+fn split_speaker(text: &str) -> (Option<&str>, &str) {
+    match text.split_once(':') {
+        Some((speaker, rest)) if !speaker.is_empty() && speaker.len() <= 32 && !speaker.contains(['{', '<']) => {
+            (Some(speaker.trim()), rest.trim())
+        }
+        _ => (None, text),
+    }
+}
+
+// ============================================================================
+// Expressions
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Text(String),
+    Var(String),
+    Word(String),
+    Op(&'static str),
+}
+
+const OPERATORS: [&str; 16] = ["==", "!=", "<=", ">=", "&&", "||", "<", ">", "+", "-", "*", "/", "(", ")", "!", "="];
+
+fn tokenize(src: &str, line: usize) -> Result<Vec<Token>, ScriptError> {
+    let mut tokens = Vec::new();
+    let mut rest = src.trim_start();
+    while !rest.is_empty() {
+        let c = rest.chars().next().unwrap_or_default();
+        let (token, len) = if c == '"' {
+            let end = rest[1..].find('"').ok_or_else(|| ScriptError::new(line, "unterminated string"))?;
+            (Token::Text(rest[1..end + 1].to_string()), end + 2)
+        } else if c.is_ascii_digit() {
+            let len = rest.find(|c: char| !(c.is_ascii_digit() || c == '.')).unwrap_or(rest.len());
+            let n = rest[..len].parse().map_err(|_| ScriptError::new(line, format!("bad number '{}'", &rest[..len])))?;
+            (Token::Number(n), len)
+        } else if c == '$' || c.is_alphabetic() || c == '_' {
+            let skip = usize::from(c == '$');
+            let len = rest[skip..].find(|c: char| !(c.is_alphanumeric() || c == '_')).map_or(rest.len(), |l| l + skip);
+            let word = rest[skip..len].to_string();
+            (if c == '$' { Token::Var(word) } else { Token::Word(word) }, len)
+        } else if let Some(op) = OPERATORS.iter().find(|op| rest.starts_with(**op)) {
+            // A lone `=` compares, as in Yarn
+            (Token::Op(if *op == "=" { "==" } else { op }), op.len())
+        } else {
+            return Err(ScriptError::new(line, format!("unexpected '{}' in expression", c)));
+        };
+        tokens.push(token);
+        rest = rest[len..].trim_start();
+    }
+    Ok(tokens)
+}
+
+pub fn parse_expr(src: &str, line: usize) -> Result<Expr, ScriptError> {
+    let tokens = tokenize(src, line)?;
+    let mut parser = ExprParser { tokens: &tokens, pos: 0, line };
+    let expr = parser.or()?;
+    match tokens.get(parser.pos) {
+        None => Ok(expr),
+        Some(t) => Err(ScriptError::new(line, format!("unexpected {:?} in expression", t))),
+    }
+}
+
+struct ExprParser<'a> {
+    tokens: &'a [Token],
+    pos: usize,
+    line: usize,
+}
+
+impl ExprParser<'_> {
+    fn eat(&mut self, ops: &[&str], words: &[&str]) -> Option<String> {
+        let found = match self.tokens.get(self.pos)? {
+            Token::Op(op) if ops.contains(op) => op.to_string(),
+            Token::Word(w) if words.contains(&w.as_str()) => w.clone(),
+            _ => return None,
+        };
+        self.pos += 1;
+        Some(found)
+    }
+
+    fn or(&mut self) -> Result<Expr, ScriptError> {
+        let mut lhs = self.and()?;
+        while self.eat(&["||"], &["or"]).is_some() {
+            lhs = Expr::Binary(BinaryOp::Or, Box::new(lhs), Box::new(self.and()?));
+        }
+        Ok(lhs)
+    }
+
+    fn and(&mut self) -> Result<Expr, ScriptError> {
+        let mut lhs = self.not()?;
+        while self.eat(&["&&"], &["and"]).is_some() {
+            lhs = Expr::Binary(BinaryOp::And, Box::new(lhs), Box::new(self.not()?));
+        }
+        Ok(lhs)
+    }
+
+    fn not(&mut self) -> Result<Expr, ScriptError> {
+        if self.eat(&["!"], &["not"]).is_some() {
+            return Ok(Expr::Not(Box::new(self.not()?)));
+        }
+        self.comparison()
+    }
+
+    fn comparison(&mut self) -> Result<Expr, ScriptError> {
+        let lhs = self.sum()?;
+        let op = match self.eat(&["==", "!=", "<=", ">=", "<", ">"], &["is", "eq", "neq"]).as_deref() {
+            Some("==" | "is" | "eq") => BinaryOp::Eq,
+            Some("!=" | "neq") => BinaryOp::NotEq,
+            Some("<=") => BinaryOp::LessEq,
+            Some(">=") => BinaryOp::GreaterEq,
+            Some("<") => BinaryOp::Less,
+            Some(">") => BinaryOp::Greater,
+            _ => return Ok(lhs),
+        };
+        Ok(Expr::Binary(op, Box::new(lhs), Box::new(self.sum()?)))
+    }
+
+    fn sum(&mut self) -> Result<Expr, ScriptError> {
+        let mut lhs = self.product()?;
+        while let Some(op) = self.eat(&["+", "-"], &[]) {
+            let op = if op == "+" { BinaryOp::Add } else { BinaryOp::Sub };
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(self.product()?));
+        }
+        Ok(lhs)
+    }
+
+    fn product(&mut self) -> Result<Expr, ScriptError> {
+        let mut lhs = self.unary()?;
+        while let Some(op) = self.eat(&["*", "/"], &[]) {
+            let op = if op == "*" { BinaryOp::Mul } else { BinaryOp::Div };
+            lhs = Expr::Binary(op, Box::new(lhs), Box::new(self.unary()?));
+        }
+        Ok(lhs)
+    }
+
+    fn unary(&mut self) -> Result<Expr, ScriptError> {
+        if self.eat(&["-"], &[]).is_some() {
+            return Ok(Expr::Neg(Box::new(self.unary()?)));
+        }
+        self.primary()
+    }
+
+    fn primary(&mut self) -> Result<Expr, ScriptError> {
+        let token = self.tokens.get(self.pos).cloned()
+            .ok_or_else(|| ScriptError::new(self.line, "expression ends early"))?;
+        self.pos += 1;
+        match token {
+            Token::Number(n) => Ok(Expr::Literal(Value::Number(n))),
+            Token::Text(s) => Ok(Expr::Literal(Value::Text(s))),
+            Token::Var(name) => Ok(Expr::Var(name)),
+            Token::Word(w) if w == "true" => Ok(Expr::Literal(Value::Bool(true))),
+            Token::Word(w) if w == "false" => Ok(Expr::Literal(Value::Bool(false))),
+            Token::Op("(") => {
+                let inner = self.or()?;
+                self.eat(&[")"], &[]).ok_or_else(|| ScriptError::new(self.line, "missing `)`"))?;
+                Ok(inner)
+            }
+            other => Err(ScriptError::new(self.line, format!("unexpected {:?} in expression", other))),
+        }
+    }
+}
+
+// ============================================================================
+// Runtime
+// ============================================================================
+
+/// Jumps one `Runner::next` may take without reaching anything to show. Past
+/// this the nodes are jumping between themselves, e.g. `<<jump Start>>` in
+/// `Start` with no line in between.
+pub const MAX_JUMPS: usize = 100;
+
+/// What the runner stopped on
+#[derive(Debug, Clone, PartialEq)]
+pub enum Step {
+    Line { speaker: Option<String>, text: String },
+    /// The options whose conditions hold; answer with `Runner::choose`
+    Choices(Vec<String>),
+    Effect(Effect),
+    End,
+}
+
+/// Steps through one conversation. Variables live outside the runner so
+/// flags outlast the conversation that set them.
+#[derive(Debug, Clone)]
+pub struct Runner {
+    script: Script,
+    /// Blocks being executed, innermost last, with the next statement index
+    frames: Vec<(Vec<Statement>, usize)>,
+    /// Bodies of the options currently offered
+    offered: Vec<Vec<Statement>>,
+}
+
+impl Runner {
+    pub fn new(script: Script, start: &str) -> Result<Self, ScriptError> {
+        let node = script.nodes.get(start)
+            .ok_or_else(|| ScriptError::new(0, format!("no node named '{}'", start)))?;
+        let frames = vec![(node.body.clone(), 0)];
+        Ok(Self { script, frames, offered: Vec::new() })
+    }
+
+    /// Runs until the next line, choice, effect or the end
+    pub fn next(&mut self, vars: &mut Variables) -> Result<Step, ScriptError> {
+        if !self.offered.is_empty() {
+            return Err(ScriptError::new(0, "waiting for a choice"));
+        }
+        let mut jumps = 0;
+        loop {
+            let Some((block, index)) = self.frames.last_mut() else { return Ok(Step::End) };
+            let Some(statement) = block.get(*index).cloned() else {
+                self.frames.pop();
+                continue;
+            };
+            *index += 1;
+
+            match statement {
+                Statement::Line { speaker, text } => {
+                    return Ok(Step::Line { speaker, text: interpolate(&text, vars) });
+                }
+                Statement::Choices(choices) => {
+                    let available: Vec<Choice> = choices.into_iter()
+                        .filter(|c| c.condition.as_ref().is_none_or(|e| e.eval(vars).truthy()))
+                        .collect();
+                    if available.is_empty() { continue; }
+                    let texts = available.iter().map(|c| interpolate(&c.text, vars)).collect();
+                    self.offered = available.into_iter().map(|c| c.body).collect();
+                    return Ok(Step::Choices(texts));
+                }
+                Statement::If { branches, otherwise } => {
+                    let taken = branches.into_iter()
+                        .find(|(cond, _)| cond.eval(vars).truthy())
+                        .map_or(otherwise, |(_, body)| body);
+                    self.frames.push((taken, 0));
+                }
+                Statement::Set(name, expr) => {
+                    let value = expr.eval(vars);
+                    vars.insert(name, value);
+                }
+                Statement::Jump(target) => {
+                    jumps += 1;
+                    if jumps > MAX_JUMPS {
+                        self.frames.clear();
+                        return Err(ScriptError::new(0, format!("jumped {} times without a line, stuck in a loop at '{}'", MAX_JUMPS, target)));
+                    }
+                    let node = self.script.nodes.get(&target)
+                        .ok_or_else(|| ScriptError::new(0, format!("jump to unknown node '{}'", target)))?;
+                    self.frames = vec![(node.body.clone(), 0)];
+                }
+                Statement::Stop => {
+                    self.frames.clear();
+                    return Ok(Step::End);
+                }
+                Statement::Effect(effect) => return Ok(Step::Effect(effect)),
+            }
+        }
+    }
+
+    /// Picks one of the options from the last `Step::Choices`
+    pub fn choose(&mut self, index: usize) -> Result<(), ScriptError> {
+        if index >= self.offered.len() {
+            return Err(ScriptError::new(0, format!("no option {} on offer", index + 1)));
+        }
+        let body = self.offered.swap_remove(index);
+        self.offered.clear();
+        self.frames.push((body, 0));
+        Ok(())
+    }
+
+    pub fn is_choosing(&self) -> bool {
+        !self.offered.is_empty()
+    }
+}
+
+/// Replaces `{$var}` with the variable's value
+pub fn interpolate(text: &str, vars: &Variables) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find("{$") {
+        let Some(len) = rest[start..].find('}') else { break };
+        out.push_str(&rest[..start]);
+        let name = &rest[start + 2..start + len];
+        out.push_str(&vars.get(name).map(Value::to_string).unwrap_or_default());
+        rest = &rest[start + len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    // Dialogue scripts without Bevy: the shipped scripts parse, lines and options
+    // come out in order with their conditions and variables applied, and a script
+    // that jumps in circles fails instead of hanging the game.
+    use super::*;
+
+    fn run(source: &str) -> Runner {
+        Runner::new(Script::parse(source).expect("script"), "Start").expect("runner")
+    }
+
+    fn line(speaker: Option<&str>, text: &str) -> Step {
+        Step::Line { speaker: speaker.map(str::to_string), text: text.to_string() }
+    }
+
+    fn choices(texts: &[&str]) -> Step {
+        Step::Choices(texts.iter().map(|t| t.to_string()).collect())
+    }
+
+    #[test]
+    fn parses_the_shipped_scripts() {
+        for path in ["assets/dialogue/archivist.yarn", "assets/dialogue/archivist.es.yarn"] {
+            let source = std::fs::read_to_string(path).expect("script file");
+            let script = Script::parse(&source).unwrap_or_else(|e| panic!("{}: {}", path, e));
+            assert!(script.nodes.contains_key("Start") && script.nodes.contains_key("Questions"), "{}", path);
+            assert_eq!(script.nodes["Start"].tags, vec!["archivist".to_string()]);
+        }
+    }
+
+    #[test]
+    fn reports_errors_with_their_line() {
+        let missing_end = Script::parse("title: Start\n---\nHello.\n").unwrap_err();
+        assert!(missing_end.message.contains("missing `===`"), "{}", missing_end);
+
+        let unknown = Script::parse("title: Start\n---\nHello.\n<<jump Nowhere>>\n===\n").unwrap_err();
+        assert_eq!(unknown.line, 4);
+        assert!(unknown.message.contains("Nowhere"));
+
+        let duplicate = Script::parse("title: A\n---\nHi.\n===\ntitle: A\n---\nHi.\n===\n").unwrap_err();
+        assert_eq!(duplicate.line, 5);
+
+        assert!(Runner::new(Script::default(), "Start").is_err());
+    }
+
+    #[test]
+    fn lines_narration_and_the_end() {
+        let mut vars = Variables::new();
+        let mut runner = run("title: Start\n---\n// a comment\nGuide: Welcome.\nThe door creaks.\n===\n");
+        assert_eq!(runner.next(&mut vars).unwrap(), line(Some("Guide"), "Welcome."));
+        assert_eq!(runner.next(&mut vars).unwrap(), line(None, "The door creaks."));
+        assert_eq!(runner.next(&mut vars).unwrap(), Step::End);
+        assert_eq!(runner.next(&mut vars).unwrap(), Step::End);
+    }
+
+    #[test]
+    fn options_hide_until_their_condition_holds() {
+        let source = "title: Start\n---\n\
+            Guide: Ask away.\n\
+            -> What is a loop?\n    Guide: A rule that repeats.\n    <<set $asked = true>>\n    <<jump Start>>\n\
+            -> I'm ready. <<if $asked>>\n    <<advance>>\n\
+            -> Goodbye.\n\
+            ===\n";
+        let mut vars = Variables::new();
+        let mut runner = run(source);
+        assert_eq!(runner.next(&mut vars).unwrap(), line(Some("Guide"), "Ask away."));
+        assert_eq!(runner.next(&mut vars).unwrap(), choices(&["What is a loop?", "Goodbye."]));
+        assert!(runner.is_choosing());
+        assert!(runner.next(&mut vars).is_err(), "must choose first");
+        assert!(runner.choose(5).is_err());
+
+        runner.choose(0).unwrap();
+        assert_eq!(runner.next(&mut vars).unwrap(), line(Some("Guide"), "A rule that repeats."));
+        assert_eq!(runner.next(&mut vars).unwrap(), line(Some("Guide"), "Ask away."));
+        assert_eq!(vars.get("asked"), Some(&Value::Bool(true)));
+        assert_eq!(runner.next(&mut vars).unwrap(), choices(&["What is a loop?", "I'm ready.", "Goodbye."]));
+
+        runner.choose(1).unwrap();
+        assert_eq!(runner.next(&mut vars).unwrap(), Step::Effect(Effect::AdvancePhase));
+        assert_eq!(runner.next(&mut vars).unwrap(), Step::End);
+    }
+
+    #[test]
+    fn variables_set_branch_and_interpolate() {
+        let source = "title: Start\n---\n\
+            <<set $visits = $visits + 1>>\n\
+            <<if $visits == 1>>\n    Guide: First time, {$name}?\n\
+            <<elseif $visits < 3>>\n    Guide: Visit number {$visits}.\n\
+            <<else>>\n    Guide: Again!\n    <<stop>>\n\
+            <<endif>>\n\
+            <<xp 25>>\n\
+            <<glow lamp fast>>\n\
+            ===\n";
+        let script = Script::parse(source).expect("script");
+        let mut vars = Variables::new();
+        vars.insert("name".to_string(), Value::Text("Ada".to_string()));
+
+        let mut runner = Runner::new(script.clone(), "Start").unwrap();
+        assert_eq!(runner.next(&mut vars).unwrap(), line(Some("Guide"), "First time, Ada?"));
+        assert_eq!(runner.next(&mut vars).unwrap(), Step::Effect(Effect::Xp(25)));
+        assert_eq!(
+            runner.next(&mut vars).unwrap(),
+            Step::Effect(Effect::Command { name: "glow".to_string(), args: vec!["lamp".to_string(), "fast".to_string()] })
+        );
+
+        // Variables outlast the conversation
+        let mut runner = Runner::new(script.clone(), "Start").unwrap();
+        assert_eq!(runner.next(&mut vars).unwrap(), line(Some("Guide"), "Visit number 2."));
+
+        let mut runner = Runner::new(script, "Start").unwrap();
+        assert_eq!(runner.next(&mut vars).unwrap(), line(Some("Guide"), "Again!"));
+        assert_eq!(runner.next(&mut vars).unwrap(), Step::End);
+        assert_eq!(vars.get("visits"), Some(&Value::Number(3.0)));
+    }
+
+    #[test]
+    fn jumps_move_between_nodes() {
+        let source = "title: Start\n---\nGuide: One.\n<<jump Next>>\nGuide: Never said.\n===\n\
+            title: Next\n---\nGuide: Two.\n===\n";
+        let mut vars = Variables::new();
+        let mut runner = run(source);
+        assert_eq!(runner.next(&mut vars).unwrap(), line(Some("Guide"), "One."));
+        assert_eq!(runner.next(&mut vars).unwrap(), line(Some("Guide"), "Two."));
+        assert_eq!(runner.next(&mut vars).unwrap(), Step::End);
+    }
+
+    #[test]
+    fn endless_jumps_are_an_error() {
+        let mut vars = Variables::new();
+        let mut runner = run("title: Start\n---\n<<jump Start>>\n===\n");
+        let error = runner.next(&mut vars).unwrap_err();
+        assert!(error.message.contains("loop"), "{}", error);
+        // The conversation is over rather than stuck
+        assert_eq!(runner.next(&mut vars).unwrap(), Step::End);
+
+        // Two nodes bouncing between themselves, setting but never saying anything
+        let mut runner = run("title: Start\n---\n<<set $n = $n + 1>>\n<<jump Other>>\n===\ntitle: Other\n---\n<<jump Start>>\n===\n");
+        assert!(runner.next(&mut vars).is_err());
+
+        // A loop that says something each time round is fine
+        let mut runner = run("title: Start\n---\nGuide: Again.\n<<jump Start>>\n===\n");
+        for _ in 0..(MAX_JUMPS * 2) {
+            assert_eq!(runner.next(&mut vars).unwrap(), line(Some("Guide"), "Again."));
+        }
+    }
+}
@@ -1,7 +1,15 @@
 use bevy::prelude::*;
+use bevy::ecs::system::SystemParam;
 use crate::ui::knowledge_popup::{KnowledgeCollectedEvent};
 use crate::syllabus::SyllabusResource;
 use crate::scoring::XpGainEvent;
+use crate::map::{MapData, MapObject};
+use crate::spatial::{SpatialHash, TileGrid};
+use crate::combat::Hitbox;
+use crate::npc::Npc;
+use crate::actions::{Action, Actions};
+use crate::accessibility::AccessibilitySettings;
+use crate::i18n::{localized, Locale};
 
 // ============================================================================
 // Components
@@ -75,14 +83,14 @@ struct RoomLabel;
 #[derive(Component)]
 struct DoorGuide;
 
-#[derive(Component)]
-struct ControlsHud;
-
 #[derive(Component)]
 pub struct KnowledgeFragment {
+    /// Syllabus fragment id (`module/title`)
+    pub id: String,
     pub title: String,
     pub content: String,
     pub xp_value: u32,
+    pub objective: Option<String>,
 }
 
 #[derive(Component)]
@@ -108,85 +116,80 @@ impl Default for CameraTrauma {
     }
 }
 
-#[derive(Resource)]
-struct RoomDiscovery(Vec<String>);
+/// Shake and particle bursts, scaled by the accessibility settings
+#[derive(SystemParam)]
+pub struct ScreenFx<'w> {
+    trauma: ResMut<'w, CameraTrauma>,
+    settings: Res<'w, AccessibilitySettings>,
+}
+
+impl ScreenFx<'_> {
+    pub fn shake(&mut self, intensity: f32) {
+        self.trauma.intensity = self.settings.shake(intensity);
+    }
+
+    pub fn particles(&self, count: usize) -> usize {
+        self.settings.particle_count(count)
+    }
+}
 
 // ============================================================================
-// Map Data — The Academy
+// Map — the zone the player is in (assets/maps/*.tmj)
 // ============================================================================
 
-const TILE: f32 = 64.0;
+pub const TILE: f32 = 64.0;
 
-/// Room definition: origin (top-left in tile coords), width, height in tiles
-#[allow(dead_code)]
-struct RoomDef {
-    name: &'static str,
-    ox: i32,
-    oy: i32,
-    w: i32,
-    h: i32,
+/// Map the world is built from. Entities spawned from it carry `MapEntity`.
+#[derive(Resource)]
+pub struct ActiveMap {
+    pub handle: Handle<MapData>,
+    /// Asset path of the map, e.g. `maps/academy.tmj`
+    pub path: String,
+    /// Named `spawn` object to place the player on (first spawn if `None`)
+    pub spawn: Option<String>,
+    spawned: bool,
 }
 
-/// A doorway between two rooms — position and orientation
-#[allow(dead_code)]
-struct DoorDef {
-    x: i32,
-    y: i32,
-    horizontal: bool, // true = horizontal passage (opening in top/bottom wall)
-}
+impl ActiveMap {
+    pub fn new(path: &str, spawn: Option<String>, asset_server: &AssetServer) -> Self {
+        Self {
+            handle: asset_server.load(path.to_string()),
+            path: path.to_string(),
+            spawn,
+            spawned: false,
+        }
+    }
 
-fn rooms() -> Vec<RoomDef> {
-    vec![
-        // Academy Hall — center, 12×10 tiles
-        RoomDef { name: "Academy Hall",   ox: -6, oy: -5, w: 12, h: 10 },
-        // Terminal Lab — west, 8×8 tiles
-        RoomDef { name: "Terminal Lab",   ox: -18, oy: -4, w: 8,  h: 8 },
-        // Archive Vault — south, 8×8 tiles
-        RoomDef { name: "Archive Vault",  ox: -4, oy: -17, w: 8,  h: 8 },
-        // Server Core — east, 8×8 tiles
-        RoomDef { name: "Server Core",    ox: 10, oy: -4, w: 8,  h: 8 },
-    ]
+    pub fn data<'a>(&self, maps: &'a Assets<MapData>) -> Option<&'a MapData> {
+        maps.get(&self.handle)
+    }
 }
 
-fn doors() -> Vec<DoorDef> {
-    vec![
-        // West corridor: Academy Hall west wall to Terminal Lab east wall
-        // Passage at y=-1..1 (3 tiles tall), from x=-10 to x=-6
-        DoorDef { x: -7, y: -1, horizontal: false },
-        DoorDef { x: -8, y: -1, horizontal: false },
-        DoorDef { x: -9, y: -1, horizontal: false },
-        DoorDef { x: -10, y: -1, horizontal: false },
-        DoorDef { x: -7, y: 0,  horizontal: false },
-        DoorDef { x: -8, y: 0,  horizontal: false },
-        DoorDef { x: -9, y: 0,  horizontal: false },
-        DoorDef { x: -10, y: 0,  horizontal: false },
-        // South corridor: Academy Hall south wall to Archive Vault north wall
-        // Passage at x=-1..1 (3 tiles wide), from y=-5 to y=-9
-        DoorDef { x: -1, y: -6,  horizontal: true },
-        DoorDef { x: 0,  y: -6,  horizontal: true },
-        DoorDef { x: 1,  y: -6,  horizontal: true },
-        DoorDef { x: -1, y: -7,  horizontal: true },
-        DoorDef { x: 0,  y: -7,  horizontal: true },
-        DoorDef { x: 1,  y: -7,  horizontal: true },
-        DoorDef { x: -1, y: -8,  horizontal: true },
-        DoorDef { x: 0,  y: -8,  horizontal: true },
-        DoorDef { x: 1,  y: -8,  horizontal: true },
-        DoorDef { x: -1, y: -9,  horizontal: true },
-        DoorDef { x: 0,  y: -9,  horizontal: true },
-        DoorDef { x: 1,  y: -9,  horizontal: true },
-        // East corridor: Academy Hall east wall to Server Core west wall
-        // Passage at y=-1..1 (3 tiles tall), from x=6 to x=10
-        DoorDef { x: 6,  y: -1, horizontal: false },
-        DoorDef { x: 7,  y: -1, horizontal: false },
-        DoorDef { x: 8,  y: -1, horizontal: false },
-        DoorDef { x: 9,  y: -1, horizontal: false },
-        DoorDef { x: 6,  y: 0,  horizontal: false },
-        DoorDef { x: 7,  y: 0,  horizontal: false },
-        DoorDef { x: 8,  y: 0,  horizontal: false },
-        DoorDef { x: 9,  y: 0,  horizontal: false },
-    ]
+#[derive(Component)]
+pub struct MapEntity;
+
+/// Solid tiles of the active map, rebuilt whenever a zone is spawned
+#[derive(Resource, Default)]
+pub struct WallGrid(pub TileGrid);
+
+/// Per-frame broadphase over hitboxes and knowledge fragments. Built in
+/// `PreUpdate`, so entities spawned this frame show up next frame and
+/// positions may lag movement by a frame — callers re-check exact distances.
+#[derive(Resource)]
+pub struct Broadphase(pub SpatialHash<Entity>);
+
+impl Default for Broadphase {
+    fn default() -> Self {
+        Self(SpatialHash::new(BROADPHASE_CELL))
+    }
 }
 
+/// Cell size of the broadphase grid — about the size of the largest hitbox
+const BROADPHASE_CELL: f32 = 128.0;
+
+/// How close the player must be to pick up a knowledge fragment
+const FRAGMENT_PICKUP_RADIUS: f32 = 40.0;
+
 // ============================================================================
 // Plugin
 // ============================================================================
@@ -196,9 +199,12 @@ pub struct GameWorldPlugin;
 impl Plugin for GameWorldPlugin {
     fn build(&self, app: &mut App) {
         app.insert_resource(CameraTrauma::default())
-           .insert_resource(RoomDiscovery(vec![]))
+           .init_resource::<WallGrid>()
+           .init_resource::<Broadphase>()
+           .add_systems(PreUpdate, rebuild_broadphase)
            .add_systems(Startup, (setup_camera, spawn_player, spawn_world, spawn_tutorial))
            .add_systems(Update, (
+               spawn_map,
                player_movement,
                update_transforms,
                camera_follow_player,
@@ -211,9 +217,9 @@ impl Plugin for GameWorldPlugin {
                spawn_quest_reward,
                fade_out_rewards,
                reveal_memories,
-               teacher_gravitational_pull,
+               npc_gravitational_pull,
                animate_ambient_glows,
-               collect_knowledge_fragments,
+               (sync_knowledge_fragments, collect_knowledge_fragments).chain(),
                update_floating_text,
                update_particles,
                check_room_discovery,
@@ -255,413 +261,208 @@ fn spawn_player(mut commands: Commands, asset_server: Res<AssetServer>) {
 fn spawn_world(
     mut commands: Commands, 
     asset_server: Res<AssetServer>,
+    syllabus: Option<Res<SyllabusResource>>,
 ) {
-    let floor_handle = asset_server.load("floor.jpg");
-    let wall_handle = asset_server.load("wall.jpg");
+    // Start in the first module's zone
+    let first_map = syllabus.as_ref()
+        .and_then(|syl| syl.syllabus.modules.first())
+        .map(|quest| quest.map_path().to_string())
+        .unwrap_or_else(|| crate::map::DEFAULT_MAP.to_string());
+    commands.insert_resource(ActiveMap::new(&first_map, None, &asset_server));
 
-    // Collect all door tile positions for quick lookup
-    let door_tiles: Vec<(i32, i32)> = doors().iter().map(|d| (d.x, d.y)).collect();
+    // --- Vignette overlay (atmosphere) ---
+    commands.spawn((
+        Sprite {
+            color: Color::srgba(0.0, 0.0, 0.0, 0.0),
+            custom_size: Some(Vec2::new(1280.0 * 2.0, 720.0 * 2.0)),
+            ..default()
+        },
+        Transform::from_xyz(0.0, 0.0, 50.0),
+    ));
+}
 
-    // --- Spawn room floors ---
-    for room in &rooms() {
-        for x in room.ox..(room.ox + room.w) {
-            for y in room.oy..(room.oy + room.h) {
-                commands.spawn((
-                    Sprite {
-                        image: floor_handle.clone(),
-                        custom_size: Some(Vec2::new(TILE, TILE)),
-                        ..default()
-                    },
-                    Transform::from_xyz(x as f32 * TILE, y as f32 * TILE, -10.0),
-                ));
-            }
+// ============================================================================
+// Map Spawning
+// ============================================================================
+
+/// Builds the level from the active map once its asset has loaded: tile
+/// layers, room labels, NPCs, interactables and door guides.
+fn spawn_map(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    active: Option<ResMut<ActiveMap>>,
+    maps: Res<Assets<MapData>>,
+    mut player_query: Query<&mut Transform, With<Player>>,
+    mut wall_grid: ResMut<WallGrid>,
+    syllabus: Option<Res<SyllabusResource>>,
+) {
+    let Some(mut active) = active else { return };
+    if active.spawned { return; }
+    let Some(map) = active.data(&maps) else { return };
+
+    // Walls go into the occupancy grid rather than carrying colliders
+    let walls = map.layers.iter()
+        .filter(|layer| layer.collides)
+        .flat_map(|layer| layer.tiles.iter().map(|tile| tile.pos));
+    wall_grid.0 = TileGrid::from_tiles(walls, TILE);
+    info!("🧱 Wall grid: {} solid tiles", wall_grid.0.solid_count());
+
+    // --- Tile layers (walls come from layers marked `collides`) ---
+    for layer in &map.layers {
+        for tile in &layer.tiles {
+            commands.spawn((
+                Sprite {
+                    image: asset_server.load(&tile.image),
+                    custom_size: Some(Vec2::new(TILE, TILE)),
+                    ..default()
+                },
+                Transform::from_xyz(tile.pos.x as f32 * TILE, tile.pos.y as f32 * TILE, layer.z),
+                MapEntity,
+            ));
         }
     }
 
-    // --- Spawn corridor floors ---
-    for door in &doors() {
+    // --- Room labels (top edge of each room) ---
+    for room in &map.rooms {
+        let top = (room.min.y + room.size.y - 1) as f32;
         commands.spawn((
-            Sprite {
-                image: floor_handle.clone(),
-                custom_size: Some(Vec2::new(TILE, TILE)),
-                ..default()
-            },
-            Transform::from_xyz(door.x as f32 * TILE, door.y as f32 * TILE, -10.0),
+            Text::new(format!("⟐ {} ⟐", room.name)),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(room.color),
+            Transform::from_xyz(room.center().x * TILE, top * TILE, 2.0),
+            RoomLabel,
+            MapEntity,
         ));
     }
 
-    // --- Spawn walls (room perimeters minus doorways) ---
-    for room in &rooms() {
-        for x in (room.ox - 1)..=(room.ox + room.w) {
-            for y in (room.oy - 1)..=(room.oy + room.h) {
-                // Only spawn wall on the perimeter
-                let on_perimeter = x == room.ox - 1 || x == room.ox + room.w
-                    || y == room.oy - 1 || y == room.oy + room.h;
-                if !on_perimeter {
-                    continue;
-                }
-
-                // Skip if this tile is a doorway
-                if door_tiles.contains(&(x, y)) {
-                    continue;
-                }
-                // Skip if this tile is inside another room's floor
-                let inside_another_room = rooms().iter().any(|r| {
-                    x >= r.ox && x < r.ox + r.w && y >= r.oy && y < r.oy + r.h
-                });
-                if inside_another_room {
-                    continue;
-                }
-
-                commands.spawn((
-                    Sprite {
-                        image: wall_handle.clone(),
-                        custom_size: Some(Vec2::new(TILE, TILE)),
-                        ..default()
-                    },
-                    Transform::from_xyz(x as f32 * TILE, y as f32 * TILE, 0.5),
-                    Collider { width: TILE, height: TILE },
-                ));
-            }
-        }
+    // --- NPCs (from map objects, then syllabus NPCs placed by tile) ---
+    let npc_defs = syllabus.as_ref().map(|s| s.syllabus.npcs.as_slice()).unwrap_or_default();
+    let mut placed = Vec::new();
+    for obj in map.objects_of("npc") {
+        let id = obj.prop_str("npc").unwrap_or(&obj.name);
+        let label = obj.prop_str("label").unwrap_or(&obj.name);
+        let npc = Npc::new(id, label, npc_defs.iter().find(|d| d.id == id));
+        placed.push(npc.id.clone());
+        spawn_map_interactable(&mut commands, &asset_server, obj, Some(npc));
     }
-
-    // --- Spawn corridor walls (sides of corridors) ---
-    // West corridor walls (top and bottom edges)
-    for x in -10..=-7 {
-        for &y in &[-2, 1] {
-            if !door_tiles.contains(&(x, y)) {
-                commands.spawn((
-                    Sprite {
-                        image: wall_handle.clone(),
-                        custom_size: Some(Vec2::new(TILE, TILE)),
-                        ..default()
-                    },
-                    Transform::from_xyz(x as f32 * TILE, y as f32 * TILE, 0.5),
-                    Collider { width: TILE, height: TILE },
-                ));
-            }
-        }
-    }
-    // East corridor walls
-    for x in 6..=9 {
-        for &y in &[-2, 1] {
-            if !door_tiles.contains(&(x, y)) {
-                commands.spawn((
-                    Sprite {
-                        image: wall_handle.clone(),
-                        custom_size: Some(Vec2::new(TILE, TILE)),
-                        ..default()
-                    },
-                    Transform::from_xyz(x as f32 * TILE, y as f32 * TILE, 0.5),
-                    Collider { width: TILE, height: TILE },
-                ));
-            }
-        }
+    for def in npc_defs {
+        if def.map.as_deref() != Some(active.path.as_str()) || placed.contains(&def.id) { continue; }
+        let Some(obj) = def.map_object() else { continue };
+        spawn_map_interactable(&mut commands, &asset_server, &obj, Some(Npc::new(&def.id, &def.name, Some(def))));
     }
-    // South corridor walls (left and right edges)
-    for y in -9..=-6 {
-        for &x in &[-2, 2] {
-            if !door_tiles.contains(&(x, y)) {
-                commands.spawn((
-                    Sprite {
-                        image: wall_handle.clone(),
-                        custom_size: Some(Vec2::new(TILE, TILE)),
-                        ..default()
-                    },
-                    Transform::from_xyz(x as f32 * TILE, y as f32 * TILE, 0.5),
-                    Collider { width: TILE, height: TILE },
-                ));
-            }
-        }
+
+    // --- Interactables ---
+    for obj in map.objects_of("interactable") {
+        spawn_map_interactable(&mut commands, &asset_server, obj, None);
     }
 
-    // --- Spawn Interactable Entities ---
+    // --- Portals to other zones ---
+    for obj in map.objects_of("portal") {
+        crate::level::spawn_portal(&mut commands, obj, TILE);
+    }
 
-    // Teacher NPC — in Academy Hall
-    let teacher_pos = Vec3::new(2.0 * TILE, 2.0 * TILE, 1.0);
-    commands.spawn((
-        Sprite {
-            image: asset_server.load("teacher.jpg"),
-            custom_size: Some(Vec2::new(64.0, 64.0)),
-            ..default()
-        },
-        Transform::from_translation(teacher_pos),
-        Collider { width: 40.0, height: 40.0 },
-        InteractionZone { radius: 100.0 },
-        crate::teacher::TeacherMarker,
-        MemoryLink,
-        QuestTrigger { id: "Teacher".to_string(), radius: 100.0 },
-    )).with_children(|parent| {
-        // Name label
-        parent.spawn((
-            Text::new("The Architect"),
-            TextFont { font_size: 14.0, ..default() },
-            TextColor(Color::srgb(0.0, 1.0, 1.0)),
-            Transform::from_xyz(0.0, 50.0, 1.0),
-        ));
-        // Ambient glow
-        parent.spawn((
+    // --- Door guide strips (subtle glow at corridor openings) ---
+    for guide in map.objects_of("guide") {
+        commands.spawn((
             Sprite {
-                color: Color::srgba(0.0, 1.0, 1.0, 0.15),
-                custom_size: Some(Vec2::new(96.0, 96.0)),
+                color: Color::srgba(1.0, 0.75, 0.0, 0.2),
+                custom_size: Some(Vec2::new(TILE * 2.0, 8.0)),
                 ..default()
             },
-            Transform::from_xyz(0.0, 0.0, -0.1),
-            AmbientGlow { base_alpha: 0.15, speed: 2.0 },
+            Transform::from_xyz(guide.tile.x * TILE, guide.tile.y * TILE, 0.3),
+            DoorGuide,
+            AmbientGlow { base_alpha: 0.2, speed: 1.0 },
+            MapEntity,
         ));
-    });
+    }
 
-    // Terminal — in Terminal Lab
-    let terminal_pos = Vec3::new(-14.0 * TILE, 0.0 * TILE, 1.0);
-    commands.spawn((
-        Sprite {
-            image: asset_server.load("terminal.jpg"),
-            custom_size: Some(Vec2::new(96.0, 96.0)),
-            ..default()
-        },
-        Transform::from_translation(terminal_pos),
-        Collider { width: 40.0, height: 40.0 },
-        InteractionZone { radius: 80.0 },
-        MemoryLink,
-        Terminal,
-        QuestTrigger { id: "Terminal".to_string(), radius: 80.0 },
-    )).with_children(|parent| {
-        parent.spawn((
-            Text::new("Ollama Terminal"),
-            TextFont { font_size: 14.0, ..default() },
-            TextColor(Color::srgb(0.0, 1.0, 0.5)),
-            Transform::from_xyz(0.0, 50.0, 1.0),
-        ));
-        parent.spawn((
-            Sprite {
-                color: Color::srgba(0.0, 1.0, 0.5, 0.12),
-                custom_size: Some(Vec2::new(96.0, 96.0)),
-                ..default()
-            },
-            Transform::from_xyz(0.0, 0.0, -0.1),
-            AmbientGlow { base_alpha: 0.12, speed: 1.5 },
-        ));
-    });
+    // --- Player start ---
+    let spawn = active.spawn.as_deref()
+        .and_then(|name| map.object("spawn", name))
+        .map(|obj| obj.tile)
+        .unwrap_or_else(|| map.spawn_point()) * TILE;
+    for mut transform in &mut player_query {
+        transform.translation.x = spawn.x;
+        transform.translation.y = spawn.y;
+    }
 
-    // Archive — in Archive Vault
-    let archive_pos = Vec3::new(0.0 * TILE, -13.0 * TILE, 1.0);
-    commands.spawn((
-        Sprite {
-            image: asset_server.load("archive.jpg"),
-            custom_size: Some(Vec2::new(96.0, 96.0)),
-            ..default()
-        },
-        Transform::from_translation(archive_pos),
-        Collider { width: 40.0, height: 40.0 },
-        InteractionZone { radius: 80.0 },
-        MemoryLink,
-        QuestTrigger { id: "Archive".to_string(), radius: 80.0 },
-    )).with_children(|parent| {
-        parent.spawn((
-            Text::new("The Archive"),
-            TextFont { font_size: 14.0, ..default() },
-            TextColor(Color::srgb(1.0, 0.75, 0.0)),
-            Transform::from_xyz(0.0, 50.0, 1.0),
-        ));
-        parent.spawn((
-            Sprite {
-                color: Color::srgba(1.0, 0.75, 0.0, 0.12),
-                custom_size: Some(Vec2::new(96.0, 96.0)),
-                ..default()
-            },
-            Transform::from_xyz(0.0, 0.0, -0.1),
-            AmbientGlow { base_alpha: 0.12, speed: 1.8 },
-        ));
-    });
+    info!("🗺️ Spawned map '{}'", map.name);
+    active.spawned = true;
+}
 
-    // Server — in Server Core
-    let server_pos = Vec3::new(14.0 * TILE, 0.0 * TILE, 1.0);
-    commands.spawn((
+/// Spawns an `npc` or `interactable` map object with its label and glow.
+fn spawn_map_interactable(commands: &mut Commands, asset_server: &AssetServer, obj: &MapObject, npc: Option<Npc>) {
+    let size = obj.prop_f32("size").unwrap_or(96.0);
+    let radius = obj.prop_f32("radius").unwrap_or(80.0);
+    let color = obj.prop_color("color").unwrap_or(Color::WHITE);
+    let label = npc.as_ref().map(|n| n.name.clone())
+        .unwrap_or_else(|| obj.prop_str("label").unwrap_or(&obj.name).to_string());
+    let glow_alpha = if obj.kind == "npc" { 0.15 } else { 0.12 };
+
+    let mut entity = commands.spawn((
         Sprite {
-            image: asset_server.load("server.jpg"),
-            custom_size: Some(Vec2::new(96.0, 96.0)),
+            image: obj.prop_str("sprite").map(|s| asset_server.load(s.to_string())).unwrap_or_default(),
+            custom_size: Some(Vec2::new(size, size)),
             ..default()
         },
-        Transform::from_translation(server_pos),
+        Transform::from_xyz(obj.tile.x * TILE, obj.tile.y * TILE, 1.0),
         Collider { width: 40.0, height: 40.0 },
-        InteractionZone { radius: 80.0 },
+        InteractionZone { radius },
         MemoryLink,
-        QuestTrigger { id: "Server".to_string(), radius: 80.0 },
-    )).with_children(|parent| {
+        QuestTrigger { id: obj.prop_str("trigger").unwrap_or(&obj.name).to_string(), radius },
+        MapEntity,
+    ));
+    if obj.prop_str("role") == Some("teacher") {
+        entity.insert(crate::teacher::TeacherMarker);
+    }
+    if let Some(npc) = npc {
+        entity.insert(npc);
+    }
+    if obj.prop_bool("terminal") == Some(true) {
+        entity.insert(Terminal);
+    }
+
+    entity.with_children(|parent| {
         parent.spawn((
-            Text::new("Server Core"),
+            Text::new(label),
             TextFont { font_size: 14.0, ..default() },
-            TextColor(Color::srgb(1.0, 0.3, 0.3)),
+            TextColor(color),
             Transform::from_xyz(0.0, 50.0, 1.0),
         ));
         parent.spawn((
             Sprite {
-                color: Color::srgba(1.0, 0.3, 0.3, 0.12),
+                color: color.with_alpha(glow_alpha),
                 custom_size: Some(Vec2::new(96.0, 96.0)),
                 ..default()
             },
             Transform::from_xyz(0.0, 0.0, -0.1),
-            AmbientGlow { base_alpha: 0.12, speed: 2.5 },
-        ));
-    });
-
-    // --- Room Labels (floating text at room entrances) ---
-    let room_labels = [
-        ("⟐ Academy Hall ⟐", 0.0, 3.0 * TILE, Color::srgb(1.0, 1.0, 1.0)),
-        ("⟐ Terminal Lab ⟐", -14.0 * TILE, 3.0 * TILE, Color::srgb(0.0, 1.0, 0.5)),
-        ("⟐ Archive Vault ⟐", 0.0, -10.0 * TILE, Color::srgb(1.0, 0.75, 0.0)),
-        ("⟐ Server Core ⟐", 14.0 * TILE, 3.0 * TILE, Color::srgb(1.0, 0.3, 0.3)),
-    ];
-
-    for (label, lx, ly, color) in room_labels {
-        commands.spawn((
-            Text::new(label),
-            TextFont { font_size: 18.0, ..default() },
-            TextColor(color),
-            Transform::from_xyz(lx, ly, 2.0),
-            RoomLabel,
+            AmbientGlow { base_alpha: glow_alpha, speed: obj.prop_f32("glow_speed").unwrap_or(2.0) },
         ));
-    }
-
-    // --- Door guide strips (subtle glow at corridor openings) ---
-    let guide_positions: Vec<(f32, f32)> = vec![
-        // West corridor entrance
-        (-6.0 * TILE, -0.5 * TILE),
-        // East corridor entrance
-        (5.5 * TILE, -0.5 * TILE),
-        // South corridor entrance
-        (-0.5 * TILE, -5.5 * TILE),
-    ];
-
-    for (gx, gy) in guide_positions {
-        commands.spawn((
-            Sprite {
-                color: Color::srgba(1.0, 0.75, 0.0, 0.2),
-                custom_size: Some(Vec2::new(TILE * 2.0, 8.0)),
-                ..default()
-            },
-            Transform::from_xyz(gx, gy, 0.3),
-            DoorGuide,
-            AmbientGlow { base_alpha: 0.2, speed: 1.0 },
-        ));
-    }
-
-    // --- Knowledge Fragments (educational collectibles) ---
-    let fragments = [
-        (-8.0, -1.0, "Data Sovereignty", "FERPA requires student data to stay on school premises. Local AI means zero data leaves your network."),
-        (-9.0, 0.0, "Open Weights", "Open-weight models like Llama can run entirely offline — no API keys, no cloud bills, no privacy concerns."),
-        (-1.0, -7.0, "Gagné's First Event", "Gagné's 9 Events start with 'Gain Attention' — hook the learner before teaching begins."),
-        (0.0, -8.0, "Constructivism", "Constructivist learning: knowledge is built, not received. Every puzzle you solve builds understanding."),
-        (7.0, -1.0, "Edge Computing", "Running AI on the 'edge' (local device) means instant responses — no network latency, no downtime."),
-        (8.0, 0.0, "Model Quantization", "Quantization shrinks AI models by 75% with minimal quality loss — making local deployment practical."),
-        (3.0, 3.0, "Socratic Method", "The Socratic method asks questions instead of giving answers. Great teachers guide discovery, not memorization."),
-        (-3.0, 3.0, "Flow State", "Mihaly Csikszentmihalyi's Flow: the sweet spot between challenge and skill where learning feels effortless."),
-    ];
-
-    for (fx, fy, title, content) in fragments {
-        commands.spawn((
-            Sprite {
-                color: Color::srgba(1.0, 0.85, 0.0, 0.7),
-                custom_size: Some(Vec2::new(20.0, 20.0)),
-                ..default()
-            },
-            Transform::from_xyz(fx * TILE, fy * TILE, 0.8),
-            KnowledgeFragment {
-                title: title.to_string(),
-                content: content.to_string(),
-                xp_value: 25,
-            },
-            AmbientGlow { base_alpha: 0.7, speed: 3.0 },
-        )).with_children(|parent| {
-            parent.spawn((
-                Text::new("?"),
-                TextFont { font_size: 16.0, ..default() },
-                TextColor(Color::srgb(1.0, 0.85, 0.0)),
-                Transform::from_xyz(0.0, 18.0, 0.1),
-            ));
-        });
-    }
-
-    // --- Vignette overlay (atmosphere) ---
-    commands.spawn((
-        Sprite {
-            color: Color::srgba(0.0, 0.0, 0.0, 0.0),
-            custom_size: Some(Vec2::new(1280.0 * 2.0, 720.0 * 2.0)),
-            ..default()
-        },
-        Transform::from_xyz(0.0, 0.0, 50.0),
-    ));
-
-    // --- On-screen Controls HUD (bottom-right) ---
-    commands.spawn((
-        Node {
-            position_type: PositionType::Absolute,
-            right: Val::Px(12.0),
-            bottom: Val::Px(12.0),
-            padding: UiRect::all(Val::Px(10.0)),
-            flex_direction: FlexDirection::Column,
-            row_gap: Val::Px(2.0),
-            border: UiRect::all(Val::Px(1.0)),
-            ..default()
-        },
-        BackgroundColor(Color::srgba(0.05, 0.05, 0.05, 0.7)),
-        BorderColor(Color::srgb(0.3, 0.3, 0.3)),
-        ControlsHud,
-    )).with_children(|parent| {
-        let controls = [
-            ("⌨ CONTROLS", Color::srgb(1.0, 0.75, 0.0)),
-            ("WASD / ARROWS Move", Color::srgb(0.6, 0.6, 0.6)),
-            ("F          Attack / Swing", Color::srgb(0.6, 0.6, 0.6)),
-            ("T          Interact", Color::srgb(0.6, 0.6, 0.6)),
-            ("SPACE      Talk to AI", Color::srgb(0.6, 0.6, 0.6)),
-            ("1, 2, 3    Choices", Color::srgb(0.6, 0.6, 0.6)),
-            ("C / L / M  Tools", Color::srgb(0.6, 0.6, 0.6)),
-            ("G          Spawn Slime", Color::srgb(0.6, 0.6, 0.6)),
-            ("ESC        Close", Color::srgb(0.6, 0.6, 0.6)),
-        ];
-        for (label, color) in controls {
-            parent.spawn((
-                Text::new(label),
-                TextFont { font_size: 11.0, ..default() },
-                TextColor(color),
-            ));
-        }
     });
 }
 
 // ============================================================================
 // Movement with Wall Collision
 // ============================================================================
+// Map walls live in `WallGrid`; the remaining `Collider` entities (a handful
+// of solid props) are still tested one by one.
 
-type WallQueryFilter = (Without<Player>, Without<Terminal>, Without<crate::teacher::TeacherMarker>);
+type WallQueryFilter = (Without<Player>, Without<Terminal>, Without<Npc>);
 
 fn player_movement(
     time: Res<Time>,
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<Actions>,
     mut player_query: Query<(&mut Player, &mut Transform, &Collider), Without<Terminal>>,
     wall_query: Query<(&Transform, &Collider), WallQueryFilter>,
+    wall_grid: Res<WallGrid>,
     popup_active: Res<crate::ui::knowledge_popup::PopupActive>,
 ) {
     if popup_active.0 { return; }
     for (mut player, mut transform, player_col) in &mut player_query {
-        let mut direction = Vec3::ZERO;
-        
-        if keys.pressed(KeyCode::KeyW) || keys.pressed(KeyCode::ArrowUp) {
-            direction.y += 1.0;
-        }
-        if keys.pressed(KeyCode::KeyS) || keys.pressed(KeyCode::ArrowDown) {
-            direction.y -= 1.0;
-        }
-        if keys.pressed(KeyCode::KeyA) || keys.pressed(KeyCode::ArrowLeft) {
-            direction.x -= 1.0;
-        }
-        if keys.pressed(KeyCode::KeyD) || keys.pressed(KeyCode::ArrowRight) {
-            direction.x += 1.0;
-        }
-        
+        // Already at most unit length; analog sticks walk slower when tilted less
+        let direction = actions.movement().extend(0.0);
+
         if direction.length() > 0.0 {
-            direction = direction.normalize();
             let velocity = direction * player.speed * time.delta_secs();
             player.is_moving = true;
             
@@ -673,10 +474,11 @@ fn player_movement(
 
             let half_pw = player_col.width / 2.0;
             let half_ph = player_col.height / 2.0;
+            let half_player = Vec2::new(half_pw, half_ph);
 
             // Try X movement
             let new_x = transform.translation.x + velocity.x;
-            let mut x_blocked = false;
+            let mut x_blocked = wall_grid.0.overlaps(Vec2::new(new_x, transform.translation.y), half_player);
             for (wall_tf, wall_col) in &wall_query {
                 let half_ww = wall_col.width / 2.0;
                 let half_wh = wall_col.height / 2.0;
@@ -693,7 +495,7 @@ fn player_movement(
 
             // Try Y movement
             let new_y = transform.translation.y + velocity.y;
-            let mut y_blocked = false;
+            let mut y_blocked = wall_grid.0.overlaps(Vec2::new(transform.translation.x, new_y), half_player);
             for (wall_tf, wall_col) in &wall_query {
                 let half_ww = wall_col.width / 2.0;
                 let half_wh = wall_col.height / 2.0;
@@ -799,10 +601,11 @@ fn camera_shake(
 
 fn animate_ambient_glows(
     time: Res<Time>,
+    settings: Res<AccessibilitySettings>,
     mut query: Query<(&AmbientGlow, &mut Sprite)>,
 ) {
     for (glow, mut sprite) in &mut query {
-        let pulse = (ops::sin(time.elapsed_secs() * glow.speed) + 1.0) / 2.0;
+        let pulse = settings.pulse(time.elapsed_secs(), glow.speed);
         let alpha = glow.base_alpha * (0.5 + 0.5 * pulse);
         let c = sprite.color.to_srgba();
         sprite.color = Color::srgba(c.red, c.green, c.blue, alpha);
@@ -813,20 +616,114 @@ fn animate_ambient_glows(
 // Knowledge Fragment Collection
 // ============================================================================
 
+/// Tile position of a syllabus fragment: its explicit tile, else a map
+/// `fragment` object with the same title, else the centre of its room.
+fn fragment_tile(def: &crate::syllabus::FragmentDef, map: &MapData) -> Option<(f32, f32)> {
+    if let Some([x, y]) = def.tile {
+        return Some((x, y));
+    }
+    if let Some(obj) = map.object("fragment", &def.title) {
+        return Some((obj.tile.x, obj.tile.y));
+    }
+    let center = map.room(def.room.as_deref()?)?.center();
+    Some((center.x, center.y))
+}
+
+/// Keeps the world's fragments in step with the syllabus: spawns the current
+/// module's (phase-gated) fragments and despawns those no longer active.
+fn sync_knowledge_fragments(
+    mut commands: Commands,
+    syllabus: Option<ResMut<crate::syllabus::SyllabusResource>>,
+    fragment_query: Query<(Entity, &KnowledgeFragment)>,
+    mut collected_events: EventReader<KnowledgeCollectedEvent>,
+    active_map: Option<Res<ActiveMap>>,
+    maps: Res<Assets<MapData>>,
+    mut score: ResMut<crate::scoring::PlayerScore>,
+) {
+    let Some(mut syl) = syllabus else { return };
+    for ev in collected_events.read() {
+        syl.collected_fragments.insert(ev.id.clone());
+        score.fragments_collected += 1;
+    }
+    // Placement needs the map, so wait for it to spawn
+    let Some(active_map) = active_map else { return };
+    if !active_map.spawned { return; }
+    let Some(map) = active_map.data(&maps) else { return };
+    if !syl.is_changed() && !active_map.is_changed() { return; }
+
+    // A module's fragments only appear in that module's own zone
+    let in_module_zone = syl.current_quest().is_some_and(|q| q.map_path() == active_map.path);
+    let active = if in_module_zone { syl.active_fragments() } else { vec![] };
+
+    for (entity, fragment) in &fragment_query {
+        if !active.iter().any(|(id, _)| *id == fragment.id) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    for (id, def) in active {
+        if fragment_query.iter().any(|(_, f)| f.id == id) { continue; }
+        let Some((fx, fy)) = fragment_tile(def, map) else {
+            warn!("⚠️ Fragment '{}' has no tile and no known room — skipped", id);
+            continue;
+        };
+
+        commands.spawn((
+            Sprite {
+                color: Color::srgba(1.0, 0.85, 0.0, 0.7),
+                custom_size: Some(Vec2::new(20.0, 20.0)),
+                ..default()
+            },
+            Transform::from_xyz(fx * TILE, fy * TILE, 0.8),
+            KnowledgeFragment {
+                id,
+                title: def.title.clone(),
+                content: def.body.clone(),
+                xp_value: def.xp,
+                objective: def.objective.clone(),
+            },
+            AmbientGlow { base_alpha: 0.7, speed: 3.0 },
+        )).with_children(|parent| {
+            parent.spawn((
+                Text::new("?"),
+                TextFont { font_size: 16.0, ..default() },
+                TextColor(Color::srgb(1.0, 0.85, 0.0)),
+                Transform::from_xyz(0.0, 18.0, 0.1),
+            ));
+        });
+    }
+}
+
+/// Re-indexes every hitbox and knowledge fragment for this frame's queries
+fn rebuild_broadphase(
+    mut broadphase: ResMut<Broadphase>,
+    hitboxes: Query<(Entity, &Transform, &Hitbox)>,
+    fragments: Query<(Entity, &Transform), With<KnowledgeFragment>>,
+) {
+    broadphase.0.clear();
+    for (entity, tf, hitbox) in &hitboxes {
+        broadphase.0.insert(entity, tf.translation.truncate(), hitbox.radius);
+    }
+    for (entity, tf) in &fragments {
+        broadphase.0.insert(entity, tf.translation.truncate(), 0.0);
+    }
+}
+
 fn collect_knowledge_fragments(
     mut commands: Commands,
     player_query: Query<&Transform, With<Player>>,
     fragment_query: Query<(Entity, &Transform, &KnowledgeFragment)>,
+    broadphase: Res<Broadphase>,
     mut xp_writer: EventWriter<XpGainEvent>,
-    mut trauma: ResMut<CameraTrauma>,
-    mut score: ResMut<crate::scoring::PlayerScore>,
+    mut fx: ScreenFx,
     mut ev_writer: EventWriter<KnowledgeCollectedEvent>,
 ) {
     let Ok(player_tf) = player_query.get_single() else { return };
 
-    for (entity, frag_tf, fragment) in &fragment_query {
+    for candidate in broadphase.0.query(player_tf.translation.truncate(), FRAGMENT_PICKUP_RADIUS) {
+        let Ok((entity, frag_tf, fragment)) = fragment_query.get(candidate) else { continue };
         let distance = player_tf.translation.distance(frag_tf.translation);
-        if distance < 40.0 {
+        if distance < FRAGMENT_PICKUP_RADIUS {
             // Collect!
             info!("📜 Knowledge Fragment: {} — {}", fragment.title, fragment.content);
 
@@ -841,10 +738,12 @@ fn collect_knowledge_fragments(
                 title: fragment.title.clone(),
                 content: fragment.content.clone(),
                 xp: fragment.xp_value,
+                id: fragment.id.clone(),
+                objective: fragment.objective.clone(),
             });
 
             // Small screen shake
-            trauma.intensity = 0.15;
+            fx.shake(0.15);
 
             // Spawn floating text
             let pos = frag_tf.translation;
@@ -856,9 +755,7 @@ fn collect_knowledge_fragments(
             );
 
             // Spawn particles
-            spawn_particle_burst(&mut commands, pos, Color::srgb(1.0, 0.85, 0.0), 8);
-
-            score.fragments_collected += 1;
+            spawn_particle_burst(&mut commands, pos, Color::srgb(1.0, 0.85, 0.0), fx.particles(8));
 
             // Despawn the fragment
             commands.entity(entity).despawn_recursive();
@@ -873,46 +770,37 @@ fn collect_knowledge_fragments(
 fn check_room_discovery(
     mut commands: Commands,
     player_query: Query<&Transform, With<Player>>,
-    mut discovery: ResMut<RoomDiscovery>,
+    mut score: ResMut<crate::scoring::PlayerScore>,
     mut xp_writer: EventWriter<XpGainEvent>,
-    mut trauma: ResMut<CameraTrauma>,
+    mut fx: ScreenFx,
+    (active_map, maps): (Option<Res<ActiveMap>>, Res<Assets<MapData>>),
+    locale: Res<Locale>,
 ) {
     let Ok(player_tf) = player_query.get_single() else { return };
-    let px = player_tf.translation.x;
-    let py = player_tf.translation.y;
+    let Some(map) = active_map.as_ref().and_then(|m| m.data(&maps)) else { return };
 
     // Check which room the player is in
-    let current_room = if px > -6.0 * TILE && px < 6.0 * TILE && py > -5.0 * TILE && py < 5.0 * TILE {
-        Some("Academy Hall")
-    } else if px < -10.0 * TILE && px > -18.0 * TILE {
-        Some("Terminal Lab")
-    } else if py < -9.0 * TILE && py > -17.0 * TILE {
-        Some("Archive Vault")
-    } else if px > 10.0 * TILE && px < 18.0 * TILE {
-        Some("Server Core")
-    } else {
-        None
-    };
-
-    if let Some(room_name) = current_room {
-        if !discovery.0.contains(&room_name.to_string()) {
-            discovery.0.push(room_name.to_string());
+    if let Some(room) = map.room_at(player_tf.translation.truncate(), TILE) {
+        let key = map.room_key(room);
+        let room_name = room.name.as_str();
+        if !score.rooms_discovered.contains(&key) {
+            score.rooms_discovered.push(key);
             
             xp_writer.send(XpGainEvent {
                 amount: 50,
                 reason: format!("Discovered {}", room_name),
             });
 
-            trauma.intensity = 0.1;
+            fx.shake(0.1);
 
             spawn_floating_text(
                 &mut commands,
                 player_tf.translation,
-                &format!("🗺️ {} Discovered! +50XP", room_name),
+                &locale.fmt("room-discovered", &[("room", &room_name), ("xp", &50)]),
                 Color::srgb(0.0, 1.0, 1.0),
             );
 
-            spawn_particle_burst(&mut commands, player_tf.translation, Color::srgb(0.0, 1.0, 1.0), 12);
+            spawn_particle_burst(&mut commands, player_tf.translation, Color::srgb(0.0, 1.0, 1.0), fx.particles(12));
             
             info!("🗺️ New room discovered: {}", room_name);
         }
@@ -927,16 +815,17 @@ fn spawn_particles_on_quest_advance(
     mut commands: Commands,
     mut events: EventReader<crate::syllabus::QuestAdvancedEvent>,
     player_query: Query<&Transform, With<Player>>,
-    mut trauma: ResMut<CameraTrauma>,
+    mut fx: ScreenFx,
     mut xp_writer: EventWriter<XpGainEvent>,
+    locale: Res<Locale>,
 ) {
     for _event in events.read() {
         if let Ok(player_tf) = player_query.get_single() {
             // Medium shake
-            trauma.intensity = 0.35;
+            fx.shake(0.35);
 
             // Amber particle burst
-            spawn_particle_burst(&mut commands, player_tf.translation, Color::srgb(1.0, 0.75, 0.0), 16);
+            spawn_particle_burst(&mut commands, player_tf.translation, Color::srgb(1.0, 0.75, 0.0), fx.particles(16));
 
             // XP for quest progress
             xp_writer.send(XpGainEvent {
@@ -947,7 +836,7 @@ fn spawn_particles_on_quest_advance(
             spawn_floating_text(
                 &mut commands,
                 player_tf.translation,
-                "⚡ Quest Advanced! +75XP",
+                &locale.fmt("quest-advanced", &[("xp", &75)]),
                 Color::srgb(1.0, 0.75, 0.0),
             );
         }
@@ -1058,7 +947,7 @@ struct RewardGlow {
 
 fn check_proximity_interactions(
     player_query: Query<&Transform, With<Player>>,
-    npc_query: Query<(&Transform, &InteractionZone), With<crate::teacher::TeacherMarker>>,
+    npc_query: Query<(&Transform, &InteractionZone, &Npc)>,
     trigger_query: Query<(&Transform, &QuestTrigger)>,
     mut story_state: ResMut<crate::story_mode::StoryState>,
     mut syllabus: Option<ResMut<SyllabusResource>>,
@@ -1091,47 +980,49 @@ fn check_proximity_interactions(
             }
         }
 
-        // 2. Check Teacher Interaction
-        let mut in_range = false;
-        
-        for (npc_transform, zone) in &npc_query {
-            let distance = player_transform.translation.distance(npc_transform.translation);
-            
-            if distance < zone.radius {
-                in_range = true;
-                break;
-            }
+        // 2. Check NPC Interaction (the closest NPC in range)
+        let nearest = npc_query.iter()
+            .map(|(tf, zone, npc)| (player_transform.translation.distance(tf.translation), zone, npc))
+            .filter(|(distance, zone, _)| *distance < zone.radius)
+            .min_by(|a, b| a.0.total_cmp(&b.0))
+            .map(|(_, _, npc)| npc);
+
+        let previous = story_state.nearby_npc.as_ref().map(|n| n.id.clone());
+        if previous.as_deref() != nearest.map(|n| n.id.as_str()) {
+            story_state.nearby_npc = nearest.cloned();
         }
-        
-        let previously_can_interact = story_state.can_interact;
-        story_state.can_interact = in_range;
-        
-        if in_range && !previously_can_interact {
-            info!("Player entered Teacher range - triggering dialogue");
+        story_state.can_interact = nearest.is_some();
+
+        if let Some(npc) = nearest.filter(|_| previous.is_none()) {
+            info!("Player entered {} range - triggering dialogue", npc.name);
         }
     }
 }
 
-fn teacher_gravitational_pull(
+/// The NPC an Exploration phase is heading for glows brighter as the player nears
+fn npc_gravitational_pull(
     time: Res<Time>,
     player_query: Query<&Transform, With<Player>>,
-    mut teacher_query: Query<(&Transform, &mut Sprite, &InteractionZone), With<crate::teacher::TeacherMarker>>,
+    mut npc_query: Query<(&Transform, &mut Sprite, &Npc)>,
     syllabus: Option<Res<SyllabusResource>>,
+    settings: Res<AccessibilitySettings>,
 ) {
     let Ok(player_transform) = player_query.get_single() else { return };
     let Some(syl) = syllabus else { return };
 
-    if !matches!(syl.current_phase(), crate::syllabus::QuestPhase::Exploration { .. }) {
-        for (_, mut sprite, _) in &mut teacher_query {
+    let target = match syl.current_phase() {
+        crate::syllabus::QuestPhase::Exploration { target, .. } => Some(target.as_str()),
+        _ => None,
+    };
+
+    for (npc_transform, mut sprite, npc) in &mut npc_query {
+        if target != Some(npc.id.as_str()) {
             sprite.color = Color::WHITE;
+            continue;
         }
-        return;
-    }
-
-    for (teacher_transform, mut sprite, _zone) in &mut teacher_query {
-        let distance = player_transform.translation.distance(teacher_transform.translation);
+        let distance = player_transform.translation.distance(npc_transform.translation);
         let intensity = (400.0 - distance).max(0.0) / 400.0; 
-        let pulse = (ops::sin(time.elapsed_secs() * 2.0) + 1.0) / 2.0;
+        let pulse = settings.pulse(time.elapsed_secs(), 2.0);
         
         sprite.color = Color::srgb(
             0.0, 
@@ -1141,36 +1032,56 @@ fn teacher_gravitational_pull(
     }
 }
 
+/// What an NPC's prompt says for the current phase. Phases delivered by
+/// someone else send the player on to them.
+fn npc_prompt(syl: &SyllabusResource, npc: &Npc, actions: &Actions, locale: &Locale) -> String {
+    use crate::syllabus::QuestPhase;
+
+    let speaker = syl.quest_script.current_npc();
+    let phase = syl.current_phase();
+    let delivered = matches!(phase, QuestPhase::Dialogue { .. } | QuestPhase::Reflection { .. } | QuestPhase::Quiz { .. });
+    if delivered && speaker != npc.id {
+        let name = syl.syllabus.npc(speaker).map(|d| d.name.as_str()).unwrap_or(speaker);
+        return locale.fmt("prompt-waiting", &[("name", &name)]);
+    }
+
+    let key = actions.label(Action::Interact);
+    match phase {
+        QuestPhase::Exploration { .. } => locale.fmt("prompt-awaits", &[("name", &npc.name)]),
+        QuestPhase::Dialogue { .. } => locale.fmt("prompt-continue", &[("key", &key)]),
+        QuestPhase::Task { description, .. } => format!("⚡ {}", description),
+        QuestPhase::Reflection { .. } => locale.fmt("prompt-reflect", &[("key", &key)]),
+        QuestPhase::Quiz { options, .. } => {
+            locale.fmt("prompt-answer", &[("keys", &actions.choices_label(options.len(), locale))])
+        }
+        QuestPhase::Complete => locale.t("prompt-complete"),
+    }
+}
+
 fn show_interaction_prompt(
     mut commands: Commands,
     player_query: Query<&Transform, With<Player>>,
-    npc_query: Query<(&Transform, &InteractionZone), With<crate::teacher::TeacherMarker>>,
-    trigger_query: Query<(&Transform, &QuestTrigger)>,
+    npc_query: Query<(&Transform, &InteractionZone, &Npc)>,
+    trigger_query: Query<(&Transform, &QuestTrigger), Without<Npc>>,
     prompt_query: Query<Entity, With<InteractionPrompt>>,
     syllabus: Option<Res<SyllabusResource>>,
+    (actions, locale): (Res<Actions>, Res<Locale>),
 ) {
+    let interact = actions.label(Action::Interact);
     if let Ok(player_transform) = player_query.get_single() {
         let mut should_show_prompt = false;
         let mut prompt_text = String::new();
 
-        // 1. Check Teacher proximity
-        for (npc_transform, zone) in &npc_query {
+        // 1. Check NPC proximity
+        for (npc_transform, zone, npc) in &npc_query {
             let distance = player_transform.translation.distance(npc_transform.translation);
             
             if distance < zone.radius {
                 should_show_prompt = true;
                 
-                prompt_text = if let Some(ref syl) = syllabus {
-                    match syl.current_phase() {
-                        crate::syllabus::QuestPhase::Exploration { .. } => "The Teacher awaits...".to_string(),
-                        crate::syllabus::QuestPhase::Dialogue { .. } => "Press T to continue".to_string(),
-                        crate::syllabus::QuestPhase::Task { description, .. } => format!("⚡ {}", description),
-                        crate::syllabus::QuestPhase::Reflection { .. } => "Press T to reflect".to_string(),
-                        crate::syllabus::QuestPhase::Quiz { .. } => "Press 1, 2, or 3 to answer".to_string(),
-                        crate::syllabus::QuestPhase::Complete => "🏆 Quest Complete!".to_string(),
-                    }
-                } else {
-                    "Press T to talk".to_string()
+                prompt_text = match syllabus {
+                    Some(ref syl) => npc_prompt(syl, npc, &actions, &locale),
+                    None => locale.fmt("prompt-talk", &[("key", &interact)]),
                 };
                 break;
             }
@@ -1179,22 +1090,22 @@ fn show_interaction_prompt(
         // 2. Check other interactable proximity (Terminal, Archive, Server)
         if !should_show_prompt {
             for (trigger_transform, trigger) in &trigger_query {
-                if trigger.id == "Teacher" { continue; } // Already handled above
                 let distance = player_transform.translation.distance(trigger_transform.translation);
                 if distance < trigger.radius {
                     should_show_prompt = true;
+                    let place = crate::syllabus::place_name(&trigger.id, &locale);
                     prompt_text = if let Some(ref syl) = syllabus {
                         match syl.current_phase() {
-                            crate::syllabus::QuestPhase::Task { description, .. } if description.contains(&trigger.id) => {
-                                format!("⚡ Press T: {}", description)
+                            phase @ crate::syllabus::QuestPhase::Task { description, .. } if phase.is_task_at(&trigger.id) => {
+                                locale.fmt("prompt-task", &[("key", &interact), ("task", description)])
                             }
                             crate::syllabus::QuestPhase::Exploration { target, .. } if *target == trigger.id => {
-                                format!("🗺️ You've reached the {}!", trigger.id)
+                                locale.fmt("prompt-reached", &[("place", &place)])
                             }
-                            _ => format!("📍 {} — Explore this area", trigger.id),
+                            _ => locale.fmt("prompt-explore", &[("place", &place)]),
                         }
                     } else {
-                        format!("Press T to interact with {}", trigger.id)
+                        locale.fmt("prompt-interact", &[("key", &interact), ("place", &place)])
                     };
                     break;
                 }
@@ -1231,7 +1142,7 @@ fn show_interaction_prompt(
 
 fn spawn_tutorial(mut commands: Commands) {
     commands.spawn((
-        Text::new("→ Walk to the Teacher to begin your quest"),
+        localized("tutorial-walk"),
         TextFont {
             font_size: 22.0,
             ..default()
@@ -1338,7 +1249,7 @@ fn reveal_memories(
 }
 
 fn terminal_interaction(
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<Actions>,
     player_query: Query<&Transform, With<Player>>,
     trigger_query: Query<(&Transform, &QuestTrigger)>,
     mut syllabus: Option<ResMut<SyllabusResource>>,
@@ -1349,20 +1260,16 @@ fn terminal_interaction(
     // Don't process interaction if puzzle UI is actively open
     if puzzle_state.is_active { return; }
 
-    if keys.just_pressed(KeyCode::KeyT) {
+    if actions.just_pressed(Action::Interact) {
         if let Ok(player_transform) = player_query.get_single() {
             if let Some(ref mut syl) = syllabus {
-                let task_desc = if let crate::syllabus::QuestPhase::Task { description, .. } = syl.current_phase() {
-                    // Skip Terminal tasks — those are handled by puzzle.rs
-                    if description.contains("Terminal") { return; }
-                    Some(description.clone())
-                } else {
-                    None
-                };
+                let phase = syl.current_phase().clone();
+                // Skip Terminal tasks — those are handled by puzzle.rs
+                let is_task = matches!(phase, crate::syllabus::QuestPhase::Task { .. });
 
-                if let Some(description) = task_desc {
+                if is_task && !phase.is_task_at("Terminal") {
                     for (trigger_transform, trigger) in &trigger_query {
-                         if description.contains(&trigger.id) {
+                         if phase.is_task_at(&trigger.id) {
                             let distance = player_transform.translation.distance(trigger_transform.translation);
                             if distance < trigger.radius {
                                 syl.complete_current_task();
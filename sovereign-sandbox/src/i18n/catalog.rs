@@ -0,0 +1,235 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+
+// ============================================================================
+// Message Catalog — the subset of Fluent (.ftl) the game uses
+// ============================================================================
+// `key = value` messages, `#` comments, indented lines continuing the value
+// above on a new line (blank lines between them are kept), `{ $name }`
+// placeables filled in by `format` and `{ "text" }` literals, for text the
+// file would otherwise trim such as leading spaces.
+// Plain std; the checks at the bottom hold every shipped catalog and
+// syllabus translation to the English one.
+
+#[derive(Debug, Clone, Default)]
+pub struct Catalog {
+    messages: HashMap<String, String>,
+}
+
+impl Catalog {
+    pub fn parse(source: &str) -> Result<Self, String> {
+        let mut messages: HashMap<String, String> = HashMap::new();
+        let mut current: Option<String> = None;
+        let mut blank_lines = 0;
+
+        for (n, line) in source.lines().enumerate() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                blank_lines += 1;
+                continue;
+            }
+            if trimmed.starts_with('#') {
+                current = None;
+                continue;
+            }
+
+            if line.starts_with([' ', '\t']) {
+                let Some(value) = current.as_ref().and_then(|key| messages.get_mut(key)) else {
+                    return Err(format!("line {}: indented text outside a message", n + 1));
+                };
+                if !value.is_empty() {
+                    value.push_str(&"\n".repeat(blank_lines + 1));
+                }
+                value.push_str(trimmed);
+                blank_lines = 0;
+                continue;
+            }
+            blank_lines = 0;
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!("line {}: expected `key = value`", n + 1));
+            };
+            let key = key.trim();
+            if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+                return Err(format!("line {}: bad message id '{}'", n + 1, key));
+            }
+            if messages.insert(key.to_string(), value.trim().to_string()).is_some() {
+                return Err(format!("line {}: '{}' is defined twice", n + 1, key));
+            }
+            current = Some(key.to_string());
+        }
+
+        Ok(Self { messages })
+    }
+
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.messages.get(key).map(String::as_str)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.messages.keys().map(String::as_str)
+    }
+
+    /// Keys this catalog has that `other` doesn't, sorted
+    pub fn missing_from(&self, other: &Catalog) -> Vec<&str> {
+        let mut missing: Vec<&str> = self.keys().filter(|key| other.get(key).is_none()).collect();
+        missing.sort_unstable();
+        missing
+    }
+}
+
+/// Fills `{ $name }` placeables from `args`; unknown ones are left as written
+pub fn format(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(len) = rest.find('}') else { break };
+        let placeable = &rest[..len + 1];
+        let inner = placeable[1..len].trim();
+        let literal = inner.strip_prefix('"').and_then(|s| s.strip_suffix('"'));
+        let name = inner.strip_prefix('$');
+        match (literal, name.and_then(|name| args.iter().find(|(arg, _)| *arg == name))) {
+            (Some(text), _) => out.push_str(text),
+            (None, Some((_, value))) => out.push_str(&value.to_string()),
+            (None, None) => out.push_str(placeable),
+        }
+        rest = &rest[len + 1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Names of the placeables in a message, sorted
+#[allow(dead_code)] // for the checks below
+pub fn placeables(template: &str) -> Vec<&str> {
+    let mut names: Vec<&str> = template.split('{')
+        .skip(1)
+        .filter_map(|part| part.split_once('}'))
+        .filter_map(|(inner, _)| inner.trim().strip_prefix('$'))
+        .collect();
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    // Translation checks: every catalog has every English message with the same
+    // placeables, and every translated syllabus has the English one's shape.
+    use super::*;
+
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    const LOCALES_DIR: &str = "assets/locales";
+    const SYLLABUS: &str = "assets/syllabus/module_1.toml";
+
+    fn read(path: &Path) -> String {
+        fs::read_to_string(path).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+    }
+
+    fn parse(path: &Path) -> Catalog {
+        Catalog::parse(&read(path)).unwrap_or_else(|e| panic!("{}: {}", path.display(), e))
+    }
+
+    /// Every `.ftl` except the English reference
+    fn translations() -> Vec<PathBuf> {
+        let mut paths: Vec<PathBuf> = fs::read_dir(LOCALES_DIR)
+            .expect("locales directory")
+            .map(|entry| entry.expect("locale entry").path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "ftl"))
+            .filter(|path| path.file_stem().is_some_and(|stem| stem != "en"))
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    #[test]
+    fn translations_have_every_message() {
+        let english = parse(&Path::new(LOCALES_DIR).join("en.ftl"));
+        let paths = translations();
+        assert!(!paths.is_empty(), "no translations found");
+
+        for path in paths {
+            let missing = english.missing_from(&parse(&path));
+            assert!(missing.is_empty(), "{} falls back to English for: {}", path.display(), missing.join(", "));
+        }
+    }
+
+    #[test]
+    fn translations_have_no_extra_messages() {
+        let english = parse(&Path::new(LOCALES_DIR).join("en.ftl"));
+        for path in translations() {
+            let translated = parse(&path);
+            let extra = translated.missing_from(&english);
+            assert!(extra.is_empty(), "{} has messages English lacks: {}", path.display(), extra.join(", "));
+        }
+    }
+
+    #[test]
+    fn translations_keep_placeables() {
+        let english = parse(&Path::new(LOCALES_DIR).join("en.ftl"));
+        for path in translations() {
+            let translated = parse(&path);
+            for key in english.keys() {
+                let Some(text) = translated.get(key) else { continue };
+                let expected = placeables(english.get(key).unwrap_or_default());
+                assert_eq!(placeables(text), expected, "{} `{}`", path.display(), key);
+            }
+        }
+    }
+
+    /// Module ids, phase kinds and targets, NPC ids and objective ids
+    fn syllabus_shape(path: &Path) -> Vec<String> {
+        let syllabus: toml::Value = toml::from_str(&read(path)).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+        let field = |value: &toml::Value, key: &str| value.get(key).map(|v| v.to_string()).unwrap_or_default();
+        let list = |key: &str| syllabus.get(key).and_then(|v| v.as_array()).cloned().unwrap_or_default();
+
+        let mut shape = Vec::new();
+        for objective in list("objectives") {
+            shape.push(format!("objective {}", field(&objective, "id")));
+        }
+        for npc in list("npcs") {
+            shape.push(format!("npc {} {}", field(&npc, "id"), field(&npc, "map")));
+        }
+        for test in ["pretest", "posttest"] {
+            for item in list(test) {
+                shape.push(format!("{} {} {}", test, field(&item, "correct_index"), field(&item, "objectives")));
+            }
+        }
+        for module in list("modules") {
+            shape.push(format!("module {} {}", field(&module, "id"), field(&module, "map")));
+            let phases = module.get("phases").and_then(|v| v.as_array()).cloned().unwrap_or_default();
+            for phase in phases {
+                // A translated task may name its trigger, which English leaves to the description
+                let target = if field(&phase, "type") == "\"task\"" { String::new() } else { field(&phase, "target") };
+                shape.push(format!(
+                    "  {} npc={} target={} step={} answer={} rewards={}",
+                    field(&phase, "type"),
+                    field(&phase, "npc"),
+                    target,
+                    field(&phase, "gagne_step"),
+                    field(&phase, "correct_index"),
+                    field(&phase, "rewards"),
+                ));
+            }
+            let fragments = module.get("fragments").and_then(|v| v.as_array()).map(Vec::len).unwrap_or(0);
+            shape.push(format!("  {} fragments", fragments));
+        }
+        shape
+    }
+
+    #[test]
+    fn translated_syllabi_match_english() {
+        let english = Path::new(SYLLABUS);
+        let expected = syllabus_shape(english);
+
+        for translation in translations() {
+            let code = translation.file_stem().and_then(|s| s.to_str()).unwrap_or_default();
+            let path = english.with_extension(format!("{}.toml", code));
+            assert_eq!(syllabus_shape(&path), expected, "{} differs from {}", path.display(), english.display());
+        }
+    }
+}
@@ -0,0 +1,234 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::sync::Mutex;
+
+pub mod catalog;
+
+use catalog::Catalog;
+
+// ============================================================================
+// Localization — UI strings and syllabus content per language
+// ============================================================================
+// UI text comes from the `Locale` resource by message id; the catalogs are
+// Fluent-style files under `assets/locales/`, compiled in so the web build has
+// them. A message missing from a translation falls back to English and is
+// reported once (the checks in `catalog` catch them before a release).
+// Texts spawned with `localized` follow the language when it changes; the
+// syllabus has its own per-language file (see `syllabus::syllabus_source`).
+// The choice is saved, to `LANGUAGE_PATH` natively and to localStorage in the
+// browser.
+
+const LANGUAGE_PATH: &str = "assets/memory/language.json";
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY: &str = "sovereign-sandbox.language";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::Spanish];
+
+    /// BCP 47 code, also the catalog and syllabus file suffix
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+        }
+    }
+
+    /// The language's name for itself, as the selector shows it
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Español",
+        }
+    }
+
+    /// Name used when asking the AI to answer in this language
+    pub fn english_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::Spanish => "Spanish",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let i = Self::ALL.iter().position(|l| l == self).unwrap_or(0);
+        Self::ALL[(i + 1) % Self::ALL.len()]
+    }
+
+    fn catalog_source(&self) -> &'static str {
+        match self {
+            Language::English => include_str!("../../assets/locales/en.ftl"),
+            Language::Spanish => include_str!("../../assets/locales/es.ftl"),
+        }
+    }
+
+    fn catalog(&self) -> Catalog {
+        Catalog::parse(self.catalog_source()).unwrap_or_else(|e| {
+            error!("Failed to parse {}.ftl: {}", self.code(), e);
+            Catalog::default()
+        })
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load() -> Self {
+        std::fs::read_to_string(LANGUAGE_PATH).ok()
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn load() -> Self {
+        web_sys::window()
+            .and_then(|w| w.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(STORAGE_KEY).ok().flatten())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self) {
+        let path = std::path::Path::new(LANGUAGE_PATH);
+        let result = serde_json::to_string(self)
+            .map_err(std::io::Error::other)
+            .and_then(|json| {
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                std::fs::write(path, json)
+            });
+        if let Err(e) = result {
+            error!("Failed to save language: {}", e);
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn save(&self) {
+        let Ok(json) = serde_json::to_string(self) else { return };
+        let storage = web_sys::window().and_then(|w| w.local_storage().ok().flatten());
+        if storage.is_none_or(|s| s.set_item(STORAGE_KEY, &json).is_err()) {
+            error!("Failed to save language to localStorage");
+        }
+    }
+}
+
+/// The active language and its messages
+#[derive(Resource)]
+pub struct Locale {
+    language: Language,
+    catalog: Catalog,
+    fallback: Catalog,
+    /// Ids already reported missing, so each is logged once
+    reported: Mutex<HashSet<String>>,
+}
+
+impl Locale {
+    pub fn new(language: Language) -> Self {
+        let catalog = language.catalog();
+        let fallback = Language::English.catalog();
+        let missing = fallback.missing_from(&catalog);
+        if !missing.is_empty() {
+            warn!("🌐 {} messages have no {} translation: {}", missing.len(), language.code(), missing.join(", "));
+        }
+        Self {
+            language,
+            catalog,
+            fallback,
+            reported: Mutex::new(HashSet::new()),
+        }
+    }
+
+    pub fn language(&self) -> Language {
+        self.language
+    }
+
+    pub fn set_language(&mut self, language: Language) {
+        *self = Self::new(language);
+    }
+
+    /// The message `key`
+    pub fn t(&self, key: &str) -> String {
+        self.fmt(key, &[])
+    }
+
+    /// The message `key` if any catalog has it, for ids built from data
+    pub fn lookup(&self, key: &str) -> Option<String> {
+        self.catalog.get(key).or_else(|| self.fallback.get(key)).map(str::to_string)
+    }
+
+    /// The message `key` with its `{ $name }` placeables filled from `args`
+    pub fn fmt(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let template = self.catalog.get(key).unwrap_or_else(|| {
+            self.report_missing(key);
+            self.fallback.get(key).unwrap_or(key)
+        });
+        catalog::format(template, args)
+    }
+
+    /// Tells the model which language to answer in; added to every prompt
+    pub fn reply_instruction(&self) -> String {
+        format!("LANGUAGE: Reply only in {}, whatever language the lesson text is in.", self.language.english_name())
+    }
+
+    fn report_missing(&self, key: &str) {
+        let Ok(mut reported) = self.reported.lock() else { return };
+        if reported.insert(key.to_string()) {
+            if self.fallback.get(key).is_some() {
+                warn!("🌐 '{}' has no {} translation, using English", key, self.language.code());
+            } else {
+                error!("🌐 Unknown message '{}'", key);
+            }
+        }
+    }
+}
+
+/// Text showing message `key`, kept in the current language
+#[derive(Component)]
+pub struct Localized(pub &'static str);
+
+/// Bundle for a text that shows message `key`
+pub fn localized(key: &'static str) -> (Text, Localized) {
+    (Text::new(""), Localized(key))
+}
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub struct LocalizationPlugin;
+
+impl Plugin for LocalizationPlugin {
+    fn build(&self, app: &mut App) {
+        let language = Language::load();
+        info!("🌐 Language: {}", language.native_name());
+        app.insert_resource(Locale::new(language))
+           .add_systems(PostUpdate, (
+               save_language,
+               apply_localized,
+           ).before(bevy::ui::UiSystem::Prepare));
+    }
+}
+
+fn save_language(locale: Res<Locale>) {
+    if locale.is_changed() && !locale.is_added() {
+        locale.language().save();
+    }
+}
+
+fn apply_localized(locale: Res<Locale>, mut texts: Query<(Ref<Localized>, &mut Text)>) {
+    for (localized, mut text) in &mut texts {
+        if !locale.is_changed() && !localized.is_added() { continue; }
+        let content = locale.t(localized.0);
+        if text.0 != content {
+            text.0 = content;
+        }
+    }
+}
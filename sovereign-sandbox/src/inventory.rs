@@ -1,5 +1,7 @@
 use bevy::prelude::*;
 use crate::ai::memory::MemoryStoreResource;
+use crate::narration::{Narrated, Priority};
+use crate::i18n::{localized, Locale};
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -19,26 +21,24 @@ pub enum ToolId {
 }
 
 impl ToolId {
-    pub fn name(&self) -> &'static str {
+    /// Message id of the name; the description is `<key>-desc`
+    pub fn key(&self) -> &'static str {
         match self {
-            ToolId::OllamaCompass => "Ollama Compass",
-            ToolId::LogicLens => "Logic Lens",
-            ToolId::FeedbackMirror => "Feedback Mirror",
-            ToolId::ThinkingCapPhi => "The Tiny Beret (Phi-3)",
-            ToolId::ThinkingCapLlama => "The Llama Top Hat (Llama-3)",
-            ToolId::ThinkingCapMistral => "The Mistral Fedora (Mistral)",
+            ToolId::OllamaCompass => "tool-ollama-compass",
+            ToolId::LogicLens => "tool-logic-lens",
+            ToolId::FeedbackMirror => "tool-feedback-mirror",
+            ToolId::ThinkingCapPhi => "tool-cap-phi",
+            ToolId::ThinkingCapLlama => "tool-cap-llama",
+            ToolId::ThinkingCapMistral => "tool-cap-mistral",
         }
     }
 
-    pub fn description(&self) -> &'static str {
-        match self {
-            ToolId::OllamaCompass => "Navigate the local AI landscape. Shows you're connected to your sovereign assistant.",
-            ToolId::LogicLens => "Translates teaching intent into game logic. The bridge between imagination and code.",
-            ToolId::FeedbackMirror => "Reflects student interactions back to you. The key to iterative design.",
-            ToolId::ThinkingCapPhi => "Fast, lightweight, and perfect for edge devices. A smart choice for quick thoughts.",
-            ToolId::ThinkingCapLlama => "Powerful and versatile. The standard for heavy lifting and complex reasoning.",
-            ToolId::ThinkingCapMistral => "Efficient and open. A balanced choice for the modern sovereign architect.",
-        }
+    pub fn name(&self, locale: &Locale) -> String {
+        locale.t(self.key())
+    }
+
+    pub fn description(&self, locale: &Locale) -> String {
+        locale.t(&format!("{}-desc", self.key()))
     }
 
     pub fn icon(&self) -> &'static str {
@@ -89,7 +89,7 @@ impl Inventory {
         if !self.tools.get(&tool).copied().unwrap_or(false) {
             self.tools.insert(tool, true);
             self.newly_acquired = Some(tool);
-            info!("🎁 Tool Acquired: {} - {}", tool.icon(), tool.name());
+            info!("🎁 Tool Acquired: {} {:?}", tool.icon(), tool);
         }
     }
 
@@ -177,7 +177,7 @@ fn setup_inventory_ui(mut commands: Commands) {
         .with_children(|parent| {
             // Header: "INVENTORY"
             parent.spawn((
-                Text::new("═ INVENTORY ═"),
+                localized("inventory-header"),
                 TextFont {
                     font_size: 13.0,
                     ..default()
@@ -214,7 +214,7 @@ fn setup_inventory_ui(mut commands: Commands) {
 
             // Hint
             parent.spawn((
-                Text::new("Tools unlock as you progress"),
+                localized("inventory-hint"),
                 TextFont {
                     font_size: 11.0,
                     ..default()
@@ -224,7 +224,7 @@ fn setup_inventory_ui(mut commands: Commands) {
 
              // Header: "THINKING CAPS"
              parent.spawn((
-                Text::new("═ THINKING CAPS ═"),
+                localized("inventory-caps-header"),
                 TextFont {
                     font_size: 13.0,
                     ..default()
@@ -277,24 +277,27 @@ fn check_tool_unlocks(
 fn handle_quest_rewards(
     mut events: EventReader<ItemGetEvent>,
     mut inventory: ResMut<Inventory>,
+    mut sfx_writer: EventWriter<crate::audio::SfxEvent>,
 ) {
     for event in events.read() {
         let tool = event.0;
         if !inventory.has_tool(tool) {
             inventory.unlock_tool(tool);
+            sfx_writer.send(crate::audio::SfxEvent::ToolUnlock);
         }
     }
 }
 
 fn update_inventory_ui(
     inventory: Res<Inventory>,
+    locale: Res<Locale>,
     slot_query: Query<(&InventorySlot, &Children)>,
     mut text_query: Query<&mut Text>,
     mut text_color_query: Query<&mut TextColor>,
     mut border_query: Query<(&InventorySlot, &mut BorderColor, &mut BackgroundColor), Without<InventoryPanel>>,
 ) {
 
-    if inventory.is_changed() {
+    if inventory.is_changed() || locale.is_changed() {
         // Update text in slots
         for (slot, children) in &slot_query {
             let is_unlocked = inventory.has_tool(slot.tool_id);
@@ -302,7 +305,7 @@ fn update_inventory_ui(
             for &child in children.iter() {
                 if let Ok(mut text) = text_query.get_mut(child) {
                     if is_unlocked {
-                        *text = Text::new(format!("{} {}", slot.tool_id.icon(), slot.tool_id.name()));
+                        *text = Text::new(format!("{} {}", slot.tool_id.icon(), slot.tool_id.name(&locale)));
                     }
                 }
                 
@@ -342,6 +345,7 @@ fn update_inventory_ui(
 fn show_acquisition_notification(
     mut commands: Commands,
     inventory: Res<Inventory>,
+    locale: Res<Locale>,
     notification_query: Query<Entity, With<AcquisitionNotification>>,
 ) {
     // Logic handles rendering if newly_acquired is set.
@@ -381,39 +385,43 @@ fn show_acquisition_notification(
             ))
             .with_children(|parent| {
                 parent.spawn((
-                    Text::new(format!("🎁 TOOL ACQUIRED! {}", tool.icon())),
+                    Text::new(locale.fmt("inventory-acquired", &[("icon", &tool.icon())])),
                     TextFont {
                         font_size: 24.0,
                         ..default()
                     },
                     TextColor(Color::srgb(1.0, 0.75, 0.0)),
+                    Narrated(Priority::Assertive),
                 ));
 
                 parent.spawn((
-                    Text::new(tool.name()),
+                    Text::new(tool.name(&locale)),
                     TextFont {
                         font_size: 20.0,
                         ..default()
                     },
                     TextColor(Color::srgb(1.0, 1.0, 1.0)),
+                    Narrated::default(),
                 ));
 
                 parent.spawn((
-                    Text::new(tool.description()),
+                    Text::new(tool.description(&locale)),
                     TextFont {
                         font_size: 14.0,
                         ..default()
                     },
                     TextColor(Color::srgb(0.8, 0.8, 0.8)),
+                    Narrated::default(),
                 ));
 
                 parent.spawn((
-                    Text::new("Press any key to continue..."),
+                    Text::new(locale.t("press-any-key")),
                     TextFont {
                         font_size: 12.0,
                         ..default()
                     },
                     TextColor(Color::srgb(0.6, 0.6, 0.6)),
+                    Narrated::default(),
                 ));
             });
         }
@@ -421,34 +429,31 @@ fn show_acquisition_notification(
 }
 
 fn dismiss_notification(
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<crate::actions::Actions>,
     mut inventory: ResMut<Inventory>,
     notification_query: Query<Entity, With<AcquisitionNotification>>,
 ) {
     // If there's a notification showing and any key is pressed, dismiss it
-    if !notification_query.is_empty() && keys.get_just_pressed().next().is_some() {
+    if !notification_query.is_empty() && actions.any_just_pressed() {
         inventory.clear_notification();
     }
 }
 
 fn toggle_tools(
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<crate::actions::Actions>,
     mut inventory: ResMut<Inventory>,
 ) {
-    let mut tool_to_toggle = None;
-    
-    if keys.just_pressed(KeyCode::KeyC) { tool_to_toggle = Some(ToolId::OllamaCompass); }
-    if keys.just_pressed(KeyCode::KeyL) { tool_to_toggle = Some(ToolId::LogicLens); }
-    if keys.just_pressed(KeyCode::KeyM) { tool_to_toggle = Some(ToolId::FeedbackMirror); }
+    let tool_to_toggle = [ToolId::OllamaCompass, ToolId::LogicLens, ToolId::FeedbackMirror].into_iter()
+        .find(|tool| actions.just_pressed(crate::actions::Action::ToggleTool(*tool)));
     
     if let Some(tool) = tool_to_toggle {
         if inventory.has_tool(tool) {
             if inventory.active_tool == Some(tool) {
                 inventory.active_tool = None;
-                info!("📴 Deactivated {}", tool.name());
+                info!("📴 Deactivated {:?}", tool);
             } else {
                 inventory.active_tool = Some(tool);
-                info!("🔋 Activated {}", tool.name());
+                info!("🔋 Activated {:?}", tool);
             }
         }
     }
@@ -457,6 +462,7 @@ fn toggle_tools(
 fn update_feedback_mirror(
     mut commands: Commands,
     inventory: Res<Inventory>,
+    locale: Res<Locale>,
     memory_store: Option<Res<MemoryStoreResource>>,
     mut display_query: Query<(Entity, &mut Text), With<FeedbackMirrorDisplay>>,
 ) {
@@ -467,9 +473,9 @@ fn update_feedback_mirror(
             // Fetch recent memories
             let memories = store.0.get_recent_memories(3).unwrap_or_default();
             
-            let mut display_text = "🪞 FEEDBACK MIRROR (Recent Memories)\n".to_string();
+            let mut display_text = format!("{}\n", locale.t("mirror-header"));
             if memories.is_empty() {
-                display_text.push_str("\n(No memories found in sovereign store)");
+                display_text.push_str(&format!("\n{}", locale.t("mirror-empty")));
             } else {
                 for mem in memories {
                     display_text.push_str(&format!("\n• [{}] {}", mem.source, mem.content));
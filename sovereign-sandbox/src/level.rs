@@ -0,0 +1,308 @@
+use bevy::prelude::*;
+use crate::GameState;
+use crate::game_world::{ActiveMap, MapEntity, Player};
+use crate::map::MapObject;
+use crate::syllabus::SyllabusResource;
+use crate::i18n::Locale;
+
+// ============================================================================
+// Levels — per-module zones, portals and transitions
+// ============================================================================
+// Each syllabus module names the zone (map) it plays in. Rolling into the next
+// module fades out, swaps the zone, shows a chapter card and fades back in.
+// Portals in a map lead to other zones once enough modules are reached.
+// Picking another language on the title screen rebuilds the zone, so its
+// NPCs carry the names from that language's syllabus.
+
+const FADE_SECS: f32 = 0.5;
+const CARD_SECS: f32 = 1.5;
+
+/// How close the player must be to step through a portal
+const PORTAL_RADIUS: f32 = 40.0;
+
+/// A doorway to another zone, unlocked from module `unlock_module` (0-based)
+#[derive(Component)]
+pub struct Portal {
+    pub target: String,
+    pub spawn: Option<String>,
+    pub unlock_module: usize,
+    pub label: String,
+}
+
+#[derive(Component)]
+struct PortalLabel;
+
+#[derive(Resource)]
+struct LevelTransition {
+    target: String,
+    spawn: Option<String>,
+    card: String,
+    elapsed: f32,
+    swapped: bool,
+}
+
+#[derive(Component)]
+struct TransitionOverlay;
+
+#[derive(Component)]
+struct ChapterCardText;
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub struct LevelPlugin;
+
+impl Plugin for LevelPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, (
+               watch_module_changes,
+               use_portals.run_if(in_state(GameState::Playing)),
+               start_transition,
+               run_transition,
+               swap_zone_when_dark,
+               update_portal_locks,
+           ).chain())
+           .add_systems(Update, rebuild_zone_for_language
+               .run_if(in_state(GameState::Menu))
+               .run_if(resource_changed::<Locale>.and(not(resource_added::<Locale>))));
+    }
+}
+
+/// Spawns a `portal` map object. Called while building a map.
+pub fn spawn_portal(commands: &mut Commands, obj: &MapObject, tile_size: f32) {
+    let Some(target) = obj.prop_str("target") else {
+        warn!("⚠️ Portal '{}' has no target map — skipped", obj.name);
+        return;
+    };
+    let color = obj.prop_color("color").unwrap_or(Color::srgb(0.55, 0.36, 0.96));
+
+    commands.spawn((
+        Sprite {
+            color: color.with_alpha(0.5),
+            custom_size: Some(Vec2::new(tile_size, tile_size)),
+            ..default()
+        },
+        Transform::from_xyz(obj.tile.x * tile_size, obj.tile.y * tile_size, 0.6),
+        Portal {
+            target: target.to_string(),
+            spawn: obj.prop_str("spawn").map(str::to_string),
+            unlock_module: obj.prop_f32("unlock_module").unwrap_or(0.0) as usize,
+            label: obj.prop_str("label").unwrap_or(&obj.name).to_string(),
+        },
+        MapEntity,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(""),
+            TextFont { font_size: 14.0, ..default() },
+            TextColor(color),
+            Transform::from_xyz(0.0, 44.0, 1.0),
+            PortalLabel,
+        ));
+    });
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+/// Starts a transition when the syllabus rolls into another module. Outside
+/// of play (e.g. a restart from the victory screen) the zone swaps instantly.
+fn watch_module_changes(
+    mut commands: Commands,
+    syllabus: Option<Res<SyllabusResource>>,
+    mut last_module: Local<Option<usize>>,
+    (state, locale): (Res<State<GameState>>, Res<Locale>),
+    mut active_map: Option<ResMut<ActiveMap>>,
+    asset_server: Res<AssetServer>,
+    map_entities: Query<Entity, With<MapEntity>>,
+) {
+    let Some(syl) = syllabus else { return };
+    let index = syl.current_module_index;
+    let previous = last_module.replace(index);
+    if previous.is_none() || previous == Some(index) { return; }
+
+    // Past the last module there is no zone to go to
+    let Some(quest) = syl.current_quest() else { return };
+
+    if *state.get() == GameState::Playing {
+        info!("🚪 Module {} — transitioning to {}", index + 1, quest.map_path());
+        commands.insert_resource(LevelTransition {
+            target: quest.map_path().to_string(),
+            spawn: None,
+            card: locale.fmt("module-card", &[("n", &(index + 1)), ("title", &quest.title)]),
+            elapsed: 0.0,
+            swapped: false,
+        });
+    } else if let Some(ref mut active) = active_map {
+        swap_zone(&mut commands, active, quest.map_path(), None, &asset_server, &map_entities);
+    }
+}
+
+fn rebuild_zone_for_language(
+    mut commands: Commands,
+    syllabus: Option<Res<SyllabusResource>>,
+    mut active_map: Option<ResMut<ActiveMap>>,
+    asset_server: Res<AssetServer>,
+    map_entities: Query<Entity, With<MapEntity>>,
+) {
+    let Some(quest) = syllabus.as_ref().and_then(|syl| syl.current_quest()) else { return };
+    if let Some(ref mut active) = active_map {
+        swap_zone(&mut commands, active, quest.map_path(), None, &asset_server, &map_entities);
+    }
+}
+
+fn use_portals(
+    mut commands: Commands,
+    player_query: Query<&Transform, With<Player>>,
+    portal_query: Query<(&Transform, &Portal)>,
+    syllabus: Option<Res<SyllabusResource>>,
+    transition: Option<Res<LevelTransition>>,
+) {
+    if transition.is_some() { return; }
+    let Ok(player_tf) = player_query.get_single() else { return };
+    let module = syllabus.map(|s| s.current_module_index).unwrap_or(0);
+
+    for (portal_tf, portal) in &portal_query {
+        if module < portal.unlock_module { continue; }
+        if player_tf.translation.truncate().distance(portal_tf.translation.truncate()) < PORTAL_RADIUS {
+            info!("🚪 Portal → {}", portal.target);
+            commands.insert_resource(LevelTransition {
+                target: portal.target.clone(),
+                spawn: portal.spawn.clone(),
+                card: portal.label.clone(),
+                elapsed: 0.0,
+                swapped: false,
+            });
+            return;
+        }
+    }
+}
+
+/// Spawns the (initially transparent) overlay and chapter card
+fn start_transition(
+    mut commands: Commands,
+    transition: Option<Res<LevelTransition>>,
+    mut popup_active: ResMut<crate::ui::knowledge_popup::PopupActive>,
+) {
+    let Some(transition) = transition else { return };
+    if !transition.is_added() { return; }
+
+    popup_active.0 = true;
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.0)),
+        GlobalZIndex(150),
+        TransitionOverlay,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(transition.card.clone()),
+            TextFont { font_size: 36.0, ..default() },
+            TextColor(Color::srgba(0.75, 0.5, 1.0, 0.0)),
+            TextLayout::new_with_justify(JustifyText::Center),
+            ChapterCardText,
+        ));
+    });
+}
+
+/// Fade out → chapter card → fade in
+fn run_transition(
+    mut commands: Commands,
+    time: Res<Time>,
+    transition: Option<ResMut<LevelTransition>>,
+    mut overlay_query: Query<(Entity, &mut BackgroundColor), With<TransitionOverlay>>,
+    mut card_query: Query<&mut TextColor, With<ChapterCardText>>,
+    mut popup_active: ResMut<crate::ui::knowledge_popup::PopupActive>,
+) {
+    let Some(mut transition) = transition else { return };
+
+    transition.elapsed += time.delta_secs();
+    let t = transition.elapsed;
+    let total = FADE_SECS * 2.0 + CARD_SECS;
+
+    let darkness = if t < FADE_SECS {
+        t / FADE_SECS
+    } else if t < FADE_SECS + CARD_SECS {
+        1.0
+    } else {
+        1.0 - (t - FADE_SECS - CARD_SECS) / FADE_SECS
+    }.clamp(0.0, 1.0);
+    let card_alpha = if (FADE_SECS..FADE_SECS + CARD_SECS).contains(&t) { 1.0 } else { 0.0 };
+
+    for (_, mut bg) in &mut overlay_query {
+        bg.0 = Color::srgba(0.0, 0.0, 0.0, darkness);
+    }
+    for mut color in &mut card_query {
+        color.0 = Color::srgba(0.75, 0.5, 1.0, card_alpha);
+    }
+
+    if t >= total {
+        for (entity, _) in &overlay_query {
+            commands.entity(entity).despawn_recursive();
+        }
+        commands.remove_resource::<LevelTransition>();
+        popup_active.0 = false;
+    }
+}
+
+/// Swaps the zone once the screen is fully dark
+fn swap_zone_when_dark(
+    mut commands: Commands,
+    transition: Option<ResMut<LevelTransition>>,
+    mut active_map: Option<ResMut<ActiveMap>>,
+    asset_server: Res<AssetServer>,
+    map_entities: Query<Entity, With<MapEntity>>,
+) {
+    let Some(mut transition) = transition else { return };
+    if transition.swapped || transition.elapsed < FADE_SECS { return; }
+
+    transition.swapped = true;
+    if let Some(ref mut active) = active_map {
+        let (target, spawn) = (transition.target.clone(), transition.spawn.clone());
+        swap_zone(&mut commands, active, &target, spawn, &asset_server, &map_entities);
+    }
+}
+
+fn swap_zone(
+    commands: &mut Commands,
+    active: &mut ActiveMap,
+    target: &str,
+    spawn: Option<String>,
+    asset_server: &AssetServer,
+    map_entities: &Query<Entity, With<MapEntity>>,
+) {
+    for entity in map_entities {
+        commands.entity(entity).despawn_recursive();
+    }
+    *active = ActiveMap::new(target, spawn, asset_server);
+}
+
+fn update_portal_locks(
+    syllabus: Option<Res<SyllabusResource>>,
+    portal_query: Query<(Ref<Portal>, &Children)>,
+    mut label_query: Query<&mut Text, With<PortalLabel>>,
+) {
+    let module = syllabus.as_ref().map(|s| s.current_module_index).unwrap_or(0);
+    let syllabus_changed = syllabus.is_some_and(|s| s.is_changed());
+
+    for (portal, children) in &portal_query {
+        if !syllabus_changed && !portal.is_added() { continue; }
+        let text = if module >= portal.unlock_module {
+            format!("⇨ {}", portal.label)
+        } else {
+            format!("🔒 {}", portal.label)
+        };
+        for child in children.iter() {
+            if let Ok(mut label) = label_query.get_mut(*child) {
+                *label = Text::new(text.clone());
+            }
+        }
+    }
+}
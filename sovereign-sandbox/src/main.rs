@@ -15,10 +15,22 @@ pub enum GameState {
     #[default]
     Menu,
     Boot,
+    PreTest,
     Playing,
+    PostTest,
+    Review,
     Victory,
 }
 
+/// Paused sits inside Playing, so the world stays loaded underneath it
+#[derive(SubStates, Debug, Clone, PartialEq, Eq, Hash, Default)]
+#[source(GameState = GameState::Playing)]
+pub enum PauseState {
+    #[default]
+    Running,
+    Paused,
+}
+
 mod ai;
 mod teacher;
 mod syllabus;
@@ -33,6 +45,29 @@ mod ui;
 mod title_screen;
 mod audio;
 mod combat;
+mod map;
+mod level;
+mod mastery;
+mod assessment;
+mod review;
+mod spaced_repetition;
+mod spatial;
+mod navigation;
+mod npc;
+mod dialogue;
+mod actions;
+mod touch;
+mod accessibility;
+mod narration;
+mod narration_sink;
+mod i18n;
+mod settings;
+mod sfx_synth;
+mod speech;
+mod command_grammar;
+mod voice_commands;
+mod tts;
+mod teacher_voice;
 
 use ai::AiPlugin;
 use ai::memory::{MemoryStore, MemoryStoreResource};
@@ -46,10 +81,28 @@ use quiz::QuizPlugin;
 use scoring::ScoringPlugin;
 use puzzle::PuzzlePlugin;
 use ui::knowledge_popup::KnowledgePopupPlugin;
+use ui::minimap::MinimapPlugin;
 use ui::victory_screen::VictoryScreenPlugin;
 use title_screen::TitleScreenPlugin;
 use audio::GameAudioPlugin;
 use combat::CombatPlugin;
+use map::MapPlugin;
+use level::LevelPlugin;
+use mastery::MasteryPlugin;
+use assessment::AssessmentPlugin;
+use review::ReviewPlugin;
+use navigation::NavigationPlugin;
+use dialogue::DialoguePlugin;
+use actions::ActionsPlugin;
+use touch::TouchPlugin;
+use accessibility::AccessibilityPlugin;
+use narration::NarrationPlugin;
+use speech::SpeechPlugin;
+use voice_commands::VoiceCommandPlugin;
+use teacher_voice::TeacherVoicePlugin;
+use i18n::LocalizationPlugin;
+use settings::{GameSettings, SettingsPlugin, LAYOUT_SIZE};
+use ui::pause_menu::PauseMenuPlugin;
 use std::sync::Arc;
 use std::path::Path;
 
@@ -73,13 +126,17 @@ fn main() {
         }
     };
 
+    // Read before the window is built so it opens as it was left
+    let settings = GameSettings::load();
+
     App::new()
         .add_plugins(DefaultPlugins.set(WindowPlugin {
             primary_window: Some(Window {
                 title: "The Sovereign Sandbox".into(),
-                resolution: (1280.0, 720.0).into(),
+                resolution: LAYOUT_SIZE.into(),
+                mode: settings.display.window_mode(),
                 present_mode: bevy::window::PresentMode::AutoVsync,
-                resizable: false,
+                resizable: settings.display.resizable,
                 canvas: Some("#bevy-canvas".into()),
                 fit_canvas_to_parent: true,
                 ..default()
@@ -87,22 +144,42 @@ fn main() {
             ..default()
         }).set(ImagePlugin::default_nearest()))
         .insert_resource(MemoryStoreResource(memory_store))
+        .insert_resource(settings)
         .init_state::<GameState>()
+        .add_sub_state::<PauseState>()
+        .add_plugins(SettingsPlugin)
+        .add_plugins(LocalizationPlugin)
         .add_plugins(SyllabusPlugin)
         .add_plugins(AiPlugin)
         .add_plugins(TeacherPlugin)
         .add_plugins(QuestUIPlugin)
         .add_plugins(InventoryPlugin)
+        .add_plugins(MapPlugin)
         .add_plugins(GameWorldPlugin)
+        .add_plugins(LevelPlugin)
+        .add_plugins(NavigationPlugin)
         .add_plugins(StoryModePlugin)
+        .add_plugins(DialoguePlugin)
+        .add_plugins(ActionsPlugin)
+        .add_plugins(TouchPlugin)
+        .add_plugins(AccessibilityPlugin)
+        .add_plugins(NarrationPlugin)
+        .add_plugins(SpeechPlugin)
+        .add_plugins(VoiceCommandPlugin)
+        .add_plugins(TeacherVoicePlugin)
         .add_plugins(QuizPlugin)
         .add_plugins(ScoringPlugin)
         .add_plugins(PuzzlePlugin)
         .add_plugins(CombatPlugin)
+        .add_plugins(MasteryPlugin)
+        .add_plugins(AssessmentPlugin)
+        .add_plugins(ReviewPlugin)
         .add_plugins(KnowledgePopupPlugin)
+        .add_plugins(MinimapPlugin)
         .add_plugins(TitleScreenPlugin)
         .add_plugins(GameAudioPlugin)
         .add_plugins(VictoryScreenPlugin)
+        .add_plugins(PauseMenuPlugin)
         .insert_resource(ClearColor(Color::srgb(0.02, 0.02, 0.04))) // Deep space blue-black
         .insert_resource(BootTimer(Timer::from_seconds(0.08, TimerMode::Repeating)))
         .add_systems(OnEnter(GameState::Boot), setup_boot)
@@ -136,14 +213,14 @@ fn animate_boot_text(
     mut next_state: ResMut<NextState<GameState>>,
     mut commands: Commands,
     boot_entities: Query<Entity, With<BootText>>,
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<actions::Actions>,
 ) {
     // Skip boot cinematic on any keypress
-    if keys.get_just_pressed().next().is_some() {
+    if actions.any_just_pressed() {
         for entity in &boot_entities {
             commands.entity(entity).despawn_recursive();
         }
-        next_state.set(GameState::Playing);
+        next_state.set(GameState::PreTest);
         return;
     }
     timer.0.tick(time.delta());
@@ -175,11 +252,11 @@ fn animate_boot_text(
                 // Flash to cyan for the welcome message
                 *color = TextColor(Color::srgb(0.0, 1.0, 1.0));
             } else {
-                // Transition to Playing state
+                // Transition to the pretest (skipped if the syllabus has none)
                 for entity in &boot_entities {
                     commands.entity(entity).despawn_recursive();
                 }
-                next_state.set(GameState::Playing);
+                next_state.set(GameState::PreTest);
             }
         }
     }
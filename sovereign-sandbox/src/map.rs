@@ -0,0 +1,541 @@
+use bevy::asset::io::Reader;
+use bevy::asset::{AssetLoader, LoadContext};
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::path::{Component as PathComponent, Path, PathBuf};
+
+// ============================================================================
+// Tiled Maps — `.tmj` (Tiled JSON) levels loaded as Bevy assets
+// ============================================================================
+// Conventions the game relies on:
+//   * map properties `origin_x` / `origin_y` place Tiled's top-left tile in
+//     game tile coordinates (game y points up, Tiled y points down)
+//   * tile layers with the bool property `collides` become walls
+//   * object layer objects are typed: `room` (rectangles), `spawn`, `npc`,
+//     `interactable`, `fragment`, `guide`
+// Tilesets must be embedded "collection of images" tilesets.
+//
+// `.tmj` is the only format read. Tile layers may be stored as CSV or as
+// base64, uncompressed or zlib/gzip compressed; zstd and infinite (chunked)
+// maps are rejected. `.tmx` maps can be exported to `.tmj` from Tiled (File ›
+// Export As); LDtk levels aren't supported.
+
+/// Zone used by modules that don't name one
+pub const DEFAULT_MAP: &str = "maps/academy.tmj";
+
+/// A tile position in game coordinates, with the asset path of its image
+#[derive(Debug, Clone)]
+pub struct MapTile {
+    pub pos: IVec2,
+    pub image: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct MapTileLayer {
+    pub z: f32,
+    pub collides: bool,
+    pub tiles: Vec<MapTile>,
+}
+
+/// A named rectangular room, in game tile coordinates (`min` is the
+/// bottom-left tile)
+#[derive(Debug, Clone)]
+pub struct MapRoom {
+    pub name: String,
+    pub min: IVec2,
+    pub size: IVec2,
+    pub color: Color,
+}
+
+impl MapRoom {
+    pub fn contains(&self, tile: Vec2) -> bool {
+        let max = self.min + self.size;
+        tile.x >= self.min.x as f32 - 0.5 && tile.x < max.x as f32 - 0.5
+            && tile.y >= self.min.y as f32 - 0.5 && tile.y < max.y as f32 - 0.5
+    }
+
+    pub fn center(&self) -> Vec2 {
+        self.min.as_vec2() + (self.size.as_vec2() - Vec2::ONE) / 2.0
+    }
+}
+
+/// A typed object from an object layer, positioned in (fractional) game
+/// tile coordinates.
+#[derive(Debug, Clone)]
+pub struct MapObject {
+    pub kind: String,
+    pub name: String,
+    pub tile: Vec2,
+    pub properties: HashMap<String, serde_json::Value>,
+}
+
+impl MapObject {
+    pub fn prop_str(&self, key: &str) -> Option<&str> {
+        self.properties.get(key)?.as_str()
+    }
+
+    pub fn prop_f32(&self, key: &str) -> Option<f32> {
+        self.properties.get(key)?.as_f64().map(|v| v as f32)
+    }
+
+    pub fn prop_bool(&self, key: &str) -> Option<bool> {
+        self.properties.get(key)?.as_bool()
+    }
+
+    pub fn prop_color(&self, key: &str) -> Option<Color> {
+        parse_tiled_color(self.prop_str(key)?)
+    }
+}
+
+#[derive(Asset, TypePath, Debug, Clone)]
+pub struct MapData {
+    pub name: String,
+    pub layers: Vec<MapTileLayer>,
+    pub rooms: Vec<MapRoom>,
+    pub objects: Vec<MapObject>,
+}
+
+impl MapData {
+    pub fn room(&self, name: &str) -> Option<&MapRoom> {
+        self.rooms.iter().find(|r| r.name == name)
+    }
+
+    /// Identifies a room across zones (room names repeat between maps)
+    pub fn room_key(&self, room: &MapRoom) -> String {
+        format!("{}/{}", self.name, room.name)
+    }
+
+    /// The room containing a world-space position
+    pub fn room_at(&self, world: Vec2, tile_size: f32) -> Option<&MapRoom> {
+        let tile = world / tile_size;
+        self.rooms.iter().find(|r| r.contains(tile))
+    }
+
+    pub fn objects_of<'a>(&'a self, kind: &'a str) -> impl Iterator<Item = &'a MapObject> {
+        self.objects.iter().filter(move |o| o.kind == kind)
+    }
+
+    pub fn object(&self, kind: &str, name: &str) -> Option<&MapObject> {
+        self.objects.iter().find(|o| o.kind == kind && o.name == name)
+    }
+
+    /// Player start position in game tile coordinates
+    pub fn spawn_point(&self) -> Vec2 {
+        self.objects_of("spawn").next().map(|o| o.tile).unwrap_or(Vec2::ZERO)
+    }
+
+    /// Parses a Tiled JSON map. `dir` is the map's directory inside the asset
+    /// folder, used to resolve tileset image paths.
+    pub fn from_tiled_json(bytes: &[u8], dir: &Path) -> anyhow::Result<Self> {
+        let map: TiledJson = serde_json::from_slice(bytes)?;
+        let props = properties(&map.properties);
+        let origin = IVec2::new(
+            props.get("origin_x").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+            props.get("origin_y").and_then(|v| v.as_i64()).unwrap_or(0) as i32,
+        );
+        let (tw, th) = (map.tilewidth as f32, map.tileheight as f32);
+
+        // gid → image path
+        let mut images: HashMap<u32, String> = HashMap::new();
+        for tileset in &map.tilesets {
+            for tile in &tileset.tiles {
+                if let Some(ref image) = tile.image {
+                    images.insert(tileset.firstgid + tile.id, resolve_path(dir, image));
+                }
+            }
+        }
+
+        let mut layers = Vec::new();
+        let mut rooms = Vec::new();
+        let mut objects = Vec::new();
+
+        for (index, layer) in map.layers.iter().enumerate() {
+            let layer_props = properties(&layer.properties);
+            match layer.layer_type.as_str() {
+                "tilelayer" => {
+                    let collides = layer_props.get("collides").and_then(|v| v.as_bool()).unwrap_or(false);
+                    let z = layer_props.get("z").and_then(|v| v.as_f64()).map(|z| z as f32)
+                        .unwrap_or(if collides { 0.5 } else { -10.0 + index as f32 * 0.01 });
+                    let width = layer.width.max(1) as i32;
+                    let tiles = layer_gids(layer)?.into_iter().enumerate()
+                        .filter(|(_, gid)| *gid != 0)
+                        .filter_map(|(i, gid)| {
+                            // Strip Tiled's flip flags from the gid
+                            let image = images.get(&(gid & 0x1FFF_FFFF))?.clone();
+                            let (col, row) = (i as i32 % width, i as i32 / width);
+                            Some(MapTile { pos: IVec2::new(origin.x + col, origin.y - row), image })
+                        })
+                        .collect();
+                    layers.push(MapTileLayer { z, collides, tiles });
+                }
+                "objectgroup" => {
+                    for obj in &layer.objects {
+                        let kind = obj.kind.clone().unwrap_or_default();
+                        let obj_props = properties(&obj.properties);
+                        if kind == "room" {
+                            let (col, row) = ((obj.x / tw).round() as i32, (obj.y / th).round() as i32);
+                            let size = IVec2::new((obj.width / tw).round() as i32, (obj.height / th).round() as i32);
+                            let color = obj_props.get("color").and_then(|v| v.as_str())
+                                .and_then(parse_tiled_color)
+                                .unwrap_or(Color::WHITE);
+                            rooms.push(MapRoom {
+                                name: obj.name.clone(),
+                                min: IVec2::new(origin.x + col, origin.y - row - size.y + 1),
+                                size,
+                                color,
+                            });
+                        } else {
+                            // Rectangles anchor at their centre, points at themselves
+                            let px = obj.x + obj.width / 2.0;
+                            let py = obj.y + obj.height / 2.0;
+                            objects.push(MapObject {
+                                kind,
+                                name: obj.name.clone(),
+                                tile: Vec2::new(origin.x as f32 + px / tw - 0.5, origin.y as f32 - (py / th - 0.5)),
+                                properties: obj_props,
+                            });
+                        }
+                    }
+                }
+                other => warn!("⚠️ Unsupported Tiled layer type '{}' in '{}'", other, layer.name),
+            }
+        }
+
+        Ok(Self {
+            name: props.get("name").and_then(|v| v.as_str()).unwrap_or("Untitled").to_string(),
+            layers,
+            rooms,
+            objects,
+        })
+    }
+}
+
+/// Tiled colors are `#RRGGBB` or `#AARRGGBB`
+pub fn parse_tiled_color(s: &str) -> Option<Color> {
+    let hex = s.strip_prefix('#')?;
+    let v = u32::from_str_radix(hex, 16).ok()?;
+    let byte = |shift: u32| ((v >> shift) & 0xFF) as u8;
+    match hex.len() {
+        6 => Some(Color::srgb_u8(byte(16), byte(8), byte(0))),
+        8 => Some(Color::srgba_u8(byte(16), byte(8), byte(0), byte(24))),
+        _ => None,
+    }
+}
+
+/// Joins a tileset-relative image path onto the map directory, folding `..`
+fn resolve_path(dir: &Path, relative: &str) -> String {
+    let mut out = PathBuf::new();
+    for part in dir.join(relative).components() {
+        match part {
+            PathComponent::ParentDir => { out.pop(); }
+            PathComponent::CurDir => {}
+            other => out.push(other),
+        }
+    }
+    out.to_string_lossy().replace('\\', "/")
+}
+
+/// A tile layer's gids, from CSV or from base64 little-endian `u32`s
+fn layer_gids(layer: &TiledLayer) -> anyhow::Result<Vec<u32>> {
+    use base64::Engine;
+    use std::io::Read;
+
+    if layer.chunks.is_some() {
+        anyhow::bail!("layer '{}' is from an infinite map, which isn't supported", layer.name);
+    }
+    let encoded = match (&layer.data, layer.encoding.as_deref()) {
+        (TiledData::Csv(gids), _) => return Ok(gids.clone()),
+        (TiledData::Encoded(text), Some("base64")) => text,
+        (TiledData::Encoded(_), encoding) => {
+            anyhow::bail!("layer '{}' has unknown encoding {:?}", layer.name, encoding)
+        }
+    };
+    let bytes = base64::engine::general_purpose::STANDARD.decode(encoded.trim())?;
+    let bytes = match layer.compression.as_deref().unwrap_or("") {
+        "" => bytes,
+        "zlib" => {
+            let mut out = Vec::new();
+            flate2::read::ZlibDecoder::new(bytes.as_slice()).read_to_end(&mut out)?;
+            out
+        }
+        "gzip" => {
+            let mut out = Vec::new();
+            flate2::read::GzDecoder::new(bytes.as_slice()).read_to_end(&mut out)?;
+            out
+        }
+        other => anyhow::bail!(
+            "layer '{}' uses {} compression; save it as CSV, or base64 uncompressed, zlib or gzip",
+            layer.name, other
+        ),
+    };
+    if bytes.len() % 4 != 0 {
+        anyhow::bail!("layer '{}' data is {} bytes, not whole tiles", layer.name, bytes.len());
+    }
+    Ok(bytes.chunks_exact(4).map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]])).collect())
+}
+
+fn properties(props: &[TiledProperty]) -> HashMap<String, serde_json::Value> {
+    props.iter().map(|p| (p.name.clone(), p.value.clone())).collect()
+}
+
+// ============================================================================
+// Tiled JSON schema (the subset we read)
+// ============================================================================
+
+#[derive(Deserialize)]
+struct TiledJson {
+    tilewidth: u32,
+    tileheight: u32,
+    #[serde(default)]
+    properties: Vec<TiledProperty>,
+    #[serde(default)]
+    tilesets: Vec<TiledTileset>,
+    layers: Vec<TiledLayer>,
+}
+
+#[derive(Deserialize)]
+struct TiledProperty {
+    name: String,
+    value: serde_json::Value,
+}
+
+#[derive(Deserialize)]
+struct TiledTileset {
+    firstgid: u32,
+    #[serde(default)]
+    tiles: Vec<TiledTile>,
+}
+
+#[derive(Deserialize)]
+struct TiledTile {
+    id: u32,
+    image: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct TiledLayer {
+    #[serde(rename = "type")]
+    layer_type: String,
+    name: String,
+    #[serde(default)]
+    width: u32,
+    #[serde(default)]
+    data: TiledData,
+    /// `base64` when `data` is a string
+    encoding: Option<String>,
+    /// `zlib`, `gzip` or `zstd` for base64 data
+    compression: Option<String>,
+    /// Present on infinite maps instead of `data`
+    chunks: Option<serde_json::Value>,
+    #[serde(default)]
+    objects: Vec<TiledObject>,
+    #[serde(default)]
+    properties: Vec<TiledProperty>,
+}
+
+/// Tile layer data: gids as an array (CSV in the editor) or an encoded string
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum TiledData {
+    Csv(Vec<u32>),
+    Encoded(String),
+}
+
+impl Default for TiledData {
+    fn default() -> Self {
+        TiledData::Csv(Vec::new())
+    }
+}
+
+#[derive(Deserialize)]
+struct TiledObject {
+    #[serde(default)]
+    name: String,
+    /// `type` in Tiled ≤ 1.8 and in JSON exports, `class` in newer editors
+    #[serde(rename = "type", alias = "class")]
+    kind: Option<String>,
+    x: f32,
+    y: f32,
+    #[serde(default)]
+    width: f32,
+    #[serde(default)]
+    height: f32,
+    #[serde(default)]
+    properties: Vec<TiledProperty>,
+}
+
+// ============================================================================
+// Asset Loader & Plugin
+// ============================================================================
+
+#[derive(Default)]
+pub struct TiledMapLoader;
+
+impl AssetLoader for TiledMapLoader {
+    type Asset = MapData;
+    type Settings = ();
+    type Error = anyhow::Error;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<MapData, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let dir = load_context.path().parent().unwrap_or(Path::new(""));
+        let map = MapData::from_tiled_json(&bytes, dir)?;
+        info!("🗺️ Loaded map '{}': {} rooms, {} objects", map.name, map.rooms.len(), map.objects.len());
+        Ok(map)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tmj"]
+    }
+}
+
+pub struct MapPlugin;
+
+impl Plugin for MapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<MapData>()
+           .init_asset_loader::<TiledMapLoader>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+    use std::io::Write;
+
+    fn load(file: &str) -> MapData {
+        let bytes = std::fs::read(Path::new("assets/maps").join(file)).expect("map file");
+        MapData::from_tiled_json(&bytes, Path::new("maps")).unwrap_or_else(|e| panic!("{}: {}", file, e))
+    }
+
+    fn tiles(map: &MapData, collides: bool) -> HashSet<IVec2> {
+        map.layers.iter().filter(|l| l.collides == collides).flat_map(|l| l.tiles.iter().map(|t| t.pos)).collect()
+    }
+
+    fn position(map: &MapData, kind: &str, name: &str) -> Vec2 {
+        map.object(kind, name).unwrap_or_else(|| panic!("no {} '{}' in {}", kind, name, map.name)).tile
+    }
+
+    #[test]
+    fn academy_keeps_the_old_layout() {
+        let map = load("academy.tmj");
+        assert_eq!(map.name, "The Academy");
+
+        // The rooms `game_world` used to hard-code, as (min, size)
+        let rooms = [
+            ("Academy Hall", [-6, -5], [12, 10]),
+            ("Terminal Lab", [-18, -4], [8, 8]),
+            ("Archive Vault", [-4, -17], [8, 8]),
+            ("Server Core", [10, -4], [8, 8]),
+        ];
+        assert_eq!(map.rooms.len(), rooms.len());
+        let (floor, walls) = (tiles(&map, false), tiles(&map, true));
+        for (name, min, size) in rooms {
+            let room = map.room(name).unwrap_or_else(|| panic!("no room {}", name));
+            assert_eq!((room.min, room.size), (IVec2::from(min), IVec2::from(size)), "{}", name);
+            for x in min[0]..min[0] + size[0] {
+                for y in min[1]..min[1] + size[1] {
+                    let tile = IVec2::new(x, y);
+                    assert!(floor.contains(&tile) && !walls.contains(&tile), "{} at {}", name, tile);
+                }
+            }
+            assert!(map.room_at(room.center() * 64.0, 64.0).is_some_and(|r| r.name == name));
+        }
+
+        // And its door tiles, which are open corridor
+        let corridors = (-10..=-7).flat_map(|x| [[x, -1], [x, 0]])
+            .chain((-9..=-6).flat_map(|y| [[-1, y], [0, y], [1, y]]))
+            .chain((6..=9).flat_map(|x| [[x, -1], [x, 0]]));
+        for tile in corridors.map(IVec2::from) {
+            assert!(floor.contains(&tile) && !walls.contains(&tile), "corridor at {}", tile);
+        }
+
+        // Spawns and triggers where they were
+        assert_eq!(map.spawn_point(), Vec2::ZERO);
+        assert_eq!(map.objects_of("spawn").count(), 3);
+        assert_eq!(position(&map, "npc", "Teacher"), Vec2::new(2.0, 2.0));
+        assert_eq!(position(&map, "interactable", "Terminal"), Vec2::new(-14.0, 0.0));
+        assert_eq!(map.objects_of("npc").count(), 1);
+        assert_eq!(map.objects_of("interactable").count(), 1);
+    }
+
+    #[test]
+    fn zones_hold_the_other_triggers_and_fragments() {
+        let maps = [load("academy.tmj"), load("archive.tmj"), load("server.tmj")];
+        assert!(maps[1].object("interactable", "Archive").is_some());
+        assert!(maps[2].object("interactable", "Server").is_some());
+
+        // The eight fragments the academy used to scatter, each on open floor
+        let mut fragments = Vec::new();
+        for map in &maps {
+            assert!(map.object("npc", "Teacher").is_some(), "{}", map.name);
+            assert!(map.objects_of("spawn").next().is_some(), "{}", map.name);
+            let (floor, walls) = (tiles(map, false), tiles(map, true));
+            for fragment in map.objects_of("fragment") {
+                let tile = fragment.tile.round().as_ivec2();
+                assert!(floor.contains(&tile) && !walls.contains(&tile), "{} in {}", fragment.name, map.name);
+                fragments.push(fragment.name.as_str());
+            }
+        }
+        fragments.sort_unstable();
+        assert_eq!(fragments, [
+            "Constructivism", "Data Sovereignty", "Edge Computing", "Flow State",
+            "Gagné's First Event", "Model Quantization", "Open Weights", "Socratic Method",
+        ]);
+        // The one still where it was
+        assert_eq!(position(&maps[0], "fragment", "Data Sovereignty"), Vec2::new(-8.0, -1.0));
+    }
+
+    /// A 2×2 map whose one tile layer holds `data`
+    fn tiny_map(data: &str) -> Vec<u8> {
+        format!(
+            r#"{{"tilewidth": 64, "tileheight": 64,
+                "tilesets": [{{"firstgid": 1, "tiles": [{{"id": 0, "image": "../floor.jpg"}}]}}],
+                "layers": [{{"type": "tilelayer", "name": "floor", "width": 2, {}}}]}}"#,
+            data
+        ).into_bytes()
+    }
+
+    fn positions(bytes: &[u8]) -> anyhow::Result<Vec<(IVec2, String)>> {
+        let map = MapData::from_tiled_json(bytes, Path::new("maps"))?;
+        Ok(map.layers[0].tiles.iter().map(|t| (t.pos, t.image.clone())).collect())
+    }
+
+    #[test]
+    fn reads_csv_and_base64_layers() {
+        use base64::Engine;
+        let gids: [u32; 4] = [1, 0, 0, 1 | 0x8000_0000];
+        let bytes: Vec<u8> = gids.iter().flat_map(|g| g.to_le_bytes()).collect();
+        let encode = |bytes: &[u8]| base64::engine::general_purpose::STANDARD.encode(bytes);
+
+        let csv = positions(&tiny_map(r#""data": [1, 0, 0, 2147483649]"#)).unwrap();
+        assert_eq!(csv, vec![(IVec2::new(0, 0), "floor.jpg".to_string()), (IVec2::new(1, -1), "floor.jpg".to_string())]);
+
+        let plain = format!(r#""encoding": "base64", "data": "{}""#, encode(&bytes));
+        assert_eq!(positions(&tiny_map(&plain)).unwrap(), csv);
+
+        let mut zlib = flate2::write::ZlibEncoder::new(Vec::new(), flate2::Compression::default());
+        zlib.write_all(&bytes).unwrap();
+        let zlib = format!(r#""encoding": "base64", "compression": "zlib", "data": "{}""#, encode(&zlib.finish().unwrap()));
+        assert_eq!(positions(&tiny_map(&zlib)).unwrap(), csv);
+
+        let mut gzip = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gzip.write_all(&bytes).unwrap();
+        let gzip = format!(r#""encoding": "base64", "compression": "gzip", "data": "{}""#, encode(&gzip.finish().unwrap()));
+        assert_eq!(positions(&tiny_map(&gzip)).unwrap(), csv);
+
+        let zstd = format!(r#""encoding": "base64", "compression": "zstd", "data": "{}""#, encode(&bytes));
+        assert!(positions(&tiny_map(&zstd)).unwrap_err().to_string().contains("zstd"));
+        let ragged = format!(r#""encoding": "base64", "data": "{}""#, encode(&bytes[..6]));
+        assert!(positions(&tiny_map(&ragged)).is_err());
+        assert!(positions(&tiny_map(r#""chunks": []"#)).unwrap_err().to_string().contains("infinite"));
+    }
+}
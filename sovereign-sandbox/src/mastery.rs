@@ -0,0 +1,188 @@
+use bevy::prelude::*;
+use serde::Serialize;
+use std::collections::HashMap;
+use crate::assessment::TestResults;
+use crate::syllabus::SyllabusResource;
+
+// ============================================================================
+// Objective Mastery — evidence from quizzes and puzzles, per objective
+// ============================================================================
+
+/// Emitted whenever the learner produces assessable evidence (a quiz answer,
+/// a puzzle attempt) for one or more objectives.
+#[derive(Event, Clone)]
+pub struct AssessmentEvent {
+    pub objectives: Vec<String>,
+    pub correct: bool,
+    pub source: String,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ObjectiveMastery {
+    pub attempts: u32,
+    pub correct: u32,
+}
+
+impl ObjectiveMastery {
+    /// Share of attempts answered correctly (0.0 with no evidence)
+    pub fn mastery(&self) -> f32 {
+        if self.attempts == 0 { return 0.0; }
+        self.correct as f32 / self.attempts as f32
+    }
+}
+
+#[derive(Resource, Default)]
+pub struct MasteryTracker {
+    pub objectives: HashMap<String, ObjectiveMastery>,
+}
+
+impl MasteryTracker {
+    /// Accuracy at or above which an objective counts as mastered
+    pub const MASTERY_THRESHOLD: f32 = 0.8;
+
+    /// Forgets every attempt, for a fresh run
+    pub fn clear(&mut self) {
+        self.objectives.clear();
+    }
+
+    pub fn record(&mut self, objective: &str, correct: bool) {
+        let entry = self.objectives.entry(objective.to_string()).or_default();
+        entry.attempts += 1;
+        if correct {
+            entry.correct += 1;
+        }
+    }
+
+    pub fn get(&self, objective: &str) -> Option<&ObjectiveMastery> {
+        self.objectives.get(objective)
+    }
+
+    pub fn is_mastered(&self, objective: &str) -> bool {
+        self.get(objective).is_some_and(|m| m.attempts > 0 && m.mastery() >= Self::MASTERY_THRESHOLD)
+    }
+}
+
+// ============================================================================
+// Report (exported at victory)
+// ============================================================================
+
+#[derive(Serialize)]
+pub struct ObjectiveReport {
+    pub id: String,
+    pub text: String,
+    pub bloom: Option<&'static str>,
+    pub attempts: u32,
+    pub correct: u32,
+    pub mastery: f32,
+    pub mastered: bool,
+    /// Normalized pre→post gain for this objective, if both tests assessed it
+    pub learning_gain: Option<f32>,
+}
+
+#[derive(Serialize)]
+pub struct LearningGainReport {
+    pub pretest_score: Option<f32>,
+    pub posttest_score: Option<f32>,
+    pub normalized_gain: Option<f32>,
+    pub pretest: Vec<crate::assessment::ItemResult>,
+    pub posttest: Vec<crate::assessment::ItemResult>,
+}
+
+#[derive(Serialize)]
+pub struct MasteryReport {
+    pub syllabus: String,
+    pub framework: String,
+    pub generated_at: chrono::DateTime<chrono::Utc>,
+    pub objectives: Vec<ObjectiveReport>,
+    pub learning_gain: LearningGainReport,
+}
+
+impl MasteryReport {
+    pub fn build(syllabus: &SyllabusResource, tracker: &MasteryTracker, results: &TestResults) -> Self {
+        let objectives = syllabus.syllabus.objectives.iter().map(|o| {
+            let stats = tracker.get(&o.id).cloned().unwrap_or_default();
+            ObjectiveReport {
+                id: o.id.clone(),
+                text: o.text.clone(),
+                bloom: o.bloom.map(|b| b.name()),
+                attempts: stats.attempts,
+                correct: stats.correct,
+                mastery: stats.mastery(),
+                mastered: tracker.is_mastered(&o.id),
+                learning_gain: results.objective_gain(&o.id),
+            }
+        }).collect();
+
+        Self {
+            syllabus: syllabus.syllabus.title.clone(),
+            framework: syllabus.framework.name.clone(),
+            generated_at: chrono::Utc::now(),
+            objectives,
+            learning_gain: LearningGainReport {
+                pretest_score: results.pretest_score(),
+                posttest_score: results.posttest_score(),
+                normalized_gain: results.overall_gain(),
+                pretest: results.pretest.clone(),
+                posttest: results.posttest.clone(),
+            },
+        }
+    }
+}
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub struct MasteryPlugin;
+
+impl Plugin for MasteryPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MasteryTracker>()
+           .add_event::<AssessmentEvent>()
+           .add_systems(Update, record_assessments)
+           .add_systems(OnEnter(crate::GameState::Victory), export_mastery_report);
+    }
+}
+
+fn record_assessments(
+    mut events: EventReader<AssessmentEvent>,
+    mut tracker: ResMut<MasteryTracker>,
+) {
+    for event in events.read() {
+        for objective in &event.objectives {
+            tracker.record(objective, event.correct);
+            info!("🎯 {} evidence for '{}': {}", event.source, objective,
+                if event.correct { "correct" } else { "incorrect" });
+        }
+    }
+}
+
+fn export_mastery_report(
+    syllabus: Option<Res<SyllabusResource>>,
+    tracker: Res<MasteryTracker>,
+    results: Res<TestResults>,
+) {
+    let Some(syl) = syllabus else { return };
+    let report = MasteryReport::build(&syl, &tracker, &results);
+
+    let json = match serde_json::to_string_pretty(&report) {
+        Ok(json) => json,
+        Err(e) => {
+            error!("Failed to serialize mastery report: {}", e);
+            return;
+        }
+    };
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let dir = std::path::Path::new("assets/memory/reports");
+        let path = dir.join(format!("mastery_{}.json", report.generated_at.format("%Y%m%d_%H%M%S")));
+        match std::fs::create_dir_all(dir).and_then(|_| std::fs::write(&path, &json)) {
+            Ok(()) => info!("📊 Mastery report exported to {}", path.display()),
+            Err(e) => error!("Failed to write mastery report: {}", e),
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    info!("📊 Mastery report:\n{}", json);
+}
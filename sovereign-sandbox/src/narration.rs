@@ -0,0 +1,400 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use crate::accessibility::AccessibilitySettings;
+use crate::actions::{Action, Actions, OfferedChoices};
+use crate::i18n::Locale;
+pub use crate::narration_sink::{speakable, MockSink, NarrationSink, Priority};
+use crate::narration_sink::deliver;
+use crate::settings::GameSettings;
+use crate::story_mode::TypewriterState;
+use crate::teacher_voice::{voices_lines, VoiceLineEvent};
+
+// ============================================================================
+// Narration — game text for screen readers and text-to-speech
+// ============================================================================
+// Everything worth hearing goes through `NarrationEvent`: dialogue lines as
+// the typewriter starts them, popups, level-ups, the focused option, and any
+// text carrying `Narrated` whenever it changes (quest updates, notifications,
+// test and review cards, menus). One `NarrationSink` delivers them:
+//   * web    — ARIA live regions in the host page, read by the screen reader
+//   * native — the system's offline speech engine, when `narration` is on
+//   * `SANDBOX_NARRATION=mock` — `MockSink`, which records and logs lines
+// Sinks, the speech queue and text clean-up are in `narration_sink`.
+
+#[derive(Event, Debug, Clone)]
+pub struct NarrationEvent {
+    pub text: String,
+    pub priority: Priority,
+}
+
+impl NarrationEvent {
+    pub fn polite(text: impl Into<String>) -> Self {
+        Self { text: text.into(), priority: Priority::Polite }
+    }
+
+    pub fn assertive(text: impl Into<String>) -> Self {
+        Self { text: text.into(), priority: Priority::Assertive }
+    }
+}
+
+/// Text read out whenever it changes
+#[derive(Component)]
+pub struct Narrated(pub Priority);
+
+impl Default for Narrated {
+    fn default() -> Self {
+        Self(Priority::Polite)
+    }
+}
+
+/// The active sink, if narration is going anywhere
+#[derive(Resource, Default)]
+pub struct Narrator(pub Option<Box<dyn NarrationSink>>);
+
+/// `MockSink` with every line logged, to check coverage while playing
+#[derive(Default)]
+struct LoggedMock(MockSink);
+
+impl NarrationSink for LoggedMock {
+    fn speak(&mut self, text: &str, priority: Priority) {
+        info!("🔊 [{:?}] {}", priority, text);
+        self.0.speak(text, priority);
+    }
+}
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub struct NarrationPlugin;
+
+impl Plugin for NarrationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<Narrator>()
+           .add_event::<NarrationEvent>()
+           .add_systems(Startup, spawn_focus_hint)
+           .add_systems(Update, (
+               select_sink,
+               narrate_typewriter,
+               narrate_popups,
+               narrate_level_ups,
+               narrate_changed_text,
+               narrate_focus,
+               deliver_narration,
+           ).chain());
+    }
+}
+
+/// Picks the sink at startup and again when the setting changes
+fn select_sink(settings: Res<AccessibilitySettings>, mut narrator: ResMut<Narrator>) {
+    if !settings.is_changed() { return; }
+
+    if std::env::var("SANDBOX_NARRATION").is_ok_and(|v| v == "mock") {
+        if narrator.0.is_none() {
+            info!("🔊 Narration: mock sink");
+            narrator.0 = Some(Box::new(LoggedMock::default()));
+        }
+        return;
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    if narrator.0.is_none() {
+        info!("🔊 Narration: ARIA live regions");
+        narrator.0 = Some(Box::new(live_region::LiveRegionSink::default()));
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    match (settings.narration, narrator.0.is_some()) {
+        (true, false) => {
+            info!("🔊 Narration: system speech");
+            narrator.0 = Some(Box::new(speech::SpeechSink::default()));
+        }
+        (false, true) => narrator.0 = None,
+        _ => {}
+    }
+}
+
+fn deliver_narration(mut events: EventReader<NarrationEvent>, mut narrator: ResMut<Narrator>) {
+    let Some(ref mut sink) = narrator.0 else {
+        events.clear();
+        return;
+    };
+    for event in events.read() {
+        deliver(sink.as_mut(), &event.text, event.priority);
+    }
+    sink.update();
+}
+
+// ============================================================================
+// Sources
+// ============================================================================
+
+/// Dialogue box text, once per line rather than per typed character. Options
+/// added under a line that was already read are read on their own, and lines
+/// the teacher speaks aloud are left to its voice.
+fn narrate_typewriter(
+    typewriter: Res<TypewriterState>,
+    mut voice_lines: EventReader<VoiceLineEvent>,
+    (accessibility, settings): (Res<AccessibilitySettings>, Res<GameSettings>),
+    mut last: Local<String>,
+    mut writer: EventWriter<NarrationEvent>,
+) {
+    let voiced = voice_lines.read().any(|line| line.0 == typewriter.full_text);
+    if !typewriter.is_changed() || typewriter.full_text == *last { return; }
+    if voiced && voices_lines(&accessibility, &settings) {
+        *last = typewriter.full_text.clone();
+        return;
+    }
+
+    let new = match typewriter.full_text.strip_prefix(last.as_str()) {
+        Some(rest) if !last.is_empty() => rest,
+        _ => typewriter.full_text.as_str(),
+    };
+    writer.send(NarrationEvent::polite(new));
+    *last = typewriter.full_text.clone();
+}
+
+fn narrate_popups(
+    mut events: EventReader<crate::ui::knowledge_popup::KnowledgeCollectedEvent>,
+    locale: Res<Locale>,
+    mut writer: EventWriter<NarrationEvent>,
+) {
+    for event in events.read() {
+        writer.send(NarrationEvent::assertive(locale.fmt("narrate-fragment", &[
+            ("title", &event.title),
+            ("content", &crate::ui::rich_text::plain(&event.content)),
+            ("xp", &event.xp),
+        ])));
+    }
+}
+
+fn narrate_level_ups(
+    mut events: EventReader<crate::scoring::LevelUpEvent>,
+    score: Res<crate::scoring::PlayerScore>,
+    locale: Res<Locale>,
+    mut writer: EventWriter<NarrationEvent>,
+) {
+    if events.read().count() > 0 {
+        writer.send(NarrationEvent::polite(locale.fmt("narrate-level-up", &[("level", &score.level), ("title", &score.title(&locale))])));
+    }
+}
+
+fn narrate_changed_text(
+    texts: Query<(Entity, &Text, &Narrated), Changed<Text>>,
+    mut removed: RemovedComponents<Narrated>,
+    mut last: Local<HashMap<Entity, String>>,
+    mut writer: EventWriter<NarrationEvent>,
+) {
+    for entity in removed.read() {
+        last.remove(&entity);
+    }
+    // Spawn order, so a panel reads top to bottom
+    let mut changed: Vec<_> = texts.iter().collect();
+    changed.sort_by_key(|(entity, ..)| *entity);
+    for (entity, text, narrated) in changed {
+        if text.0.trim().is_empty() || last.get(&entity) == Some(&text.0) { continue; }
+        last.insert(entity, text.0.clone());
+        writer.send(NarrationEvent { text: text.0.clone(), priority: narrated.0 });
+    }
+}
+
+// ============================================================================
+// Focus — the arrow keys' current option, announced and shown
+// ============================================================================
+
+#[derive(Component)]
+struct FocusHint;
+
+fn spawn_focus_hint(mut commands: Commands) {
+    commands.spawn((
+        Text::new(""),
+        TextFont { font_size: 16.0, ..default() },
+        TextColor(Color::srgb(1.0, 0.9, 0.4)),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(12.0),
+            left: Val::Percent(30.0),
+            right: Val::Percent(30.0),
+            padding: UiRect::axes(Val::Px(16.0), Val::Px(8.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.05, 0.02, 0.1, 0.92)),
+        BorderColor(Color::srgb(1.0, 0.9, 0.4)),
+        GlobalZIndex(260),
+        Visibility::Hidden,
+        FocusHint,
+    ));
+}
+
+fn narrate_focus(
+    actions: Res<Actions>,
+    offered: Res<OfferedChoices>,
+    locale: Res<Locale>,
+    mut last: Local<Option<usize>>,
+    mut hint: Query<(&mut Text, &mut Visibility), With<FocusHint>>,
+    mut writer: EventWriter<NarrationEvent>,
+) {
+    let focus = actions.focused().filter(|i| *i < offered.0.len());
+    if focus == *last { return; }
+    *last = focus;
+
+    let Ok((mut text, mut visibility)) = hint.get_single_mut() else { return };
+    let Some(i) = focus else {
+        *visibility = Visibility::Hidden;
+        return;
+    };
+
+    let option = &offered.0[i];
+    let (n, count) = (i + 1, offered.0.len());
+    writer.send(NarrationEvent::assertive(locale.fmt("narrate-option", &[("n", &n), ("count", &count), ("option", option)])));
+    text.0 = locale.fmt("focus-hint", &[("n", &n), ("count", &count), ("option", option), ("key", &actions.label(Action::Confirm))]);
+    *visibility = Visibility::Inherited;
+}
+
+// ============================================================================
+// Sinks
+// ============================================================================
+
+/// Two visually hidden `aria-live` regions in the host page
+#[cfg(target_arch = "wasm32")]
+mod live_region {
+    use super::{NarrationSink, Priority};
+
+    const HIDDEN_STYLE: &str = "position:absolute;width:1px;height:1px;margin:-1px;padding:0;\
+        overflow:hidden;clip:rect(0 0 0 0);white-space:nowrap;border:0;";
+
+    /// Lines from one frame are joined so the region isn't overwritten
+    /// before the screen reader sees it
+    #[derive(Default)]
+    pub struct LiveRegionSink {
+        polite: Vec<String>,
+        assertive: Vec<String>,
+    }
+
+    fn region(priority: Priority) -> Option<web_sys::Element> {
+        let (id, live, role) = match priority {
+            Priority::Polite => ("sandbox-narration-polite", "polite", "status"),
+            Priority::Assertive => ("sandbox-narration-assertive", "assertive", "alert"),
+        };
+        let document = web_sys::window()?.document()?;
+        if let Some(existing) = document.get_element_by_id(id) {
+            return Some(existing);
+        }
+        let region = document.create_element("div").ok()?;
+        region.set_id(id);
+        let _ = region.set_attribute("aria-live", live);
+        let _ = region.set_attribute("aria-atomic", "true");
+        let _ = region.set_attribute("role", role);
+        let _ = region.set_attribute("style", HIDDEN_STYLE);
+        document.body()?.append_child(&region).ok()?;
+        Some(region)
+    }
+
+    impl NarrationSink for LiveRegionSink {
+        fn speak(&mut self, text: &str, priority: Priority) {
+            match priority {
+                Priority::Polite => self.polite.push(text.to_string()),
+                Priority::Assertive => self.assertive.push(text.to_string()),
+            }
+        }
+
+        fn update(&mut self) {
+            for (priority, lines) in [(Priority::Polite, &mut self.polite), (Priority::Assertive, &mut self.assertive)] {
+                if lines.is_empty() { continue; }
+                let text = lines.drain(..).collect::<Vec<_>>().join(" ");
+                let Some(region) = region(priority) else { continue };
+                // Clearing first makes a repeated line count as a change
+                region.set_text_content(None);
+                region.set_text_content(Some(&text));
+            }
+        }
+    }
+}
+
+/// The platform's offline speech command: `say`, Windows' System.Speech, or
+/// espeak-ng. Lines are spoken one at a time.
+#[cfg(not(target_arch = "wasm32"))]
+mod speech {
+    use super::{NarrationSink, Priority};
+    use crate::narration_sink::SpeechQueue;
+    use bevy::prelude::*;
+    use std::process::{Child, Command, Stdio};
+
+    #[derive(Default)]
+    pub struct SpeechSink {
+        queue: SpeechQueue,
+        speaking: Option<Child>,
+        unavailable: bool,
+    }
+
+    #[cfg(target_os = "macos")]
+    fn command(text: &str) -> Command {
+        let mut command = Command::new("say");
+        // After `--`, a line starting with "-" isn't read as a flag
+        command.args(["--", text]);
+        command
+    }
+
+    #[cfg(target_os = "windows")]
+    fn command(text: &str) -> Command {
+        let mut command = Command::new("powershell");
+        command
+            .args(["-NoProfile", "-Command",
+                   "Add-Type -AssemblyName System.Speech; \
+                    (New-Object System.Speech.Synthesis.SpeechSynthesizer).Speak($env:SANDBOX_SAY)"])
+            .env("SANDBOX_SAY", text);
+        command
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows")))]
+    fn command(text: &str) -> Command {
+        let mut command = Command::new("espeak-ng");
+        command.args(["--", text]);
+        command
+    }
+
+    impl SpeechSink {
+        fn stop(&mut self) {
+            if let Some(mut child) = self.speaking.take() {
+                let _ = child.kill();
+                let _ = child.wait();
+            }
+        }
+    }
+
+    impl NarrationSink for SpeechSink {
+        fn speak(&mut self, text: &str, priority: Priority) {
+            if self.unavailable { return; }
+            if self.queue.push(text, priority) {
+                self.stop();
+            }
+        }
+
+        fn update(&mut self) {
+            if let Some(ref mut child) = self.speaking {
+                if !matches!(child.try_wait(), Ok(None)) {
+                    self.speaking = None;
+                }
+            }
+            if self.speaking.is_some() { return; }
+            let Some(text) = self.queue.pop() else { return };
+
+            match command(&text).stdout(Stdio::null()).stderr(Stdio::null()).spawn() {
+                Ok(child) => self.speaking = Some(child),
+                Err(e) => {
+                    warn!("⚠️ No speech engine ({}); narration is off", e);
+                    self.unavailable = true;
+                    self.queue.clear();
+                }
+            }
+        }
+    }
+
+    impl Drop for SpeechSink {
+        fn drop(&mut self) {
+            self.stop();
+        }
+    }
+}
@@ -0,0 +1,163 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+// ============================================================================
+// Narration Sinks — where narration ends up, and in what order
+// ============================================================================
+// The half of `narration` that doesn't need the ECS: lines are cleaned up for
+// speech engines, handed to a `NarrationSink`, and queued by priority for
+// sinks that speak one line at a time. `MockSink` records what it's given,
+// so delivery can be tested on its own.
+
+/// Lines waiting behind the one being spoken; older polite lines are dropped
+#[cfg(not(target_arch = "wasm32"))]
+pub const MAX_QUEUED: usize = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Priority {
+    /// Waits for the current line
+    Polite,
+    /// Interrupts whatever is being read
+    Assertive,
+}
+
+/// Where narration ends up
+pub trait NarrationSink: Send + Sync {
+    fn speak(&mut self, text: &str, priority: Priority);
+
+    /// Called every frame, for sinks that pace their own output
+    fn update(&mut self) {}
+}
+
+/// Records what would have been said, for tests and for checking coverage
+#[derive(Clone, Default)]
+pub struct MockSink {
+    pub spoken: Arc<Mutex<Vec<(Priority, String)>>>,
+}
+
+impl NarrationSink for MockSink {
+    fn speak(&mut self, text: &str, priority: Priority) {
+        if let Ok(mut spoken) = self.spoken.lock() {
+            spoken.push((priority, text.to_string()));
+        }
+    }
+}
+
+/// Drops emoji, box drawing and cursor glyphs that speech engines read badly
+pub fn speakable(text: &str) -> String {
+    let kept: String = text.chars()
+        .map(|c| if c.is_alphanumeric() || c.is_whitespace() || ".,;:!?'\"-()/%+&$#*".contains(c) { c } else { ' ' })
+        .collect();
+    kept.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Gives `sink` the speakable part of `text`, if there is any
+pub fn deliver(sink: &mut dyn NarrationSink, text: &str, priority: Priority) {
+    let text = speakable(text);
+    if !text.is_empty() {
+        sink.speak(&text, priority);
+    }
+}
+
+/// Lines for a sink that says one at a time (the system speech engine)
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Debug, Default)]
+pub struct SpeechQueue {
+    lines: VecDeque<String>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl SpeechQueue {
+    /// Queues `text`. An assertive line replaces everything waiting; true
+    /// when it should also cut off the line being spoken.
+    pub fn push(&mut self, text: &str, priority: Priority) -> bool {
+        let interrupts = priority == Priority::Assertive;
+        if interrupts {
+            self.lines.clear();
+        }
+        if self.lines.len() >= MAX_QUEUED {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(text.to_string());
+        interrupts
+    }
+
+    /// The next line to say
+    pub fn pop(&mut self) -> Option<String> {
+        self.lines.pop_front()
+    }
+
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // Narration delivery without a speech engine: text is cleaned up for speech
+    // before it reaches the sink, nothing empty is said, and the speech queue
+    // lets assertive lines cut in while polite ones wait their turn.
+    use super::*;
+
+    #[test]
+    fn speakable_drops_glyphs_and_keeps_words() {
+        assert_eq!(speakable("🧙 The Archivist: Welcome back!"), "The Archivist: Welcome back!");
+        assert_eq!(speakable("╔══╗ Quest ▌\n\n  log"), "Quest log");
+        assert_eq!(speakable("- Loops repeat, 100% of the time."), "- Loops repeat, 100% of the time.");
+        assert_eq!(speakable("¿Cuántos bucles hay?"), "Cuántos bucles hay?");
+        assert_eq!(speakable("✨ ▌ ═"), "");
+    }
+
+    #[test]
+    fn delivers_speakable_lines_to_the_sink() {
+        let mut sink = MockSink::default();
+        let spoken = sink.spoken.clone();
+        deliver(&mut sink, "📜 Fragment found: Loops", Priority::Assertive);
+        deliver(&mut sink, "✨✨", Priority::Polite);
+        deliver(&mut sink, "  Level 2 —  Apprentice ", Priority::Polite);
+
+        assert_eq!(*spoken.lock().unwrap(), vec![
+            (Priority::Assertive, "Fragment found: Loops".to_string()),
+            (Priority::Polite, "Level 2 Apprentice".to_string()),
+        ]);
+    }
+
+    #[test]
+    fn polite_lines_wait_in_order() {
+        let mut queue = SpeechQueue::default();
+        assert!(!queue.push("one", Priority::Polite));
+        assert!(!queue.push("two", Priority::Polite));
+        assert_eq!(queue.pop().as_deref(), Some("one"));
+        assert_eq!(queue.pop().as_deref(), Some("two"));
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn assertive_lines_cut_in() {
+        let mut queue = SpeechQueue::default();
+        queue.push("quest updated", Priority::Polite);
+        queue.push("level up", Priority::Polite);
+        assert!(queue.push("option 1 of 3", Priority::Assertive), "should stop the current line");
+        queue.push("after", Priority::Polite);
+        assert_eq!(queue.pop().as_deref(), Some("option 1 of 3"));
+        assert_eq!(queue.pop().as_deref(), Some("after"));
+        assert_eq!(queue.pop(), None);
+    }
+
+    #[test]
+    fn a_full_queue_drops_the_oldest() {
+        let mut queue = SpeechQueue::default();
+        for i in 0..MAX_QUEUED + 2 {
+            queue.push(&format!("line {}", i), Priority::Polite);
+        }
+        let left: Vec<String> = std::iter::from_fn(|| queue.pop()).collect();
+        assert_eq!(left.len(), MAX_QUEUED);
+        assert_eq!(left[0], "line 2");
+        assert_eq!(left.last().map(String::as_str), Some(format!("line {}", MAX_QUEUED + 1).as_str()));
+
+        queue.push("again", Priority::Polite);
+        queue.clear();
+        assert_eq!(queue.pop(), None);
+    }
+}
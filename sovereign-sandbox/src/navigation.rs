@@ -0,0 +1,247 @@
+use bevy::prelude::*;
+use crate::GameState;
+use crate::actions::{Action, Actions};
+use crate::game_world::{Collider, GameCamera, Player, QuestTrigger, Terminal, WallGrid};
+use crate::inventory::{Inventory, ToolId};
+use crate::spatial::TileGrid;
+use crate::syllabus::{QuestPhase, SyllabusResource};
+
+// ============================================================================
+// Navigation — A* route to the current objective
+// ============================================================================
+// While the Ollama Compass is the active tool, the player gets a route to the
+// current phase's target: the Exploration `target` trigger, the trigger a
+// Task's description names, or the NPC delivering a Dialogue, Reflection or
+// Quiz. Off-screen targets get an arrow on the screen
+// edge; B toggles a breadcrumb trail along the route.
+
+/// How many tiles along the route the edge arrow aims at, so it bends
+/// around walls instead of pointing straight through them
+const ARROW_LOOKAHEAD: usize = 4;
+
+/// Distance of the edge arrow from the window border, in pixels
+const ARROW_MARGIN: f32 = 36.0;
+
+/// Breadcrumbs shown from the player onwards
+const MAX_BREADCRUMBS: usize = 32;
+
+/// Walls plus solid props, in tiles. Rebuilt whenever a zone is spawned.
+#[derive(Resource, Default)]
+pub struct NavGrid(pub TileGrid);
+
+/// The route currently being shown, in tiles from the player to the target
+#[derive(Resource, Default)]
+pub struct Route {
+    pub path: Vec<IVec2>,
+    pub target: Option<Vec2>,
+}
+
+#[derive(Resource)]
+pub struct NavigationSettings {
+    pub breadcrumbs: bool,
+}
+
+impl Default for NavigationSettings {
+    fn default() -> Self {
+        Self { breadcrumbs: true }
+    }
+}
+
+#[derive(Component)]
+struct EdgeArrow;
+
+#[derive(Component)]
+struct Breadcrumb;
+
+/// Solid props: everything with a `Collider` the player can't walk through
+type PropFilter = (Without<Player>, Without<Terminal>, Without<crate::npc::Npc>);
+
+type ArrowQuery<'w, 's> = Query<'w, 's, (&'static mut Node, &'static mut Transform, &'static mut Visibility), (With<EdgeArrow>, Without<Player>)>;
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub struct NavigationPlugin;
+
+impl Plugin for NavigationPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<NavGrid>()
+           .init_resource::<Route>()
+           .init_resource::<NavigationSettings>()
+           .add_systems(Startup, spawn_edge_arrow)
+           .add_systems(Update, (
+               rebuild_nav_grid,
+               update_route.run_if(in_state(GameState::Playing)),
+               toggle_breadcrumbs,
+               draw_breadcrumbs,
+               update_edge_arrow,
+           ).chain())
+           .add_systems(OnExit(GameState::Playing), clear_route);
+    }
+}
+
+fn spawn_edge_arrow(mut commands: Commands) {
+    commands.spawn((
+        Text::new("➤"),
+        TextFont { font_size: 32.0, ..default() },
+        TextColor(Color::srgb(0.75, 0.5, 1.0)), // Violet
+        Node {
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        Visibility::Hidden,
+        GlobalZIndex(50),
+        EdgeArrow,
+    ));
+}
+
+// ============================================================================
+// Systems
+// ============================================================================
+
+fn rebuild_nav_grid(
+    wall_grid: Res<WallGrid>,
+    mut nav_grid: ResMut<NavGrid>,
+    props: Query<&Transform, (With<Collider>, PropFilter)>,
+    new_props: Query<(), (Added<Collider>, PropFilter)>,
+) {
+    if !wall_grid.is_changed() && new_props.is_empty() { return; }
+
+    let walls = &wall_grid.0;
+    let prop_tiles = props.iter().map(|tf| walls.world_to_tile(tf.translation.truncate()));
+    nav_grid.0 = TileGrid::from_tiles(walls.solid_tiles().chain(prop_tiles), walls.tile_size());
+}
+
+/// World position of the trigger the current phase is asking for
+pub fn objective_position(
+    syl: &SyllabusResource,
+    triggers: &Query<(&Transform, &QuestTrigger)>,
+) -> Option<Vec2> {
+    let mut triggers = triggers.iter();
+    let found = match syl.current_phase() {
+        QuestPhase::Exploration { target, .. } => triggers.find(|(_, t)| t.id == *target),
+        phase @ QuestPhase::Task { .. } => triggers.find(|(_, t)| phase.is_task_at(&t.id)),
+        QuestPhase::Dialogue { .. } | QuestPhase::Reflection { .. } | QuestPhase::Quiz { .. } => {
+            let speaker = syl.quest_script.current_npc();
+            triggers.find(|(_, t)| t.id == speaker)
+        }
+        QuestPhase::Complete => None,
+    };
+    found.map(|(tf, _)| tf.translation.truncate())
+}
+
+fn update_route(
+    inventory: Res<Inventory>,
+    syllabus: Option<Res<SyllabusResource>>,
+    nav_grid: Res<NavGrid>,
+    player_query: Query<&Transform, With<Player>>,
+    trigger_query: Query<(&Transform, &QuestTrigger)>,
+    mut route: ResMut<Route>,
+) {
+    let compass = inventory.active_tool == Some(ToolId::OllamaCompass);
+    let target = syllabus.filter(|_| compass).and_then(|syl| objective_position(&syl, &trigger_query));
+    let Some((target, player_tf)) = target.zip(player_query.get_single().ok()) else {
+        if route.target.is_some() {
+            *route = Route::default();
+        }
+        return;
+    };
+
+    let grid = &nav_grid.0;
+    let start = grid.world_to_tile(player_tf.translation.truncate());
+    let goal = grid.world_to_tile(target);
+
+    // Only search again when something the route depends on has moved
+    let unchanged = route.target == Some(target) && route.path.first() == Some(&start);
+    if unchanged && !nav_grid.is_changed() { return; }
+
+    route.path = grid.find_path(start, goal).unwrap_or_default();
+    route.target = Some(target);
+}
+
+fn clear_route(mut route: ResMut<Route>) {
+    *route = Route::default();
+}
+
+fn toggle_breadcrumbs(
+    actions: Res<Actions>,
+    mut settings: ResMut<NavigationSettings>,
+) {
+    if actions.just_pressed(Action::Breadcrumbs) {
+        settings.breadcrumbs = !settings.breadcrumbs;
+        info!("🍞 Breadcrumb trail {}", if settings.breadcrumbs { "on" } else { "off" });
+    }
+}
+
+fn draw_breadcrumbs(
+    mut commands: Commands,
+    route: Res<Route>,
+    settings: Res<NavigationSettings>,
+    nav_grid: Res<NavGrid>,
+    crumbs: Query<Entity, With<Breadcrumb>>,
+) {
+    if !route.is_changed() && !settings.is_changed() { return; }
+
+    for entity in &crumbs {
+        commands.entity(entity).despawn_recursive();
+    }
+    if !settings.breadcrumbs { return; }
+
+    // Skip the player's own tile and the target's
+    let trail = route.path.iter().skip(1).take(MAX_BREADCRUMBS.min(route.path.len().saturating_sub(2)));
+    for (i, tile) in trail.enumerate() {
+        let fade = 1.0 - i as f32 / MAX_BREADCRUMBS as f32;
+        let pos = nav_grid.0.tile_to_world(*tile);
+        commands.spawn((
+            Sprite {
+                color: Color::srgba(0.75, 0.5, 1.0, 0.6 * fade),
+                custom_size: Some(Vec2::new(8.0, 8.0)),
+                ..default()
+            },
+            Transform::from_xyz(pos.x, pos.y, 0.4),
+            Breadcrumb,
+        ));
+    }
+}
+
+/// Pins the arrow to the screen edge, pointing along the route, whenever
+/// the target is off-screen
+fn update_edge_arrow(
+    route: Res<Route>,
+    nav_grid: Res<NavGrid>,
+    camera_query: Query<(&Camera, &GlobalTransform), With<GameCamera>>,
+    player_query: Query<&Transform, With<Player>>,
+    mut arrow_query: ArrowQuery,
+) {
+    let Ok((mut node, mut arrow_tf, mut visibility)) = arrow_query.get_single_mut() else { return };
+    *visibility = Visibility::Hidden;
+
+    let Some(target) = route.target else { return };
+    let Ok((camera, camera_tf)) = camera_query.get_single() else { return };
+    let Ok(player_tf) = player_query.get_single() else { return };
+    let Some(size) = camera.logical_viewport_size() else { return };
+
+    if let Ok(on_screen) = camera.world_to_viewport(camera_tf, target.extend(0.0)) {
+        let inside = on_screen.x >= 0.0 && on_screen.y >= 0.0 && on_screen.x <= size.x && on_screen.y <= size.y;
+        if inside { return; }
+    }
+
+    // Aim a few tiles down the route, or straight at the target without one
+    let aim = match route.path.get(ARROW_LOOKAHEAD.min(route.path.len().saturating_sub(1))) {
+        Some(tile) => nav_grid.0.tile_to_world(*tile),
+        None => target,
+    };
+    let Some(dir) = (aim - player_tf.translation.truncate()).try_normalize() else { return };
+
+    // Screen space has y pointing down
+    let screen_dir = Vec2::new(dir.x, -dir.y);
+    let half = size / 2.0 - Vec2::splat(ARROW_MARGIN);
+    let scale = (half.x / screen_dir.x.abs()).min(half.y / screen_dir.y.abs());
+    let pos = size / 2.0 + screen_dir * scale;
+
+    node.left = Val::Px(pos.x - 16.0);
+    node.top = Val::Px(pos.y - 16.0);
+    arrow_tf.rotation = Quat::from_rotation_z(-dir.to_angle());
+    *visibility = Visibility::Visible;
+}
@@ -0,0 +1,101 @@
+use bevy::prelude::*;
+use serde::Deserialize;
+use std::collections::HashMap;
+use crate::map::MapObject;
+use crate::syllabus::EventSet;
+
+// ============================================================================
+// NPCs — data-driven characters that deliver quest phases
+// ============================================================================
+// NPCs are declared under `[[npcs]]` in the syllabus and placed by `npc`
+// objects in a map (the object's name, or its `npc` property, is the NPC id)
+// or by `map` + `tile` on the definition itself. Phases name who delivers
+// them with `npc = "<id>"`; phases that don't are delivered by the Teacher.
+
+/// Delivers phases that don't name an NPC
+pub const DEFAULT_NPC: &str = "Teacher";
+
+const DEFAULT_PERSONA: &str = "You are The Gamification Architect, a wise cyberpunk mentor and Pedagogical Orchestrator \
+    who teaches the value of local, sovereign AI.";
+
+/// An NPC as written in the syllabus
+#[derive(Debug, Deserialize, Clone)]
+pub struct NpcDef {
+    pub id: String,
+    pub name: String,
+    pub sprite: Option<String>,
+    /// Who the NPC is, written as the opening of an LLM prompt
+    pub persona: String,
+    /// Said (via the LLM) when the NPC has nothing to deliver
+    pub greeting: Option<String>,
+    /// The NPC's own telling of framework events; falls back to the module's
+    #[serde(default)]
+    pub dialogue: EventSet,
+    /// Zone and tile to stand on, for NPCs without a map object
+    pub map: Option<String>,
+    pub tile: Option<[f32; 2]>,
+    /// Asset path of a `.yarn` dialogue script, run on SPACE instead of the LLM
+    pub script: Option<String>,
+}
+
+impl NpcDef {
+    /// The map object this definition stands in for when placed by `tile`
+    pub fn map_object(&self) -> Option<MapObject> {
+        let [x, y] = self.tile?;
+        let mut properties = HashMap::from([
+            ("label".to_string(), serde_json::Value::from(self.name.clone())),
+            ("size".to_string(), serde_json::Value::from(64.0)),
+            ("radius".to_string(), serde_json::Value::from(100.0)),
+        ]);
+        if let Some(ref sprite) = self.sprite {
+            properties.insert("sprite".to_string(), serde_json::Value::from(sprite.clone()));
+        }
+        Some(MapObject {
+            kind: "npc".to_string(),
+            name: self.id.clone(),
+            tile: Vec2::new(x, y),
+            properties,
+        })
+    }
+}
+
+/// A spawned NPC. Its `QuestTrigger` id is the same as `id`.
+#[derive(Component, Clone, Debug)]
+pub struct Npc {
+    pub id: String,
+    pub name: String,
+    pub persona: String,
+    pub greeting: Option<String>,
+    pub dialogue: EventSet,
+    pub script: Option<String>,
+}
+
+impl Npc {
+    /// Builds the component from its definition, or a Teacher-like stand-in
+    /// for map NPCs the syllabus doesn't declare
+    pub fn new(id: &str, label: &str, def: Option<&NpcDef>) -> Self {
+        match def {
+            Some(def) => Self {
+                id: def.id.clone(),
+                name: def.name.clone(),
+                persona: def.persona.clone(),
+                greeting: def.greeting.clone(),
+                dialogue: def.dialogue.clone(),
+                script: def.script.clone(),
+            },
+            None => Self {
+                id: id.to_string(),
+                name: label.to_string(),
+                persona: DEFAULT_PERSONA.to_string(),
+                greeting: None,
+                dialogue: EventSet::default(),
+                script: None,
+            },
+        }
+    }
+
+    /// Lesson text for a framework event, preferring the NPC's own telling
+    pub fn event_text<'a>(&'a self, event: &str, module_events: &'a EventSet) -> Option<&'a str> {
+        self.dialogue.get(event).or_else(|| module_events.get(event))
+    }
+}
@@ -1,5 +1,9 @@
 use bevy::prelude::*;
 use crate::scoring::XpGainEvent;
+use crate::audio::SfxEvent;
+use crate::actions::{Action, Actions, InputContext};
+use crate::narration::{Narrated, Priority};
+use crate::i18n::Locale;
 
 // ============================================================================
 // Terminal Command-Builder Puzzle
@@ -72,7 +76,7 @@ impl Plugin for PuzzlePlugin {
 
 /// Activate puzzle when player is near Terminal and quest requires a task
 fn activate_puzzle(
-    keys: Res<ButtonInput<KeyCode>>,
+    (actions, locale): (Res<Actions>, Res<Locale>),
     player_query: Query<&Transform, With<crate::game_world::Player>>,
     trigger_query: Query<(&Transform, &crate::game_world::QuestTrigger)>,
     syllabus: Option<Res<crate::syllabus::SyllabusResource>>,
@@ -82,15 +86,13 @@ fn activate_puzzle(
 ) {
     if puzzle.is_active || puzzle.solved { return; }
 
-    if !keys.just_pressed(KeyCode::KeyT) { return; }
+    if !actions.just_pressed(Action::Interact) { return; }
 
     let Ok(player_tf) = player_query.get_single() else { return };
     let Some(syl) = &syllabus else { return };
 
     // Only activate during Task phases that mention Terminal
-    let is_terminal_task = matches!(syl.current_phase(), 
-        crate::syllabus::QuestPhase::Task { description, .. } if description.contains("Terminal")
-    );
+    let is_terminal_task = syl.current_phase().is_task_at("Terminal");
     if !is_terminal_task { return; }
 
     // Check if player is near the Terminal
@@ -104,7 +106,7 @@ fn activate_puzzle(
                 puzzle.error_flash = 0.0;
                 
                 // Spawn puzzle UI overlay
-                spawn_puzzle_ui(&mut commands, &puzzle);
+                spawn_puzzle_ui(&mut commands, &puzzle, &actions, &locale);
                 info!("🧩 Terminal Puzzle activated!");
                 return;
             }
@@ -112,7 +114,7 @@ fn activate_puzzle(
     }
 }
 
-fn spawn_puzzle_ui(commands: &mut Commands, puzzle: &PuzzleState) {
+fn spawn_puzzle_ui(commands: &mut Commands, puzzle: &PuzzleState, actions: &Actions, locale: &Locale) {
     commands.spawn((
         Node {
             position_type: PositionType::Absolute,
@@ -134,16 +136,20 @@ fn spawn_puzzle_ui(commands: &mut Commands, puzzle: &PuzzleState) {
     )).with_children(|parent| {
         // Title
         parent.spawn((
-            Text::new("💻 TERMINAL PUZZLE"),
+            Text::new(locale.t("puzzle-title")),
             TextFont { font_size: 24.0, ..default() },
             TextColor(Color::srgb(0.0, 1.0, 0.5)),
+            Narrated(Priority::Assertive),
         ));
 
         // Instruction
         parent.spawn((
-            Text::new("Build the correct command to start your local AI.\nPress 1, 2, or 3 to select tokens in order."),
+            Text::new(locale.fmt("puzzle-instructions", &[
+                ("keys", &actions.choices_label(puzzle.tokens.len(), locale)),
+            ])),
             TextFont { font_size: 14.0, ..default() },
             TextColor(Color::srgb(0.7, 0.7, 0.7)),
+            Narrated::default(),
         ));
 
         // Token buttons row
@@ -164,9 +170,10 @@ fn spawn_puzzle_ui(commands: &mut Commands, puzzle: &PuzzleState) {
                     BackgroundColor(Color::srgb(0.1, 0.15, 0.1)),
                     BorderColor(Color::srgb(0.0, 0.8, 0.4)),
                     PuzzleTokenSlot { index: i },
+                    crate::touch::TapChoice(i as u8),
                 )).with_children(|slot| {
                     slot.spawn((
-                        Text::new(format!("[{}] {}", i + 1, token)),
+                        Text::new(format!("[{}] {}", actions.label(Action::Choice(i as u8)), token)),
                         TextFont { font_size: 18.0, ..default() },
                         TextColor(Color::srgb(0.0, 1.0, 0.5)),
                     ));
@@ -180,6 +187,7 @@ fn spawn_puzzle_ui(commands: &mut Commands, puzzle: &PuzzleState) {
             TextFont { font_size: 20.0, ..default() },
             TextColor(Color::srgb(1.0, 1.0, 1.0)),
             PuzzleInputDisplay,
+            Narrated::default(),
         ));
 
         // Status text
@@ -188,27 +196,26 @@ fn spawn_puzzle_ui(commands: &mut Commands, puzzle: &PuzzleState) {
             TextFont { font_size: 16.0, ..default() },
             TextColor(Color::srgb(1.0, 0.3, 0.3)),
             PuzzleStatusText,
+            Narrated(Priority::Assertive),
         ));
     });
 }
 
 fn handle_puzzle_input(
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<Actions>,
     mut puzzle: ResMut<PuzzleState>,
     mut xp_writer: EventWriter<XpGainEvent>,
     mut syllabus: Option<ResMut<crate::syllabus::SyllabusResource>>,
     mut event_writer: EventWriter<crate::syllabus::QuestAdvancedEvent>,
     mut reward_writer: EventWriter<crate::inventory::ItemGetEvent>,
+    (mut assessment_writer, mut sfx_writer): (EventWriter<crate::mastery::AssessmentEvent>, EventWriter<SfxEvent>),
 ) {
     if !puzzle.is_active || puzzle.solved { return; }
 
-    let mut selected = None;
-    if keys.just_pressed(KeyCode::Digit1) { selected = Some(0); }
-    if keys.just_pressed(KeyCode::Digit2) { selected = Some(1); }
-    if keys.just_pressed(KeyCode::Digit3) { selected = Some(2); }
+    let selected = actions.choice(InputContext::Puzzle);
 
     // ESC to cancel
-    if keys.just_pressed(KeyCode::Escape) {
+    if actions.just_pressed(Action::Cancel) {
         puzzle.is_active = false;
         return;
     }
@@ -224,7 +231,16 @@ fn handle_puzzle_input(
                     .map(|&i| puzzle.tokens[i])
                     .collect();
 
-                if player_cmd == puzzle.correct_order {
+                let solved = player_cmd == puzzle.correct_order;
+                if let Some(ref syl) = syllabus {
+                    assessment_writer.send(crate::mastery::AssessmentEvent {
+                        objectives: syl.quest_script.current_objectives().to_vec(),
+                        correct: solved,
+                        source: "Terminal Puzzle".to_string(),
+                    });
+                }
+
+                if solved {
                     // CORRECT!
                     puzzle.solved = true;
                     info!("✅ Terminal Puzzle SOLVED! Command: {}", player_cmd.join(" "));
@@ -253,6 +269,7 @@ fn handle_puzzle_input(
                     // WRONG — reset
                     puzzle.player_sequence.clear();
                     puzzle.error_flash = 1.0;
+                    sfx_writer.send(SfxEvent::PuzzleError);
                     info!("❌ Wrong command order! Try again.");
                 }
             }
@@ -266,6 +283,7 @@ fn update_puzzle_ui(
     mut input_query: Query<&mut Text, (With<PuzzleInputDisplay>, Without<PuzzleStatusText>)>,
     mut status_query: Query<&mut Text, (With<PuzzleStatusText>, Without<PuzzleInputDisplay>)>,
     mut slot_query: Query<(&PuzzleTokenSlot, &mut BackgroundColor)>,
+    locale: Res<Locale>,
 ) {
     if !puzzle.is_active { return; }
 
@@ -290,11 +308,11 @@ fn update_puzzle_ui(
     if puzzle.error_flash > 0.0 {
         puzzle.error_flash -= time.delta_secs() * 2.0;
         for mut text in &mut status_query {
-            *text = Text::new("⚠ Wrong order! Try again...");
+            *text = Text::new(locale.t("puzzle-wrong-order"));
         }
     } else if puzzle.solved {
         for mut text in &mut status_query {
-            *text = Text::new("✅ COMPILED! +100 XP");
+            *text = Text::new(locale.fmt("puzzle-solved", &[("xp", &100)]));
         }
     } else {
         for mut text in &mut status_query {
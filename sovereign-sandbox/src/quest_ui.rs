@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use crate::syllabus::SyllabusResource;
+use crate::i18n::{localized, Locale};
 
 // ============================================================================
 // Components
@@ -23,6 +24,9 @@ pub struct QuestTimerText;
 #[derive(Component)]
 pub struct QuestObjectiveText;
 
+#[derive(Component)]
+pub struct QuestChecklistText;
+
 #[derive(Resource)]
 pub struct MissionTimer(pub Timer);
 
@@ -53,6 +57,7 @@ impl Plugin for QuestUIPlugin {
                handle_quest_navigation, 
                update_quest_notification,
                update_quest_log,
+               update_objective_checklist,
                update_mission_timer,
                show_victory_screen,
                manage_notifications,
@@ -86,7 +91,7 @@ fn setup_quest_ui(mut commands: Commands) {
         .with_children(|parent| {
             // Header: "QUEST LOG"
             parent.spawn((
-                Text::new("═══ QUEST LOG ═══"),
+                localized("quest-log-header"),
                 TextFont {
                     font_size: 13.0,
                     ..default()
@@ -96,7 +101,7 @@ fn setup_quest_ui(mut commands: Commands) {
 
             // Module Title
             parent.spawn((
-                Text::new("Loading..."),
+                localized("loading"),
                 TextFont {
                     font_size: 16.0,
                     ..default()
@@ -107,7 +112,7 @@ fn setup_quest_ui(mut commands: Commands) {
 
             // Current Phase / Step
             parent.spawn((
-                Text::new("🗺️ Walk to the Teacher"),
+                localized("quest-log-start"),
                 TextFont {
                     font_size: 14.0,
                     ..default()
@@ -127,6 +132,17 @@ fn setup_quest_ui(mut commands: Commands) {
                 QuestObjectiveText,
             ));
 
+            // Learning objective checklist for the current module
+            parent.spawn((
+                Text::new(""),
+                TextFont {
+                    font_size: 12.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.7, 0.7, 0.7)),
+                QuestChecklistText,
+            ));
+
             // Progress Bar Container
             parent
                 .spawn(Node {
@@ -175,7 +191,7 @@ fn setup_quest_ui(mut commands: Commands) {
         QuestNotification,
     )).with_children(|parent| {
         parent.spawn((
-            Text::new("NEW OBJECTIVE"),
+            localized("quest-new-objective"),
             TextFont {
                 font_size: 28.0,
                 ..default()
@@ -189,12 +205,14 @@ fn setup_quest_ui(mut commands: Commands) {
             BackgroundColor(Color::srgba(0.05, 0.02, 0.1, 0.88)),
             BorderColor(Color::srgb(0.39, 0.40, 0.95)),
             QuestNotificationText,
+            crate::narration::Narrated::default(),
         ));
     });
 }
 
 fn update_quest_notification(
     syllabus: Option<Res<SyllabusResource>>,
+    locale: Res<Locale>,
     mut notification_timer: ResMut<NotificationTimer>,
     mut notification_query: Query<&mut Node, (With<QuestNotification>, Without<QuestProgressBar>)>,
     mut notif_text_query: Query<&mut Text, With<QuestNotificationText>>,
@@ -209,12 +227,12 @@ fn update_quest_notification(
             let phase = syl.current_phase();
             let phase_num = syl.quest_script.current_phase + 1;
             let total = syl.quest_script.total_phases();
-            let phase_type = phase.phase_type_name();
-            let goal = phase.display_label();
+            let phase_type = phase.phase_type_label(&locale);
+            let goal = phase.display_label(&locale);
 
             for mut text in &mut notif_text_query {
                 if syl.quest_script.is_complete() {
-                    *text = Text::new("━━  QUEST COMPLETE  ━━");
+                    *text = Text::new(format!("━━  {}  ━━", locale.t("quest-complete-banner")));
                 } else {
                     *text = Text::new(format!("━━  [{phase_type}]  {phase_num}/{total}: {goal}  ━━"));
                 }
@@ -229,18 +247,19 @@ type ObjectiveQueryFilter = (With<QuestObjectiveText>, Without<QuestTitleText>,
 
 fn update_quest_log(
     syllabus: Option<Res<SyllabusResource>>,
+    locale: Res<Locale>,
     mut title_query: Query<&mut Text, TitleQueryFilter>,
     mut step_query: Query<&mut Text, StepQueryFilter>,
     mut objective_query: Query<&mut Text, ObjectiveQueryFilter>,
     mut progress_query: Query<&mut Node, With<QuestProgressBar>>,
 ) {
     if let Some(syl) = syllabus {
-        if syl.is_changed() {
+        if syl.is_changed() || locale.is_changed() {
             let phase = syl.current_phase();
             let phase_num = syl.quest_script.current_phase + 1;
             let total = syl.quest_script.total_phases();
-            let phase_type = phase.phase_type_name();
-            let goal = phase.display_label();
+            let phase_type = phase.phase_type_label(&locale);
+            let goal = phase.display_label(&locale);
 
             // Update module title
             if let Some(quest) = syl.current_quest() {
@@ -252,7 +271,7 @@ fn update_quest_log(
             // Update phase display
             for mut text in &mut step_query {
                 if syl.quest_script.is_complete() {
-                    *text = Text::new("✅ Quest Complete!");
+                    *text = Text::new(locale.t("quest-complete"));
                 } else {
                     *text = Text::new(format!("[{}] {}/{}", phase_type, phase_num, total));
                 }
@@ -263,7 +282,7 @@ fn update_quest_log(
                 if syl.quest_script.is_complete() {
                     *text = Text::new("");
                 } else {
-                    *text = Text::new(format!("GOAL: {}", goal));
+                    *text = Text::new(locale.fmt("quest-goal", &[("goal", &goal)]));
                 }
             }
 
@@ -276,6 +295,36 @@ fn update_quest_log(
     }
 }
 
+fn update_objective_checklist(
+    syllabus: Option<Res<SyllabusResource>>,
+    tracker: Res<crate::mastery::MasteryTracker>,
+    locale: Res<Locale>,
+    mut query: Query<&mut Text, With<QuestChecklistText>>,
+) {
+    let Some(syl) = syllabus else { return };
+    if !syl.is_changed() && !tracker.is_changed() && !locale.is_changed() { return; }
+
+    let objectives = syl.module_objectives();
+    let checklist = if objectives.is_empty() {
+        String::new()
+    } else {
+        let mut lines = vec![locale.t("quest-objectives")];
+        for objective in objectives {
+            let mark = match tracker.get(&objective.id) {
+                _ if tracker.is_mastered(&objective.id) => "☑",
+                Some(m) if m.attempts > 0 => "◐",
+                _ => "☐",
+            };
+            lines.push(format!("{} {}", mark, objective.text));
+        }
+        lines.join("\n")
+    };
+
+    for mut text in &mut query {
+        *text = Text::new(checklist.clone());
+    }
+}
+
 fn manage_notifications(
     time: Res<Time>,
     mut timer: ResMut<NotificationTimer>,
@@ -310,7 +359,7 @@ fn handle_quest_navigation(
                 info!("🎓 Advanced to Module {}", syl.current_module_index + 1);
             } else {
                 let phase = syl.current_phase();
-                info!("📖 Advanced to Phase {}: {}", syl.quest_script.current_phase + 1, phase.display_label());
+                info!("📖 Advanced to Phase {}: {:?}", syl.quest_script.current_phase + 1, phase.phase_type_name());
             }
         }
     }
@@ -338,12 +387,16 @@ fn show_victory_screen(
     victory_query: Query<Entity, With<VictoryScreen>>,
     timer: Res<MissionTimer>,
     score: Res<crate::scoring::PlayerScore>,
+    tracker: Res<crate::mastery::MasteryTracker>,
+    locale: Res<Locale>,
 ) {
     if let Some(syl) = syllabus {
         if syl.current_module_index >= syl.syllabus.modules.len() && victory_query.is_empty() {
             let elapsed = timer.0.elapsed_secs();
             let mins = (elapsed / 60.0) as usize;
             let secs = (elapsed % 60.0) as usize;
+            let objectives = &syl.syllabus.objectives;
+            let mastered = objectives.iter().filter(|o| tracker.is_mastered(&o.id)).count();
 
             // Spawn victory screen
             commands.spawn((
@@ -367,13 +420,13 @@ fn show_victory_screen(
             ))
             .with_children(|parent| {
                 parent.spawn((
-                    Text::new("🏆  MISSION COMPLETE"),
+                    Text::new(locale.t("mission-complete")),
                     TextFont { font_size: 48.0, ..default() },
                     TextColor(Color::srgb(0.55, 0.36, 0.96)),
                 ));
 
                 parent.spawn((
-                    Text::new("The Sovereign Sandbox"),
+                    Text::new(locale.t("game-title")),
                     TextFont { font_size: 20.0, ..default() },
                     TextColor(Color::srgb(0.39, 0.40, 0.95)),
                 ));
@@ -391,12 +444,13 @@ fn show_victory_screen(
 
                 // Stats grid
                 let stats = [
-                    ("⏱  Time",      format!("{:02}:{:02}", mins, secs)),
-                    ("⚡  XP Earned", format!("{}", score.xp)),
-                    ("📜  Fragments", format!("{}/8", score.fragments_collected)),
-                    ("🗺️  Rooms",     format!("{}/4", score.rooms_discovered.len())),
-                    ("🧩  Puzzles",   format!("{}", score.puzzles_solved)),
-                    ("⭐  Level",     format!("Lv.{} — {}", score.level, score.title)),
+                    ("stat-time",       format!("{:02}:{:02}", mins, secs)),
+                    ("stat-xp",         format!("{}", score.xp)),
+                    ("stat-fragments",  format!("{}/{}", score.fragments_collected, syl.total_fragments())),
+                    ("stat-rooms",      format!("{}", score.rooms_discovered.len())),
+                    ("stat-puzzles",    format!("{}", score.puzzles_solved)),
+                    ("stat-objectives", locale.fmt("stat-objectives-value", &[("mastered", &mastered), ("total", &objectives.len())])),
+                    ("stat-level",      locale.fmt("stat-level-value", &[("level", &score.level), ("title", &score.title(&locale))])),
                 ];
 
                 for (label, value) in &stats {
@@ -409,7 +463,7 @@ fn show_victory_screen(
                         }
                     ).with_children(|row| {
                         row.spawn((
-                            Text::new(*label),
+                            Text::new(locale.t(label)),
                             TextFont { font_size: 17.0, ..default() },
                             TextColor(Color::srgb(0.7, 0.7, 0.7)),
                             Node { width: Val::Px(180.0), ..default() },
@@ -434,13 +488,13 @@ fn show_victory_screen(
                 ));
 
                 parent.spawn((
-                    Text::new("You have demonstrated mastery over local AI tools.\nThe sovereign classroom is yours to shape."),
+                    Text::new(locale.t("mission-complete-body")),
                     TextFont { font_size: 16.0, ..default() },
                     TextColor(Color::srgb(0.75, 0.75, 0.75)),
                 ));
 
                 parent.spawn((
-                    Text::new("← Return to the E-Learning Module to continue your journey"),
+                    Text::new(locale.t("mission-complete-return")),
                     TextFont { font_size: 14.0, ..default() },
                     TextColor(Color::srgb(0.39, 0.40, 0.95)),
                 ));
@@ -37,15 +37,11 @@ pub struct SubmitAnswerEvent(pub usize);
 
 // 3. The Input System (Fires the Event)
 pub fn handle_quiz_input(
-    keyboard_input: Res<ButtonInput<KeyCode>>,
+    actions: Res<crate::actions::Actions>,
     mut event_writer: EventWriter<SubmitAnswerEvent>,
 ) {
-    if keyboard_input.just_pressed(KeyCode::Digit1) {
-        event_writer.send(SubmitAnswerEvent(0));
-    } else if keyboard_input.just_pressed(KeyCode::Digit2) {
-        event_writer.send(SubmitAnswerEvent(1));
-    } else if keyboard_input.just_pressed(KeyCode::Digit3) {
-        event_writer.send(SubmitAnswerEvent(2));
+    if let Some(choice) = actions.choice(crate::actions::InputContext::Quiz).filter(|i| *i < 3) {
+        event_writer.send(SubmitAnswerEvent(choice));
     }
 }
 
@@ -0,0 +1,420 @@
+use bevy::prelude::*;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use crate::GameState;
+use crate::actions::{Action, Actions, InputContext};
+use crate::i18n::Locale;
+use crate::scoring::XpGainEvent;
+pub use crate::spaced_repetition::{CardContent, Clock, ReviewCard, SystemClock};
+use crate::ui::knowledge_popup::KnowledgeCollectedEvent;
+
+// ============================================================================
+// Review — spaced repetition of fragments and missed quiz items
+// ============================================================================
+// Collected fragments and missed quiz questions become review cards. Cards are
+// scheduled with SM-2 (see `spaced_repetition`) and persisted between
+// sessions, to `DECK_PATH` natively and to localStorage in the browser; the
+// title screen's [R] option runs whatever is due.
+
+const DECK_PATH: &str = "assets/memory/review_deck.json";
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY: &str = "sovereign-sandbox.review-deck";
+
+/// XP for each card recalled during a review session
+const RECALL_XP: u32 = 5;
+
+#[derive(Resource)]
+pub struct ReviewClock(pub Box<dyn Clock>);
+
+impl Default for ReviewClock {
+    fn default() -> Self {
+        Self(Box::new(SystemClock))
+    }
+}
+
+#[derive(Resource, Default, Serialize, Deserialize)]
+pub struct ReviewDeck {
+    pub cards: Vec<ReviewCard>,
+}
+
+impl ReviewDeck {
+    /// Adds a card, or makes an existing one due again. Returns true if the
+    /// deck changed.
+    pub fn add(&mut self, content: CardContent, now: DateTime<Utc>) -> bool {
+        let id = content.id();
+        match self.cards.iter_mut().find(|c| c.id == id) {
+            Some(card) if matches!(card.content, CardContent::Quiz { .. }) => {
+                // Missed again in play — start the card over
+                card.repetitions = 0;
+                card.interval_days = 0;
+                card.due = now;
+                true
+            }
+            Some(_) => false,
+            None => {
+                self.cards.push(ReviewCard::new(content, now));
+                true
+            }
+        }
+    }
+
+    pub fn due_ids(&self, now: DateTime<Utc>) -> Vec<String> {
+        let mut due: Vec<&ReviewCard> = self.cards.iter().filter(|c| c.is_due(now)).collect();
+        due.sort_by_key(|c| c.due);
+        due.into_iter().map(|c| c.id.clone()).collect()
+    }
+
+    pub fn get(&self, id: &str) -> Option<&ReviewCard> {
+        self.cards.iter().find(|c| c.id == id)
+    }
+
+    pub fn grade(&mut self, id: &str, quality: u8, now: DateTime<Utc>) {
+        if let Some(card) = self.cards.iter_mut().find(|c| c.id == id) {
+            card.schedule(quality, now);
+        }
+    }
+
+    fn from_json(json: &str) -> Self {
+        serde_json::from_str(json).unwrap_or_else(|e| {
+            warn!("⚠️ Review deck unreadable ({}), starting fresh", e);
+            Self::default()
+        })
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn load() -> Self {
+        std::fs::read_to_string(DECK_PATH).map(|json| Self::from_json(&json)).unwrap_or_default()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn load() -> Self {
+        web_sys::window()
+            .and_then(|w| w.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(STORAGE_KEY).ok().flatten())
+            .map(|json| Self::from_json(&json))
+            .unwrap_or_default()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn save(&self) {
+        let path = std::path::Path::new(DECK_PATH);
+        let result = serde_json::to_string_pretty(self)
+            .map_err(std::io::Error::other)
+            .and_then(|json| {
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                std::fs::write(path, json)
+            });
+        if let Err(e) = result {
+            error!("Failed to save review deck: {}", e);
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn save(&self) {
+        let Ok(json) = serde_json::to_string(self) else { return };
+        let storage = web_sys::window().and_then(|w| w.local_storage().ok().flatten());
+        if storage.is_none_or(|s| s.set_item(STORAGE_KEY, &json).is_err()) {
+            error!("Failed to save review deck to localStorage");
+        }
+    }
+}
+
+/// Sent when play produces something worth reviewing later (e.g. a missed quiz)
+#[derive(Event)]
+pub struct ReviewCardEvent {
+    pub content: CardContent,
+}
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub struct ReviewPlugin;
+
+impl Plugin for ReviewPlugin {
+    fn build(&self, app: &mut App) {
+        let deck = ReviewDeck::load();
+        info!("🔁 Review deck: {} cards", deck.cards.len());
+
+        app.insert_resource(deck)
+           .init_resource::<ReviewClock>()
+           .add_event::<ReviewCardEvent>()
+           .add_systems(Update, collect_review_cards)
+           .add_systems(OnEnter(GameState::Review), start_review_session)
+           .add_systems(Update, (handle_review_input, update_review_text)
+               .chain()
+               .run_if(in_state(GameState::Review)))
+           .add_systems(OnExit(GameState::Review), cleanup_review_session);
+    }
+}
+
+// ============================================================================
+// Card Collection
+// ============================================================================
+
+fn collect_review_cards(
+    mut knowledge_events: EventReader<KnowledgeCollectedEvent>,
+    mut card_events: EventReader<ReviewCardEvent>,
+    mut deck: ResMut<ReviewDeck>,
+    clock: Res<ReviewClock>,
+) {
+    let now = clock.0.now();
+    let mut changed = false;
+
+    for ev in knowledge_events.read() {
+        changed |= deck.add(CardContent::Fragment {
+            title: ev.title.clone(),
+            content: crate::ui::rich_text::plain(&ev.content),
+        }, now);
+    }
+    for ev in card_events.read() {
+        changed |= deck.add(ev.content.clone(), now);
+    }
+
+    if changed {
+        deck.save();
+    }
+}
+
+// ============================================================================
+// Review Session
+// ============================================================================
+
+#[derive(Debug, Clone, PartialEq)]
+enum ReviewStage {
+    /// Card front is showing
+    Prompt,
+    /// Fragment content revealed, waiting for a self-grade
+    Revealed,
+    /// Result of the last card, waiting for any key
+    Feedback(String),
+    /// Nothing left to review
+    Done,
+}
+
+#[derive(Resource)]
+pub struct ReviewSession {
+    queue: Vec<String>,
+    current: usize,
+    stage: ReviewStage,
+    recalled: u32,
+}
+
+impl ReviewSession {
+    /// What the choice inputs pick right now: a self-grade or a quiz option
+    pub fn choices(&self, deck: &ReviewDeck, locale: &Locale) -> Vec<String> {
+        let card = self.queue.get(self.current).and_then(|id| deck.get(id));
+        match (&self.stage, card.map(|c| &c.content)) {
+            (ReviewStage::Revealed, Some(_)) => GRADE_NAMES.iter().map(|key| locale.t(key)).collect(),
+            (ReviewStage::Prompt, Some(CardContent::Quiz { options, .. })) => options.clone(),
+            _ => Vec::new(),
+        }
+    }
+}
+
+#[derive(Component)]
+struct ReviewScreenRoot;
+
+#[derive(Component)]
+struct ReviewCardText;
+
+fn start_review_session(
+    mut commands: Commands,
+    deck: Res<ReviewDeck>,
+    clock: Res<ReviewClock>,
+    actions: Res<Actions>,
+    locale: Res<Locale>,
+) {
+    let queue = deck.due_ids(clock.0.now());
+    info!("🔁 Review session: {} cards due", queue.len());
+    let stage = if queue.is_empty() { ReviewStage::Done } else { ReviewStage::Prompt };
+
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            flex_direction: FlexDirection::Column,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            row_gap: Val::Px(20.0),
+            ..default()
+        },
+        BackgroundColor(Color::srgb(0.02, 0.02, 0.04)),
+        ReviewScreenRoot,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(locale.t("review-title")),
+            TextFont { font_size: 32.0, ..default() },
+            TextColor(Color::srgb(0.0, 1.0, 0.5)),
+        ));
+
+        parent.spawn((
+            Text::new(""),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(Color::srgb(0.9, 0.9, 0.9)),
+            Node { max_width: Val::Px(720.0), ..default() },
+            ReviewCardText,
+            crate::narration::Narrated::default(),
+        ));
+
+        parent.spawn((
+            Text::new(locale.fmt("review-back", &[("key", &actions.label(Action::Cancel))])),
+            TextFont { font_size: 13.0, ..default() },
+            TextColor(Color::srgb(0.5, 0.5, 0.5)),
+        ));
+    });
+
+    commands.insert_resource(ReviewSession { queue, current: 0, stage, recalled: 0 });
+}
+
+/// SM-2 quality for each self-grade
+const GRADE_QUALITY: [u8; 4] = [1, 3, 4, 5];
+/// Message ids of the self-grades
+const GRADE_NAMES: [&str; 4] = ["review-forgot", "review-hard", "review-good", "review-easy"];
+
+fn handle_review_input(
+    actions: Res<Actions>,
+    session: Option<ResMut<ReviewSession>>,
+    mut deck: ResMut<ReviewDeck>,
+    clock: Res<ReviewClock>,
+    mut xp_writer: EventWriter<XpGainEvent>,
+    mut next_state: ResMut<NextState<GameState>>,
+    locale: Res<Locale>,
+) {
+    let Some(mut session) = session else { return };
+
+    if actions.just_pressed(Action::Cancel) {
+        next_state.set(GameState::Menu);
+        return;
+    }
+
+    let Some(card) = session.queue.get(session.current).and_then(|id| deck.get(id)).cloned() else {
+        if session.stage != ReviewStage::Done {
+            session.stage = ReviewStage::Done;
+        }
+        if actions.just_pressed(Action::Confirm) {
+            next_state.set(GameState::Menu);
+        }
+        return;
+    };
+
+    let pressed = actions.any_just_pressed();
+    let digit = actions.choice(InputContext::Review);
+
+    let quality = match (&session.stage, &card.content) {
+        (ReviewStage::Prompt, CardContent::Fragment { .. }) => {
+            if actions.just_pressed(Action::Talk) || actions.just_pressed(Action::Confirm) {
+                session.stage = ReviewStage::Revealed;
+            }
+            None
+        }
+        (ReviewStage::Revealed, _) => digit.and_then(|i| GRADE_QUALITY.get(i).copied()),
+        (ReviewStage::Prompt, CardContent::Quiz { options, correct_index, .. }) => {
+            match digit.filter(|i| *i < options.len()) {
+                Some(i) if i == *correct_index => Some(4),
+                Some(_) => Some(1),
+                None => None,
+            }
+        }
+        (ReviewStage::Feedback(_), _) => {
+            if pressed {
+                session.current += 1;
+                session.stage = if session.current < session.queue.len() {
+                    ReviewStage::Prompt
+                } else {
+                    ReviewStage::Done
+                };
+            }
+            None
+        }
+        (ReviewStage::Done, _) => None,
+    };
+
+    if let Some(quality) = quality {
+        deck.grade(&card.id, quality, clock.0.now());
+        deck.save();
+
+        let recalled = quality >= 3;
+        if recalled {
+            session.recalled += 1;
+            xp_writer.send(XpGainEvent {
+                amount: RECALL_XP,
+                reason: "Review recall".to_string(),
+            });
+        }
+
+        let next_due = deck.get(&card.id).map(|c| c.interval_days).unwrap_or(1);
+        let verdict = match (&card.content, recalled) {
+            (CardContent::Quiz { options, correct_index, .. }, false) => locale.fmt("review-answer-was", &[
+                ("answer", &options.get(*correct_index).map(|s| s.as_str()).unwrap_or("?")),
+            ]),
+            (_, true) => locale.fmt("review-recalled", &[("xp", &RECALL_XP)]),
+            (_, false) => locale.t("review-again-soon"),
+        };
+        session.stage = ReviewStage::Feedback(locale.fmt("review-feedback", &[("verdict", &verdict), ("days", &next_due)]));
+    }
+}
+
+fn update_review_text(
+    session: Option<Res<ReviewSession>>,
+    deck: Res<ReviewDeck>,
+    actions: Res<Actions>,
+    locale: Res<Locale>,
+    mut query: Query<&mut Text, With<ReviewCardText>>,
+) {
+    let Some(session) = session else { return };
+    if !session.is_changed() { return; }
+    let choice = |i: usize| actions.label(Action::Choice(i as u8));
+
+    let card = session.queue.get(session.current).and_then(|id| deck.get(id));
+    let progress = locale.fmt("review-progress", &[("n", &(session.current + 1)), ("count", &session.queue.len())]);
+
+    let body = match (&session.stage, card.map(|c| &c.content)) {
+        (ReviewStage::Done, _) | (_, None) if session.queue.is_empty() => {
+            locale.fmt("review-nothing-due", &[("key", &actions.label(Action::Confirm))])
+        }
+        (ReviewStage::Done, _) | (_, None) => locale.fmt("review-complete", &[
+            ("recalled", &session.recalled),
+            ("count", &session.queue.len()),
+            ("key", &actions.label(Action::Confirm)),
+        ]),
+        (ReviewStage::Feedback(msg), _) => msg.clone(),
+        (ReviewStage::Prompt, Some(CardContent::Fragment { title, .. })) => format!(
+            "{}\n\n📜 \"{}\"\n\n{}",
+            progress, title, locale.fmt("review-reveal", &[("key", &actions.label(Action::Talk))])
+        ),
+        (ReviewStage::Revealed, Some(CardContent::Fragment { title, content })) => format!(
+            "{}\n\n📜 \"{}\"\n\n{}\n\n{}",
+            progress, title, content,
+            GRADE_NAMES.iter().enumerate()
+                .map(|(i, key)| format!("[{}] {}", choice(i), locale.t(key)))
+                .collect::<Vec<_>>()
+                .join("  ")
+        ),
+        (_, Some(CardContent::Quiz { question, options, .. })) => {
+            let opts = options.iter().enumerate()
+                .map(|(i, opt)| format!("[{}] {}", choice(i), opt))
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!("{}\n\n❓ {}\n\n{}", progress, question, opts)
+        }
+    };
+
+    for mut text in &mut query {
+        *text = Text::new(body.clone());
+    }
+}
+
+fn cleanup_review_session(
+    mut commands: Commands,
+    query: Query<Entity, With<ReviewScreenRoot>>,
+) {
+    for entity in &query {
+        commands.entity(entity).despawn_recursive();
+    }
+    commands.remove_resource::<ReviewSession>();
+}
@@ -1,5 +1,6 @@
 use bevy::prelude::*;
 use crate::audio::SfxEvent;
+use crate::i18n::Locale;
 
 // ============================================================================
 // XP & Scoring System — The Progression Engine
@@ -18,7 +19,6 @@ pub struct LevelUpEvent;
 pub struct PlayerScore {
     pub xp: u32,
     pub level: u32,
-    pub title: String,
     pub rooms_discovered: Vec<String>,
     pub fragments_collected: u32,
     pub puzzles_solved: u32,
@@ -29,7 +29,6 @@ impl Default for PlayerScore {
         Self {
             xp: 0,
             level: 1,
-            title: "Novice".to_string(),
             rooms_discovered: vec![],
             fragments_collected: 0,
             puzzles_solved: 0,
@@ -63,14 +62,16 @@ impl PlayerScore {
         (current as f32 / range as f32).clamp(0.0, 1.0)
     }
 
-    fn title_for_level(level: u32) -> &'static str {
-        match level {
-            1 => "Novice",
-            2 => "Student",
-            3 => "Architect",
-            4 => "Sovereign",
-            _ => "Grandmaster",
-        }
+    /// Rank name for the current level
+    pub fn title(&self, locale: &Locale) -> String {
+        let key = match self.level {
+            1 => "rank-novice",
+            2 => "rank-student",
+            3 => "rank-architect",
+            4 => "rank-sovereign",
+            _ => "rank-grandmaster",
+        };
+        locale.t(key)
     }
 }
 
@@ -138,14 +139,14 @@ fn setup_xp_hud(mut commands: Commands) {
             }
         ).with_children(|row| {
             row.spawn((
-                Text::new("⚡ Lv.1 Novice"),
+                Text::new(""),
                 TextFont { font_size: 13.0, ..default() },
                 TextColor(Color::srgb(1.0, 0.75, 0.0)),
                 XpLevelText,
             ));
 
             row.spawn((
-                Text::new("0 / 100 XP"),
+                Text::new(""),
                 TextFont { font_size: 11.0, ..default() },
                 TextColor(Color::srgb(0.6, 0.6, 0.6)),
                 XpValueText,
@@ -190,24 +191,24 @@ fn process_xp_gains(
         // Check level up
         while score.xp >= score.xp_for_next_level() {
             score.level += 1;
-            score.title = PlayerScore::title_for_level(score.level).to_string();
             
             level_up_writer.send(LevelUpEvent);
 
             sfx_writer.send(SfxEvent::LevelUp);
 
-            info!("🎉 LEVEL UP! Level {} — {}", score.level, score.title);
+            info!("🎉 LEVEL UP! Level {}", score.level);
         }
     }
 }
 
 fn update_xp_hud(
     score: Res<PlayerScore>,
+    locale: Res<Locale>,
     mut fill_query: Query<&mut Node, With<XpBarFill>>,
     mut level_text_query: Query<&mut Text, (With<XpLevelText>, Without<XpValueText>)>,
     mut value_text_query: Query<&mut Text, (With<XpValueText>, Without<XpLevelText>)>,
 ) {
-    if !score.is_changed() { return; }
+    if !score.is_changed() && !locale.is_changed() { return; }
 
     let progress = score.level_progress() * 100.0;
 
@@ -216,7 +217,7 @@ fn update_xp_hud(
     }
 
     for mut text in &mut level_text_query {
-        *text = Text::new(format!("⚡ Lv.{} {}", score.level, score.title));
+        *text = Text::new(locale.fmt("xp-level", &[("level", &score.level), ("title", &score.title(&locale))]));
     }
 
     for mut text in &mut value_text_query {
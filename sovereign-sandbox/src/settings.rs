@@ -0,0 +1,235 @@
+use bevy::prelude::*;
+use bevy::window::{MonitorSelection, PrimaryWindow, WindowMode};
+use serde::{Deserialize, Serialize};
+use crate::ai::conversation::ContextBudget;
+use crate::audio::Bus;
+
+// ============================================================================
+// Settings — audio, display and AI backend, persisted
+// ============================================================================
+// Loaded in `main` before the window is built, so a saved fullscreen or
+// resizable window opens that way. The pause menu edits the resource in place;
+// any change is applied to the window and saved, to `SETTINGS_PATH` natively
+// and to localStorage in the browser. Accessibility and key bindings keep
+// their own files (see `accessibility` and `actions`). The prompt's token
+// budget (`context`) is only set in the file.
+
+const SETTINGS_PATH: &str = "assets/memory/settings.json";
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY: &str = "sovereign-sandbox.settings";
+
+/// The window size the UI is laid out for
+pub const LAYOUT_SIZE: Vec2 = Vec2::new(1280.0, 720.0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum DisplayMode {
+    #[default]
+    Windowed,
+    /// Borderless, on the monitor the window is on
+    Fullscreen,
+}
+
+/// Who answers the teacher's prompts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum AiBackend {
+    /// Lesson text straight from the prompt; always available
+    #[default]
+    Lessons,
+    /// The local Moshi voice model, when it's loaded (native only)
+    Moshi,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AudioSettings {
+    pub master: f32,
+    pub music: f32,
+    pub sfx: f32,
+    pub voice: f32,
+    pub ambient: f32,
+    /// Buses silenced without losing their level
+    pub muted: Vec<Bus>,
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self { master: 1.0, music: 1.0, sfx: 1.0, voice: 1.0, ambient: 1.0, muted: Vec::new() }
+    }
+}
+
+impl AudioSettings {
+    /// The bus's own level, before master and mute
+    pub fn level(&self, bus: Bus) -> f32 {
+        match bus {
+            Bus::Music => self.music,
+            Bus::Sfx => self.sfx,
+            Bus::Voice => self.voice,
+            Bus::Ambient => self.ambient,
+        }
+    }
+
+    pub fn level_mut(&mut self, bus: Bus) -> &mut f32 {
+        match bus {
+            Bus::Music => &mut self.music,
+            Bus::Sfx => &mut self.sfx,
+            Bus::Voice => &mut self.voice,
+            Bus::Ambient => &mut self.ambient,
+        }
+    }
+
+    pub fn is_muted(&self, bus: Bus) -> bool {
+        self.muted.contains(&bus)
+    }
+
+    pub fn toggle_mute(&mut self, bus: Bus) {
+        if self.is_muted(bus) {
+            self.muted.retain(|b| *b != bus);
+        } else {
+            self.muted.push(bus);
+        }
+    }
+
+    /// Volume sounds on `bus` play at
+    pub fn volume(&self, bus: Bus) -> f32 {
+        if self.is_muted(bus) { 0.0 } else { self.master * self.level(bus) }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DisplaySettings {
+    pub mode: DisplayMode,
+    pub resizable: bool,
+    /// Grows and shrinks the UI with the window, against `LAYOUT_SIZE`
+    pub scale_ui_with_window: bool,
+}
+
+impl Default for DisplaySettings {
+    fn default() -> Self {
+        Self { mode: DisplayMode::Windowed, resizable: false, scale_ui_with_window: true }
+    }
+}
+
+impl DisplaySettings {
+    pub fn window_mode(&self) -> WindowMode {
+        match self.mode {
+            DisplayMode::Windowed => WindowMode::Windowed,
+            DisplayMode::Fullscreen => WindowMode::BorderlessFullscreen(MonitorSelection::Current),
+        }
+    }
+
+    /// How much bigger than the layout `window` is, or 1 when not scaling
+    pub fn ui_factor(&self, window: Option<&Window>) -> f32 {
+        window
+            .filter(|_| self.scale_ui_with_window)
+            .filter(|w| w.width() > 0.0 && w.height() > 0.0)
+            .map_or(1.0, |w| (w.width() / LAYOUT_SIZE.x).min(w.height() / LAYOUT_SIZE.y))
+    }
+}
+
+#[derive(Resource, Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct GameSettings {
+    pub audio: AudioSettings,
+    pub display: DisplaySettings,
+    pub ai_backend: AiBackend,
+    /// How the model's window is shared out in each prompt
+    pub context: ContextBudget,
+}
+
+impl GameSettings {
+    fn sanitized(mut self) -> Self {
+        self.audio.master = self.audio.master.clamp(0.0, 1.0);
+        for bus in Bus::ALL {
+            let level = self.audio.level_mut(bus);
+            *level = level.clamp(0.0, 1.0);
+        }
+        self.context = self.context.sanitized();
+        self
+    }
+
+    fn from_json(json: &str) -> Self {
+        match serde_json::from_str::<Self>(json) {
+            Ok(settings) => settings.sanitized(),
+            Err(e) => {
+                warn!("⚠️ Settings unreadable ({}), using defaults", e);
+                Self::default()
+            }
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn load() -> Self {
+        std::fs::read_to_string(SETTINGS_PATH).map(|json| Self::from_json(&json)).unwrap_or_default()
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn load() -> Self {
+        web_sys::window()
+            .and_then(|w| w.local_storage().ok().flatten())
+            .and_then(|storage| storage.get_item(STORAGE_KEY).ok().flatten())
+            .map(|json| Self::from_json(&json))
+            .unwrap_or_default()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn save(&self) {
+        let path = std::path::Path::new(SETTINGS_PATH);
+        let result = serde_json::to_string_pretty(self)
+            .map_err(std::io::Error::other)
+            .and_then(|json| {
+                if let Some(dir) = path.parent() {
+                    std::fs::create_dir_all(dir)?;
+                }
+                std::fs::write(path, json)
+            });
+        if let Err(e) = result {
+            error!("Failed to save settings: {}", e);
+        }
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    pub fn save(&self) {
+        let Ok(json) = serde_json::to_string(self) else { return };
+        let storage = web_sys::window().and_then(|w| w.local_storage().ok().flatten());
+        if storage.is_none_or(|s| s.set_item(STORAGE_KEY, &json).is_err()) {
+            error!("Failed to save settings to localStorage");
+        }
+    }
+}
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+/// Expects `GameSettings` to be inserted already (see `main`)
+pub struct SettingsPlugin;
+
+impl Plugin for SettingsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, (save_settings, apply_display));
+    }
+}
+
+fn save_settings(settings: Res<GameSettings>) {
+    if settings.is_changed() && !settings.is_added() {
+        settings.save();
+    }
+}
+
+fn apply_display(settings: Res<GameSettings>, mut windows: Query<&mut Window, With<PrimaryWindow>>) {
+    if !settings.is_changed() {
+        return;
+    }
+    let mode = settings.display.window_mode();
+    for mut window in &mut windows {
+        if window.mode != mode {
+            info!("🖥️ Display: {:?}", settings.display.mode);
+            window.mode = mode;
+        }
+        if window.resizable != settings.display.resizable {
+            window.resizable = settings.display.resizable;
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+
+// ============================================================================
+// SFX Synthesizer — sfxr-style procedural sound effects
+// ============================================================================
+// A sound is one oscillator (square, sawtooth, sine, triangle or noise) with an
+// attack/sustain/decay envelope, a pitch slide, an optional arpeggio jump and
+// white noise mixed in. Presets live in `assets/audio/sfx.toml`. Kept free of
+// ECS types so `examples/render_sfx.rs` can include it directly.
+
+pub const SAMPLE_RATE: u32 = 44_100;
+
+/// The presets built into the game
+pub const PRESETS: &str = include_str!("../assets/audio/sfx.toml");
+
+/// Samples per noise period; noise is held for 1/32 of a cycle, so `freq`
+/// still colours it
+const NOISE_STEPS: usize = 32;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Wave {
+    #[default]
+    Square,
+    Sawtooth,
+    Sine,
+    Triangle,
+    Noise,
+}
+
+/// One sound. Times are in seconds, slides in octaves per second.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct SfxParams {
+    pub wave: Wave,
+    /// Fraction of a square cycle spent high
+    pub duty: f32,
+    pub attack: f32,
+    pub sustain: f32,
+    /// Extra level at the start of the sustain, fading to 1 by its end
+    pub punch: f32,
+    pub decay: f32,
+    /// Starting pitch, in Hz
+    pub freq: f32,
+    /// Pitch change; negative slides down
+    pub slide: f32,
+    /// Change in `slide`, per second
+    pub delta_slide: f32,
+    /// Pitch never slides below this
+    pub min_freq: f32,
+    /// Pitch is multiplied by `arp_mult` once, `arp_time` in
+    pub arp_time: f32,
+    pub arp_mult: f32,
+    /// White noise mixed over the wave, 0 to 1
+    pub noise: f32,
+    pub volume: f32,
+}
+
+impl Default for SfxParams {
+    fn default() -> Self {
+        Self {
+            wave: Wave::Square,
+            duty: 0.5,
+            attack: 0.0,
+            sustain: 0.1,
+            punch: 0.0,
+            decay: 0.2,
+            freq: 440.0,
+            slide: 0.0,
+            delta_slide: 0.0,
+            min_freq: 20.0,
+            arp_time: 0.0,
+            arp_mult: 1.0,
+            noise: 0.0,
+            volume: 0.5,
+        }
+    }
+}
+
+impl SfxParams {
+    pub fn duration(&self) -> f32 {
+        self.attack.max(0.0) + self.sustain.max(0.0) + self.decay.max(0.0)
+    }
+
+    /// Envelope level `t` seconds in
+    fn envelope(&self, t: f32) -> f32 {
+        let (attack, sustain, decay) = (self.attack.max(0.0), self.sustain.max(0.0), self.decay.max(0.0));
+        if t < attack {
+            t / attack
+        } else if t < attack + sustain {
+            1.0 + self.punch * (1.0 - (t - attack) / sustain)
+        } else if decay > 0.0 {
+            (1.0 - (t - attack - sustain) / decay).max(0.0)
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Parses a preset table: one `[name]` section of `SfxParams` per sound
+pub fn parse_presets(toml: &str) -> Result<BTreeMap<String, SfxParams>, toml::de::Error> {
+    toml::from_str(toml)
+}
+
+/// Mono samples in -1..1 at `SAMPLE_RATE`. The same seed gives the same noise.
+pub fn render(params: &SfxParams, seed: u64) -> Vec<f32> {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let dt = 1.0 / SAMPLE_RATE as f32;
+    let len = (params.duration() * SAMPLE_RATE as f32) as usize;
+    let min_freq = params.min_freq.max(1.0);
+
+    let mut freq = params.freq.max(min_freq);
+    let mut slide = params.slide;
+    let mut arpeggiated = params.arp_time <= 0.0;
+    let mut phase = 0.0_f32;
+    let mut held: [f32; NOISE_STEPS] = std::array::from_fn(|_| rng.gen_range(-1.0..1.0));
+
+    let mut samples = Vec::with_capacity(len);
+    for i in 0..len {
+        let t = i as f32 * dt;
+        if !arpeggiated && t >= params.arp_time {
+            freq *= params.arp_mult;
+            arpeggiated = true;
+        }
+
+        let wave = match params.wave {
+            Wave::Square => if phase < params.duty { 1.0 } else { -1.0 },
+            Wave::Sawtooth => 1.0 - 2.0 * phase,
+            Wave::Sine => (std::f32::consts::TAU * phase).sin(),
+            Wave::Triangle => 1.0 - 4.0 * (phase - 0.5).abs(),
+            Wave::Noise => held[((phase * NOISE_STEPS as f32) as usize).min(NOISE_STEPS - 1)],
+        };
+        let white = rng.gen_range(-1.0..1.0);
+        let value = wave * (1.0 - params.noise) + white * params.noise;
+        samples.push((value * params.envelope(t) * params.volume).clamp(-1.0, 1.0));
+
+        phase += freq * dt;
+        if phase >= 1.0 {
+            phase = phase.fract();
+            if params.wave == Wave::Noise {
+                held = std::array::from_fn(|_| rng.gen_range(-1.0..1.0));
+            }
+        }
+        slide += params.delta_slide * dt;
+        freq = (freq * (slide * dt).exp2()).max(min_freq);
+    }
+    samples
+}
+
+/// 16-bit mono PCM WAV, as Bevy's `AudioSource` and any player read it
+pub fn to_wav(samples: &[f32]) -> Vec<u8> {
+    to_wav_at(samples, SAMPLE_RATE)
+}
+
+/// `to_wav` for samples at another rate
+pub fn to_wav_at(samples: &[f32], rate: u32) -> Vec<u8> {
+    let data_len = (samples.len() * 2) as u32;
+    let mut bytes = Vec::with_capacity(44 + data_len as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVEfmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes()); // PCM
+    bytes.extend_from_slice(&1u16.to_le_bytes()); // mono
+    bytes.extend_from_slice(&rate.to_le_bytes());
+    bytes.extend_from_slice(&(rate * 2).to_le_bytes());
+    bytes.extend_from_slice(&2u16.to_le_bytes());
+    bytes.extend_from_slice(&16u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+    for sample in samples {
+        bytes.extend_from_slice(&to_i16(*sample).to_le_bytes());
+    }
+    bytes
+}
+
+pub fn to_i16(sample: f32) -> i16 {
+    (sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16
+}
@@ -0,0 +1,198 @@
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+// ============================================================================
+// Spaced Repetition — SM-2 scheduling of review cards
+// ============================================================================
+// A card's ease and interval after each graded recall, and when it's next
+// due. "Now" always comes from the caller, through a `Clock` in the game, so
+// the schedule can be tested on a fixed one. The deck, its persistence and
+// the review session are in `review`.
+
+/// Source of "now" for the scheduler, so scheduling can be driven by a fake
+/// clock instead of the wall clock.
+pub trait Clock: Send + Sync {
+    fn now(&self) -> DateTime<Utc>;
+}
+
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub enum CardContent {
+    /// Recall the content of a knowledge fragment from its title (self-graded)
+    Fragment { title: String, content: String },
+    /// Re-answer a quiz question that was missed (auto-graded)
+    Quiz { question: String, options: Vec<String>, correct_index: usize },
+}
+
+impl CardContent {
+    pub fn id(&self) -> String {
+        match self {
+            CardContent::Fragment { title, .. } => format!("fragment:{}", title),
+            CardContent::Quiz { question, .. } => format!("quiz:{}", question),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewCard {
+    pub id: String,
+    pub content: CardContent,
+    pub ease: f32,
+    pub interval_days: u32,
+    pub repetitions: u32,
+    pub due: DateTime<Utc>,
+}
+
+impl ReviewCard {
+    pub fn new(content: CardContent, now: DateTime<Utc>) -> Self {
+        Self {
+            id: content.id(),
+            content,
+            ease: 2.5,
+            interval_days: 0,
+            repetitions: 0,
+            due: now,
+        }
+    }
+
+    /// SM-2 update for a recall graded 0 (blackout) to 5 (perfect)
+    pub fn schedule(&mut self, quality: u8, now: DateTime<Utc>) {
+        let q = quality.min(5) as f32;
+        if quality < 3 {
+            self.repetitions = 0;
+            self.interval_days = 1;
+        } else {
+            self.repetitions += 1;
+            self.interval_days = match self.repetitions {
+                1 => 1,
+                2 => 6,
+                _ => (self.interval_days as f32 * self.ease).round() as u32,
+            };
+        }
+        self.ease = (self.ease + 0.1 - (5.0 - q) * (0.08 + (5.0 - q) * 0.02)).max(1.3);
+        self.due = now + Duration::days(self.interval_days as i64);
+    }
+
+    pub fn is_due(&self, now: DateTime<Utc>) -> bool {
+        self.due <= now
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    // SM-2 on a fixed clock: intervals grow 1, 6, then by the ease; the ease
+    // follows recall quality down to its floor; a lapse starts the card over.
+    use super::*;
+
+    use chrono::{DateTime, Duration, TimeZone, Utc};
+
+    struct FixedClock(DateTime<Utc>);
+
+    impl Clock for FixedClock {
+        fn now(&self) -> DateTime<Utc> {
+            self.0
+        }
+    }
+
+    fn start() -> FixedClock {
+        FixedClock(Utc.with_ymd_and_hms(2026, 9, 1, 9, 0, 0).unwrap())
+    }
+
+    fn card(clock: &FixedClock) -> ReviewCard {
+        let content = CardContent::Quiz {
+            question: "What stops a loop?".to_string(),
+            options: vec!["Its exit condition".to_string(), "Nothing".to_string()],
+            correct_index: 0,
+        };
+        ReviewCard::new(content, clock.now())
+    }
+
+    /// Grades `card` with `quality` on the day it comes due, moving the clock there
+    fn review(card: &mut ReviewCard, clock: &mut FixedClock, quality: u8) {
+        clock.0 = card.due;
+        card.schedule(quality, clock.now());
+    }
+
+    #[test]
+    fn new_cards_are_due_at_once() {
+        let clock = start();
+        let card = card(&clock);
+        assert_eq!(card.id, "quiz:What stops a loop?");
+        assert_eq!((card.ease, card.interval_days, card.repetitions), (2.5, 0, 0));
+        assert!(card.is_due(clock.now()));
+    }
+
+    #[test]
+    fn intervals_grow_with_each_recall() {
+        let mut clock = start();
+        let mut card = card(&clock);
+        let mut intervals = Vec::new();
+        for _ in 0..4 {
+            review(&mut card, &mut clock, 4);
+            intervals.push(card.interval_days);
+            assert_eq!(card.due, clock.now() + Duration::days(card.interval_days as i64));
+            assert!(!card.is_due(clock.now()));
+            assert!(card.is_due(card.due));
+        }
+        // Quality 4 leaves the ease at 2.5: 6 × 2.5 = 15, 15 × 2.5 = 37.5
+        assert_eq!(intervals, vec![1, 6, 15, 38]);
+        assert_eq!(card.repetitions, 4);
+        assert!((card.ease - 2.5).abs() < 1e-6);
+    }
+
+    #[test]
+    fn ease_follows_quality() {
+        let mut clock = start();
+        let mut easy = card(&clock);
+        review(&mut easy, &mut clock, 5);
+        assert!((easy.ease - 2.6).abs() < 1e-6, "{}", easy.ease);
+
+        let mut clock = start();
+        let mut hard = card(&clock);
+        review(&mut hard, &mut clock, 3);
+        assert!((hard.ease - 2.36).abs() < 1e-6, "{}", hard.ease);
+        // Still a pass
+        assert_eq!((hard.repetitions, hard.interval_days), (1, 1));
+
+        // Grades above 5 count as 5
+        let mut clock = start();
+        let mut over = card(&clock);
+        review(&mut over, &mut clock, 9);
+        assert!((over.ease - 2.6).abs() < 1e-6, "{}", over.ease);
+    }
+
+    #[test]
+    fn lapses_start_over_and_ease_has_a_floor() {
+        let mut clock = start();
+        let mut card = card(&clock);
+        for _ in 0..3 {
+            review(&mut card, &mut clock, 5);
+        }
+        assert_eq!(card.repetitions, 3);
+        assert!(card.interval_days > 6);
+
+        review(&mut card, &mut clock, 2);
+        assert_eq!((card.repetitions, card.interval_days), (0, 1));
+        assert_eq!(card.due, clock.now() + Duration::days(1));
+        // 2.8 + 0.1 - 3 × (0.08 + 3 × 0.02)
+        assert!((card.ease - 2.48).abs() < 1e-5, "{}", card.ease);
+
+        // Relearning runs 1, 6 again
+        review(&mut card, &mut clock, 4);
+        review(&mut card, &mut clock, 4);
+        assert_eq!((card.repetitions, card.interval_days), (2, 6));
+
+        for _ in 0..10 {
+            review(&mut card, &mut clock, 0);
+        }
+        assert_eq!(card.ease, 1.3);
+        assert_eq!(card.interval_days, 1);
+    }
+}
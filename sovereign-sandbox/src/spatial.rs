@@ -0,0 +1,349 @@
+use bevy::math::{IVec2, Vec2};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+// ============================================================================
+// Spatial Indexing — static wall grid, A* and uniform-grid broadphase
+// ============================================================================
+// Kept free of ECS types so `benches/spatial_grid.rs` can include it directly.
+
+/// Static occupancy grid of solid tiles. A tile at (x, y) covers the square
+/// of side `tile_size` centred on `(x, y) * tile_size`.
+#[derive(Debug, Clone, Default)]
+pub struct TileGrid {
+    tile_size: f32,
+    origin: IVec2,
+    width: i32,
+    height: i32,
+    solid: Vec<bool>,
+}
+
+impl TileGrid {
+    /// Builds a grid just large enough to hold the given solid tiles.
+    pub fn from_tiles(tiles: impl IntoIterator<Item = IVec2>, tile_size: f32) -> Self {
+        let tiles: Vec<IVec2> = tiles.into_iter().collect();
+        let Some(first) = tiles.first() else {
+            return Self { tile_size, ..Default::default() };
+        };
+        let (min, max) = tiles.iter().fold((*first, *first), |(lo, hi), t| (lo.min(*t), hi.max(*t)));
+        let (width, height) = (max.x - min.x + 1, max.y - min.y + 1);
+
+        let mut grid = Self {
+            tile_size,
+            origin: min,
+            width,
+            height,
+            solid: vec![false; (width * height) as usize],
+        };
+        for tile in tiles {
+            if let Some(i) = grid.index(tile) {
+                grid.solid[i] = true;
+            }
+        }
+        grid
+    }
+
+    fn index(&self, tile: IVec2) -> Option<usize> {
+        let local = tile - self.origin;
+        if local.x < 0 || local.y < 0 || local.x >= self.width || local.y >= self.height {
+            return None;
+        }
+        Some((local.y * self.width + local.x) as usize)
+    }
+
+    pub fn is_solid(&self, tile: IVec2) -> bool {
+        self.index(tile).is_some_and(|i| self.solid[i])
+    }
+
+    pub fn solid_count(&self) -> usize {
+        self.solid.iter().filter(|s| **s).count()
+    }
+
+    pub fn tile_size(&self) -> f32 {
+        self.tile_size
+    }
+
+    /// The tile whose square contains a world position
+    pub fn world_to_tile(&self, world: Vec2) -> IVec2 {
+        (world / self.tile_size).round().as_ivec2()
+    }
+
+    pub fn tile_to_world(&self, tile: IVec2) -> Vec2 {
+        tile.as_vec2() * self.tile_size
+    }
+
+    pub fn solid_tiles(&self) -> impl Iterator<Item = IVec2> + '_ {
+        (0..self.solid.len())
+            .filter(|i| self.solid[*i])
+            .map(|i| self.origin + IVec2::new(i as i32 % self.width, i as i32 / self.width))
+    }
+
+    /// Shortest 4-connected walk from `start` to `goal` (both included),
+    /// searching one tile beyond the grid's bounds. The goal may itself be
+    /// solid, so paths can lead up to props that block their own tile.
+    pub fn find_path(&self, start: IVec2, goal: IVec2) -> Option<Vec<IVec2>> {
+        let lo = self.origin - IVec2::ONE;
+        let hi = self.origin + IVec2::new(self.width, self.height);
+        let in_bounds = |t: IVec2| t.x >= lo.x && t.y >= lo.y && t.x <= hi.x && t.y <= hi.y;
+        if !in_bounds(start) || !in_bounds(goal) { return None; }
+
+        let heuristic = |t: IVec2| (goal - t).abs().element_sum();
+        let mut open = BinaryHeap::new();
+        let mut came_from: HashMap<IVec2, IVec2> = HashMap::new();
+        let mut cost: HashMap<IVec2, i32> = HashMap::from([(start, 0)]);
+        open.push(Reverse((heuristic(start), start.x, start.y)));
+
+        while let Some(Reverse((_, x, y))) = open.pop() {
+            let current = IVec2::new(x, y);
+            if current == goal {
+                let mut path = vec![goal];
+                while let Some(prev) = came_from.get(path.last()?) {
+                    path.push(*prev);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            let next_cost = cost[&current] + 1;
+            for step in [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y] {
+                let next = current + step;
+                if !in_bounds(next) || (next != goal && self.is_solid(next)) { continue; }
+                if cost.get(&next).is_some_and(|c| *c <= next_cost) { continue; }
+                cost.insert(next, next_cost);
+                came_from.insert(next, current);
+                open.push(Reverse((next_cost + heuristic(next), next.x, next.y)));
+            }
+        }
+        None
+    }
+
+    /// Does an axis-aligned box (centre, half extents) overlap any solid tile?
+    /// Touching edges do not count, matching the old per-wall AABB test.
+    pub fn overlaps(&self, center: Vec2, half: Vec2) -> bool {
+        if self.tile_size <= 0.0 { return false; }
+        let half_tile = self.tile_size / 2.0;
+        let lo = ((center - half + half_tile) / self.tile_size).floor().as_ivec2();
+        let hi = ((center + half + half_tile) / self.tile_size).ceil().as_ivec2() - IVec2::ONE;
+
+        for y in lo.y..=hi.y {
+            for x in lo.x..=hi.x {
+                let tile = IVec2::new(x, y);
+                if !self.is_solid(tile) { continue; }
+                let tile_center = tile.as_vec2() * self.tile_size;
+                let d = (center - tile_center).abs();
+                if d.x < half.x + half_tile && d.y < half.y + half_tile {
+                    return true;
+                }
+            }
+        }
+        false
+    }
+}
+
+/// Uniform-grid broadphase for moving circles. Rebuilt every frame; queries
+/// return only entries whose circles overlap the query circle.
+#[derive(Debug, Clone)]
+pub struct SpatialHash<T: Copy> {
+    cell_size: f32,
+    cells: HashMap<IVec2, Vec<(T, Vec2, f32)>>,
+    /// Largest radius inserted, so queries know how far to look
+    max_radius: f32,
+}
+
+impl<T: Copy> SpatialHash<T> {
+    pub fn new(cell_size: f32) -> Self {
+        Self { cell_size, cells: HashMap::new(), max_radius: 0.0 }
+    }
+
+    fn cell(&self, pos: Vec2) -> IVec2 {
+        (pos / self.cell_size).floor().as_ivec2()
+    }
+
+    pub fn clear(&mut self) {
+        // Keep the allocations around for next frame
+        for bucket in self.cells.values_mut() {
+            bucket.clear();
+        }
+        self.max_radius = 0.0;
+    }
+
+    pub fn insert(&mut self, item: T, pos: Vec2, radius: f32) {
+        let cell = self.cell(pos);
+        self.cells.entry(cell).or_default().push((item, pos, radius));
+        self.max_radius = self.max_radius.max(radius);
+    }
+
+    /// Items whose circles overlap the circle at `pos` with `radius`
+    pub fn query(&self, pos: Vec2, radius: f32) -> Vec<T> {
+        let reach = radius + self.max_radius;
+        let lo = self.cell(pos - Vec2::splat(reach));
+        let hi = self.cell(pos + Vec2::splat(reach));
+        let mut found = Vec::new();
+        for y in lo.y..=hi.y {
+            for x in lo.x..=hi.x {
+                let Some(bucket) = self.cells.get(&IVec2::new(x, y)) else { continue };
+                for (item, p, r) in bucket {
+                    if p.distance(pos) < radius + r {
+                        found.push(*item);
+                    }
+                }
+            }
+        }
+        found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TILE: f32 = 64.0;
+    const HALF: Vec2 = Vec2::new(16.0, 16.0);
+
+    #[test]
+    fn boxes_touching_a_wall_do_not_overlap_it() {
+        let grid = TileGrid::from_tiles([IVec2::ZERO], TILE);
+        // The tile spans -32..32; the box is 16 either side of its centre
+        for dir in [Vec2::X, Vec2::NEG_X, Vec2::Y, Vec2::NEG_Y] {
+            assert!(!grid.overlaps(dir * 48.0, HALF), "touching on {}", dir);
+            assert!(grid.overlaps(dir * 47.9, HALF), "overlapping on {}", dir);
+        }
+        // Corner to corner
+        assert!(!grid.overlaps(Vec2::splat(48.0), HALF));
+        assert!(grid.overlaps(Vec2::splat(47.0), HALF));
+        // Inside, and far away
+        assert!(grid.overlaps(Vec2::ZERO, HALF));
+        assert!(!grid.overlaps(Vec2::new(500.0, 0.0), HALF));
+        assert!(!TileGrid::default().overlaps(Vec2::ZERO, HALF));
+    }
+
+    #[test]
+    fn boxes_spanning_several_tiles() {
+        // A wall with a one-tile gap at x = 0
+        let grid = TileGrid::from_tiles([IVec2::new(-1, 0), IVec2::new(1, 0)], TILE);
+        assert_eq!(grid.solid_count(), 2);
+        assert!(!grid.is_solid(IVec2::ZERO));
+        assert!(!grid.overlaps(Vec2::ZERO, HALF), "fits through the gap");
+        assert!(grid.overlaps(Vec2::ZERO, Vec2::new(33.0, 16.0)), "too wide for the gap");
+        assert!(!grid.overlaps(Vec2::ZERO, Vec2::new(32.0, 16.0)), "exactly as wide as the gap");
+    }
+
+    #[test]
+    fn negative_coordinates() {
+        let wall = IVec2::new(-3, -2);
+        let grid = TileGrid::from_tiles([wall, IVec2::new(-5, -7)], TILE);
+        assert!(grid.is_solid(wall) && !grid.is_solid(IVec2::new(-4, -2)));
+        assert_eq!(grid.world_to_tile(Vec2::new(-192.0, -128.0)), wall);
+        assert_eq!(grid.world_to_tile(Vec2::new(-31.0, -33.0)), IVec2::new(0, -1));
+        assert_eq!(grid.tile_to_world(wall), Vec2::new(-192.0, -128.0));
+
+        let center = grid.tile_to_world(wall);
+        assert!(grid.overlaps(center, HALF));
+        assert!(!grid.overlaps(center + Vec2::new(48.0, 0.0), HALF));
+        assert!(grid.overlaps(center + Vec2::new(-47.0, 47.0), HALF));
+        assert!(!grid.overlaps(center + Vec2::new(0.0, -48.0), HALF));
+
+        let mut tiles: Vec<IVec2> = grid.solid_tiles().collect();
+        tiles.sort_by_key(|t| (t.x, t.y));
+        assert_eq!(tiles, vec![IVec2::new(-5, -7), wall]);
+    }
+
+    fn sorted(mut found: Vec<u32>) -> Vec<u32> {
+        found.sort();
+        found
+    }
+
+    #[test]
+    fn hash_queries_cross_cells() {
+        let mut hash = SpatialHash::new(TILE);
+        hash.insert(1, Vec2::new(63.0, 10.0), 10.0);
+        hash.insert(2, Vec2::new(65.0, 10.0), 10.0);
+        hash.insert(3, Vec2::new(64.0, 200.0), 10.0);
+
+        // Either side of the x = 64 cell boundary
+        assert_eq!(sorted(hash.query(Vec2::new(60.0, 10.0), 5.0)), vec![1, 2]);
+        assert_eq!(sorted(hash.query(Vec2::new(70.0, 0.0), 5.0)), vec![1, 2]);
+        // Circles that only touch don't count
+        assert_eq!(hash.query(Vec2::new(64.0, 230.0), 20.0), Vec::<u32>::new());
+        assert_eq!(hash.query(Vec2::new(64.0, 229.0), 20.0), vec![3]);
+
+        // A big circle reaches queries several cells away from its own
+        hash.insert(4, Vec2::new(-300.0, 10.0), 200.0);
+        assert_eq!(hash.query(Vec2::new(-110.0, 10.0), 1.0), vec![4]);
+
+        hash.clear();
+        assert!(hash.query(Vec2::new(60.0, 10.0), 50.0).is_empty());
+    }
+
+    #[test]
+    fn hash_negative_coordinates() {
+        let mut hash = SpatialHash::new(TILE);
+        hash.insert(1, Vec2::new(-1.0, -1.0), 2.0);
+        hash.insert(2, Vec2::new(-128.0, -64.0), 8.0);
+        // (-1, -1) is in cell (-1, -1), the query point in cell (0, 0)
+        assert_eq!(hash.query(Vec2::new(1.0, 1.0), 2.0), vec![1]);
+        assert_eq!(hash.query(Vec2::new(-120.0, -60.0), 4.0), vec![2]);
+        assert!(hash.query(Vec2::new(-120.0, -60.0), 0.5).is_empty());
+    }
+
+
+    /// Steps of one tile, none through a wall except possibly the goal
+    fn assert_walkable(grid: &TileGrid, path: &[IVec2]) {
+        for pair in path.windows(2) {
+            assert_eq!((pair[1] - pair[0]).abs().element_sum(), 1, "{:?}", path);
+        }
+        for tile in &path[..path.len() - 1] {
+            assert!(!grid.is_solid(*tile), "through the wall at {} in {:?}", tile, path);
+        }
+    }
+
+    #[test]
+    fn path_to_where_you_stand() {
+        let grid = TileGrid::from_tiles([IVec2::new(3, 3)], TILE);
+        assert_eq!(grid.find_path(IVec2::new(2, 3), IVec2::new(2, 3)), Some(vec![IVec2::new(2, 3)]));
+        // Straight across open floor
+        let path = grid.find_path(IVec2::new(2, 2), IVec2::new(4, 2)).unwrap();
+        assert_eq!(path, vec![IVec2::new(2, 2), IVec2::new(3, 2), IVec2::new(4, 2)]);
+    }
+
+    #[test]
+    fn shortest_path_around_a_wall() {
+        // A wall at x = 0 from y = -2 to 2, inside a larger grid
+        let mut walls: Vec<IVec2> = (-2..=2).map(|y| IVec2::new(0, y)).collect();
+        walls.extend([IVec2::new(-6, -6), IVec2::new(6, 6)]);
+        let grid = TileGrid::from_tiles(walls, TILE);
+
+        let (start, goal) = (IVec2::new(-2, 0), IVec2::new(2, 0));
+        let path = grid.find_path(start, goal).unwrap();
+        assert_eq!((path[0], *path.last().unwrap()), (start, goal));
+        assert_walkable(&grid, &path);
+        // Up to y = ±3, across and back: 3 + 4 + 3 steps
+        assert_eq!(path.len(), 11, "{:?}", path);
+        assert!(path.iter().any(|t| t.y.abs() == 3));
+
+        // The same walk backwards is just as short
+        assert_eq!(grid.find_path(goal, start).unwrap().len(), 11);
+    }
+
+    #[test]
+    fn solid_goals_can_be_walked_up_to() {
+        let terminal = IVec2::new(4, 0);
+        let grid = TileGrid::from_tiles([terminal, IVec2::new(-1, -1)], TILE);
+        let path = grid.find_path(IVec2::ZERO, terminal).unwrap();
+        assert_eq!(path.len(), 5);
+        assert_eq!(path.last(), Some(&terminal));
+        assert_walkable(&grid, &path);
+    }
+
+    #[test]
+    fn unreachable_goals_have_no_path() {
+        // A walled-in tile at the origin
+        let ring = [IVec2::X, IVec2::NEG_X, IVec2::Y, IVec2::NEG_Y, IVec2::new(5, 5), IVec2::new(-5, -5)];
+        let grid = TileGrid::from_tiles(ring, TILE);
+        assert_eq!(grid.find_path(IVec2::new(3, 0), IVec2::ZERO), None);
+        assert_eq!(grid.find_path(IVec2::ZERO, IVec2::new(3, 0)), None);
+        // Beyond the edge of the searched area
+        assert_eq!(grid.find_path(IVec2::new(3, 0), IVec2::new(40, 0)), None);
+        assert_eq!(grid.find_path(IVec2::new(-40, 0), IVec2::new(3, 0)), None);
+        assert_eq!(TileGrid::default().find_path(IVec2::new(1, 0), IVec2::ZERO), None);
+    }
+}
@@ -0,0 +1,331 @@
+use bevy::prelude::*;
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use crate::actions::{Action, Actions, InputContext, VirtualInput, VirtualInputSet};
+use crate::ai::TurnEvent;
+use crate::ai::conversation::Turn;
+use crate::ai::memory::MemoryStoreResource;
+use crate::i18n::Locale;
+use crate::narration::Narrated;
+use crate::story_mode::StoryState;
+use crate::syllabus::{QuestPhase, SyllabusResource};
+use crate::voice_commands::{CommandLine, CommandText};
+
+// ============================================================================
+// Speech Input — hold to talk, transcribed offline
+// ============================================================================
+// Holding Speak records from the microphone; letting go hands the recording
+// to a worker thread running Whisper (see `ai::stt`) in the selected
+// language. What it heard arrives as a `SpokenText` event and goes to
+// whoever is listening:
+//   * the typing prompt or the command line — appended to what's typed
+//   * a reflection question — the answer is kept in memory and the reflection
+//     confirmed, as Interact would
+//   * anything else — a command (see `voice_commands`): "option two", "open
+//     the compass", "take me to the archive"...
+// The model and microphone open on the worker, so startup doesn't wait for
+// them. Without either, or in the browser, Speak says speech isn't available.
+// `SANDBOX_SPEECH_WAV=<file>` makes every Speak hear that file instead of the
+// microphone, for trying the routing without one.
+
+/// Whether this build can listen at all
+pub const AVAILABLE: bool = cfg!(not(target_arch = "wasm32"));
+
+/// How long what was heard stays on screen
+const CAPTION_SECS: f32 = 3.0;
+
+/// Something the player said
+#[derive(Event, Debug, Clone)]
+pub struct SpokenText(pub String);
+
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+enum SpeechCommand {
+    Start,
+    Stop { language: &'static str },
+}
+
+#[cfg_attr(target_arch = "wasm32", allow(dead_code))]
+enum SpeechReply {
+    Heard(String),
+    Failed(String),
+    Unavailable,
+}
+
+#[derive(Resource)]
+struct SpeechWorker {
+    commands: Sender<SpeechCommand>,
+    replies: Receiver<SpeechReply>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Listening {
+    #[default]
+    Idle,
+    Recording,
+    Transcribing,
+}
+
+#[derive(Resource, Default)]
+struct SpeechStatus {
+    listening: Listening,
+    /// Message id and text shown after listening
+    caption: Option<(&'static str, String)>,
+    caption_timer: Timer,
+}
+
+impl SpeechStatus {
+    fn show(&mut self, key: &'static str, text: String) {
+        self.caption = Some((key, text));
+        self.caption_timer = Timer::from_seconds(CAPTION_SECS, TimerMode::Once);
+    }
+}
+
+#[derive(Component)]
+struct SpeechIndicator;
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub struct SpeechPlugin;
+
+impl Plugin for SpeechPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<SpokenText>()
+           .init_resource::<SpeechStatus>()
+           .add_systems(Startup, (spawn_worker, spawn_indicator))
+           .add_systems(PreUpdate, route_spoken_text.in_set(VirtualInputSet))
+           .add_systems(Update, (push_to_talk, receive_transcripts, update_indicator).chain());
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn spawn_worker(mut commands: Commands) {
+    let (command_tx, command_rx) = unbounded();
+    let (reply_tx, reply_rx) = unbounded();
+    let spawned = std::thread::Builder::new()
+        .name("speech".into())
+        .spawn(move || run_worker(command_rx, reply_tx));
+    match spawned {
+        Ok(_) => commands.insert_resource(SpeechWorker { commands: command_tx, replies: reply_rx }),
+        Err(e) => error!("Failed to start the speech worker: {}", e),
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn spawn_worker() {}
+
+/// Where the worker's audio comes from
+#[cfg(not(target_arch = "wasm32"))]
+enum Listener {
+    Microphone(crate::ai::microphone::Microphone),
+    File(std::path::PathBuf),
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Listener {
+    fn open() -> anyhow::Result<Self> {
+        match std::env::var_os("SANDBOX_SPEECH_WAV") {
+            Some(path) => Ok(Self::File(path.into())),
+            None => crate::ai::microphone::Microphone::open().map(Self::Microphone),
+        }
+    }
+
+    fn start(&self) -> anyhow::Result<()> {
+        match self {
+            Self::Microphone(microphone) => microphone.start(),
+            Self::File(_) => Ok(()),
+        }
+    }
+
+    /// What was heard since `start`, at 16 kHz
+    fn stop(&self) -> anyhow::Result<Vec<f32>> {
+        match self {
+            Self::Microphone(microphone) => microphone.stop(),
+            Self::File(path) => crate::ai::stt::load_wav(path),
+        }
+    }
+}
+
+/// Owns the audio source and the model; transcribes each recording as it ends
+#[cfg(not(target_arch = "wasm32"))]
+fn run_worker(commands: Receiver<SpeechCommand>, replies: Sender<SpeechReply>) {
+    use crate::ai::stt;
+
+    let mut transcriber = match stt::Transcriber::load(std::path::Path::new(stt::MODEL_DIR)) {
+        Ok(transcriber) => {
+            info!("🎙️ Whisper loaded from {}", stt::MODEL_DIR);
+            Some(transcriber)
+        }
+        Err(e) => {
+            warn!("⚠️ Speech input unavailable, no Whisper model: {:#}", e);
+            None
+        }
+    };
+    let source = Listener::open()
+        .inspect_err(|e| warn!("⚠️ Speech input unavailable: {:#}", e))
+        .ok();
+
+    for command in commands {
+        let (Some(source), Some(transcriber)) = (&source, &mut transcriber) else {
+            if matches!(command, SpeechCommand::Stop { .. }) && replies.send(SpeechReply::Unavailable).is_err() {
+                return;
+            }
+            continue;
+        };
+        let reply = match command {
+            SpeechCommand::Start => match source.start() {
+                Ok(()) => continue,
+                Err(e) => SpeechReply::Failed(e.to_string()),
+            },
+            SpeechCommand::Stop { language } => match source.stop().and_then(|pcm| transcriber.transcribe(&pcm, language)) {
+                Ok(text) => SpeechReply::Heard(text),
+                Err(e) => SpeechReply::Failed(e.to_string()),
+            },
+        };
+        if replies.send(reply).is_err() {
+            return;
+        }
+    }
+}
+
+// ============================================================================
+// Listening
+// ============================================================================
+
+fn push_to_talk(
+    actions: Res<Actions>,
+    locale: Res<Locale>,
+    worker: Option<Res<SpeechWorker>>,
+    mut status: ResMut<SpeechStatus>,
+) {
+    if actions.just_pressed(Action::Speak) && status.listening == Listening::Idle {
+        match worker.as_ref().filter(|w| w.commands.send(SpeechCommand::Start).is_ok()) {
+            Some(_) => status.listening = Listening::Recording,
+            None => status.show("speech-unavailable", String::new()),
+        }
+    } else if status.listening == Listening::Recording && !actions.pressed(Action::Speak) {
+        let language = locale.language().code();
+        let sent = worker.is_some_and(|w| w.commands.send(SpeechCommand::Stop { language }).is_ok());
+        status.listening = if sent { Listening::Transcribing } else { Listening::Idle };
+    }
+}
+
+fn receive_transcripts(
+    worker: Option<Res<SpeechWorker>>,
+    mut status: ResMut<SpeechStatus>,
+    mut spoken: EventWriter<SpokenText>,
+) {
+    let Some(worker) = worker else { return };
+    while let Ok(reply) = worker.replies.try_recv() {
+        status.listening = Listening::Idle;
+        match reply {
+            SpeechReply::Heard(text) if !text.trim().is_empty() => {
+                info!("🎙️ Heard: {}", text);
+                status.show("speech-heard", text.clone());
+                spoken.send(SpokenText(text));
+            }
+            SpeechReply::Heard(_) => status.show("speech-not-heard", String::new()),
+            SpeechReply::Failed(e) => {
+                warn!("⚠️ Speech input failed: {}", e);
+                status.show("speech-not-heard", String::new());
+            }
+            SpeechReply::Unavailable => status.show("speech-unavailable", String::new()),
+        }
+    }
+}
+
+/// Hands what was said to whoever is taking input
+fn route_spoken_text(
+    mut spoken: EventReader<SpokenText>,
+    context: Res<InputContext>,
+    (mut story_state, mut command_line): (ResMut<StoryState>, ResMut<CommandLine>),
+    syllabus: Option<Res<SyllabusResource>>,
+    memory_store: Option<Res<MemoryStoreResource>>,
+    mut input: ResMut<VirtualInput>,
+    (mut commands, mut turns): (EventWriter<CommandText>, EventWriter<TurnEvent>),
+) {
+    for SpokenText(text) in spoken.read() {
+        if *context == InputContext::TextEntry {
+            let typed = if command_line.open { &mut command_line.input } else { &mut story_state.player_input };
+            if !typed.is_empty() {
+                typed.push(' ');
+            }
+            typed.push_str(text.trim());
+            continue;
+        }
+
+        let reflecting = *context == InputContext::World && syllabus.as_ref().is_some_and(|syl| {
+            matches!(syl.current_phase(), QuestPhase::Reflection { .. })
+                && story_state.nearby_npc.as_ref().is_some_and(|npc| npc.id == syl.quest_script.current_npc())
+        });
+        if reflecting {
+            if let Some(store) = memory_store.as_ref() {
+                if let Err(e) = store.0.store(text, Some("Reflection"), None, None) {
+                    warn!("⚠️ Failed to keep reflection answer: {}", e);
+                }
+            }
+            turns.send(TurnEvent(Turn::new(story_state.player_name.clone(), text.clone())));
+            input.just_pressed.insert(Action::Interact);
+        } else {
+            commands.send(CommandText(text.clone()));
+        }
+    }
+}
+
+// ============================================================================
+// Indicator — top centre, while listening and just after
+// ============================================================================
+
+fn spawn_indicator(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(12.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        PickingBehavior::IGNORE,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(""),
+            TextFont { font_size: 16.0, ..default() },
+            TextColor(Color::srgb(1.0, 0.75, 0.0)),
+            Node { padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)), ..default() },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.05, 0.8)),
+            Visibility::Hidden,
+            Narrated::default(),
+            SpeechIndicator,
+        ));
+    });
+}
+
+fn update_indicator(
+    time: Res<Time<Real>>,
+    locale: Res<Locale>,
+    mut status: ResMut<SpeechStatus>,
+    mut indicator: Query<(&mut Text, &mut Visibility), With<SpeechIndicator>>,
+) {
+    let status = &mut *status;
+    if status.caption_timer.tick(time.delta()).just_finished() {
+        status.caption = None;
+    }
+    let line = match (status.listening, &status.caption) {
+        (Listening::Recording, _) => Some(locale.t("speech-listening")),
+        (Listening::Transcribing, _) => Some(locale.t("speech-transcribing")),
+        (Listening::Idle, Some((key, text))) => Some(locale.fmt(key, &[("text", text)])),
+        (Listening::Idle, None) => None,
+    };
+
+    for (mut text, mut visibility) in &mut indicator {
+        let shown = if line.is_some() { Visibility::Inherited } else { Visibility::Hidden };
+        if *visibility != shown {
+            *visibility = shown;
+        }
+        if let Some(line) = &line {
+            if text.0 != *line {
+                text.0 = line.clone();
+            }
+        }
+    }
+}
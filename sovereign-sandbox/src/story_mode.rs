@@ -1,6 +1,12 @@
 use bevy::prelude::*;
-use crate::ai::{AiChannel, AiRequest};
+use crate::ai::conversation::Turn;
+use crate::ai::{AiChannel, AiRequest, TurnEvent};
 use crate::syllabus::SyllabusResource;
+use crate::actions::{Action, Actions, InputContext, MAX_CHOICES};
+use crate::accessibility::AccessibilitySettings;
+use crate::audio::SfxEvent;
+use crate::i18n::{localized, Locale};
+use crate::teacher_voice::VoiceLineEvent;
 
 // ============================================================================
 // Story Mode - LitRPG Narrative System
@@ -12,9 +18,10 @@ pub struct StoryState {
     pub current_chapter: usize,
     #[allow(dead_code)]
     pub player_name: String,
-    pub narrative_context: Vec<String>,
     pub active_dialogue: Option<DialogueNode>,
     pub can_interact: bool,
+    /// The closest NPC within interaction range
+    pub nearby_npc: Option<crate::npc::Npc>,
     pub is_thinking: bool,
     pub player_input: String,
     pub is_typing_prompt: bool,
@@ -25,9 +32,9 @@ impl Default for StoryState {
         Self {
             current_chapter: 1,
             player_name: "Architect".to_string(),
-            narrative_context: vec![],
             active_dialogue: None,
             can_interact: false,
+            nearby_npc: None,
             is_thinking: false,
             player_input: String::new(),
             is_typing_prompt: false,
@@ -35,19 +42,14 @@ impl Default for StoryState {
     }
 }
 
-#[derive(Clone)]
+/// The scripted line on screen, with any options offered after it
+/// (see `crate::dialogue`)
+#[derive(Clone, Default)]
 pub struct DialogueNode {
-    #[allow(dead_code)]
+    /// Empty for narration
     pub speaker: String,
-    #[allow(dead_code)]
-    pub text: String,
-    pub choices: Vec<DialogueChoice>,
-}
-
-#[derive(Clone)]
-pub struct DialogueChoice {
     pub text: String,
-    pub consequence: String,
+    pub choices: Vec<String>,
 }
 
 // ============================================================================
@@ -60,6 +62,9 @@ pub struct TypewriterState {
     pub revealed_chars: usize,
     pub timer: Timer,
     pub is_active: bool,
+    /// How far the teacher's voice has got while it reads the line out; the
+    /// reveal keeps pace with it instead of the timer (see `teacher_voice`)
+    pub voiced_chars: Option<usize>,
 }
 
 impl Default for TypewriterState {
@@ -69,6 +74,7 @@ impl Default for TypewriterState {
             revealed_chars: 0,
             timer: Timer::from_seconds(0.03, TimerMode::Repeating),
             is_active: false,
+            voiced_chars: None,
         }
     }
 }
@@ -83,6 +89,10 @@ pub struct DialogueBox;
 #[derive(Component)]
 pub struct NarrativeText;
 
+/// Dialogue box header naming who is talking
+#[derive(Component)]
+struct SpeakerName;
+
 // ============================================================================
 // Plugin
 // ============================================================================
@@ -96,23 +106,23 @@ impl Plugin for StoryModePlugin {
            .add_systems(Startup, setup_story_ui)
            .add_systems(Update, (
                generate_dynamic_dialogue,
-               handle_dialogue_choices,
                handle_quiz_input,
                handle_typing_input,
                update_narrative_display,
                typewriter_tick,
+               update_speaker_name,
            ));
     }
 }
 
-fn setup_story_ui(mut commands: Commands) {
+fn setup_story_ui(mut commands: Commands, actions: Res<Actions>, locale: Res<Locale>) {
     commands.spawn((
         Node {
             position_type: PositionType::Absolute,
             bottom: Val::Px(12.0),
             left: Val::Percent(10.0),
             right: Val::Percent(10.0),
-            max_height: Val::Px(180.0),
+            max_height: Val::Px(280.0),
             padding: UiRect::new(Val::Px(20.0), Val::Px(20.0), Val::Px(14.0), Val::Px(14.0)),
             border: UiRect::all(Val::Px(2.0)),
             flex_direction: FlexDirection::Column,
@@ -126,20 +136,26 @@ fn setup_story_ui(mut commands: Commands) {
     ))
     .with_children(|parent| {
         parent.spawn((
-            Text::new("🧙 The Gamification Architect"),
+            localized("story-default-speaker"),
             TextFont { font_size: 15.0, ..default() },
             TextColor(Color::srgb(0.0, 1.0, 1.0)),
+            SpeakerName,
         ));
 
         parent.spawn((
-            Text::new("Walk to the Teacher to begin your quest..."),
+            localized("story-intro"),
             TextFont { font_size: 14.0, ..default() },
             TextColor(Color::srgb(0.9, 0.9, 0.9)),
             NarrativeText,
         ));
 
         parent.spawn((
-            Text::new("[WASD] Move  [T] Interact  [SPACE] Dialogue  [1-3] Choices"),
+            Text::new(locale.fmt("story-controls", &[
+                ("interact", &actions.label(Action::Interact)),
+                ("talk", &actions.label(Action::Talk)),
+                ("first", &actions.label(Action::Choice(0))),
+                ("last", &actions.label(Action::Choice(MAX_CHOICES - 1))),
+            ])),
             TextFont { font_size: 11.0, ..default() },
             TextColor(Color::srgb(0.45, 0.45, 0.45)),
         ));
@@ -147,14 +163,29 @@ fn setup_story_ui(mut commands: Commands) {
 }
 
 fn generate_dynamic_dialogue(
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<Actions>,
     mut story_state: ResMut<StoryState>,
+    mut typewriter: ResMut<TypewriterState>,
     ai_channel: Res<AiChannel>,
     syllabus: Option<Res<SyllabusResource>>,
+    locale: Res<Locale>,
 ) {
-    if keys.just_pressed(KeyCode::Space) && story_state.active_dialogue.is_none() && !story_state.is_typing_prompt {
+    if actions.just_pressed(Action::Talk) && story_state.active_dialogue.is_none() && !story_state.is_typing_prompt {
+        // The first press finishes the line, the next asks for another
+        if typewriter.is_active {
+            typewriter.revealed_chars = typewriter.full_text.len();
+            return;
+        }
+
+        // NPCs with an authored script are handled by `crate::dialogue`
+        if story_state.nearby_npc.as_ref().is_some_and(|npc| npc.script.is_some()) { return; }
+
         // Specific quest phase handling is now data-driven via the Syllabus
 
+        let persona = story_state.nearby_npc.as_ref()
+            .map(|npc| npc.persona.clone())
+            .unwrap_or_else(|| "You are The Gamification Architect, a wise mentor in a LitRPG world.".to_string());
+
         let context = if let Some(syl) = syllabus {
             if let Some(quest) = syl.current_quest() {
                 let phase = syl.current_phase();
@@ -162,28 +193,28 @@ fn generate_dynamic_dialogue(
                 // Phase 2 Constructivism mechanic
                 if syl.current_module_index == 0 && syl.quest_script.current_phase == 2 && !story_state.is_typing_prompt {
                     story_state.is_typing_prompt = true;
-                    let text = "Architect! It is time to construct your first logic script. Type a natural language command below and press Enter:".to_string();
+                    let text = locale.fmt("story-logic-prompt", &[("key", &actions.label(Action::Confirm))]);
                     let _ = ai_channel.sender.send(AiRequest::Text(text));
                     story_state.is_thinking = true;
                     return;
                 }
 
                 format!(
-                    "You are The Gamification Architect, a wise mentor in a LitRPG world. \
+                    "{} \
                     The player is on quest '{}', currently in phase [{}]: {}. \
                     Generate a short, encouraging dialogue. \
-                    STRICT RULES: Reply in exactly 2 short sentences. Do not hallucinate. Do not break character. Do not use lists. Call the player 'Architect'.",
+                    STRICT RULES: Reply in exactly 2 short sentences. Do not hallucinate. Do not break character. Do not use lists. Call the player 'Architect'.\n\n{}",
+                    persona,
                     quest.title,
                     phase.phase_type_name(),
-                    phase.display_label()
+                    phase.display_label(&locale),
+                    locale.reply_instruction()
                 )
             } else {
-                "You are The Gamification Architect. Welcome the player to their journey. \
-                STRICT RULES: Reply in exactly 2 short sentences. Do not hallucinate. Do not break character. Do not use lists.".to_string()
+                welcome_prompt(&locale)
             }
         } else {
-            "You are The Gamification Architect. Welcome the player to their journey. \
-            STRICT RULES: Reply in exactly 2 short sentences. Do not hallucinate. Do not break character. Do not use lists.".to_string()
+            welcome_prompt(&locale)
         };
 
         let _ = ai_channel.sender.send(AiRequest::Text(context));
@@ -191,43 +222,12 @@ fn generate_dynamic_dialogue(
     }
 }
 
-fn handle_dialogue_choices(
-    keys: Res<ButtonInput<KeyCode>>,
-    mut story_state: ResMut<StoryState>,
-    ai_channel: Res<AiChannel>,
-) {
-    let choices_to_process = story_state.active_dialogue.as_ref().map(|dialogue| dialogue.choices.clone());
-    
-    if let Some(choices) = choices_to_process {
-        if keys.just_pressed(KeyCode::Digit1) && !choices.is_empty() {
-            let choice = &choices[0];
-            story_state.narrative_context.push(choice.consequence.clone());
-            let _ = ai_channel.sender.send(AiRequest::Text(format!("{} (Press Space to continue)", choice.consequence)));
-            story_state.is_thinking = true;
-            // Only clear active dialogue if correct, so they have to try again if wrong
-            if choice.text.contains("Local LLM") {
-                story_state.active_dialogue = None;
-            }
-        }
-        if keys.just_pressed(KeyCode::Digit2) && choices.len() > 1 {
-            let choice = &choices[1];
-            story_state.narrative_context.push(choice.consequence.clone());
-            let _ = ai_channel.sender.send(AiRequest::Text(format!("{} (Press Space to continue)", choice.consequence)));
-            story_state.is_thinking = true;
-            if choice.text.contains("Local LLM") {
-                story_state.active_dialogue = None;
-            }
-        }
-        if keys.just_pressed(KeyCode::Digit3) && choices.len() > 2 {
-            let choice = &choices[2];
-            story_state.narrative_context.push(choice.consequence.clone());
-            let _ = ai_channel.sender.send(AiRequest::Text(format!("{} (Press Space to continue)", choice.consequence)));
-            story_state.is_thinking = true;
-            if choice.text.contains("Local LLM") {
-                story_state.active_dialogue = None;
-            }
-        }
-    }
+fn welcome_prompt(locale: &Locale) -> String {
+    format!(
+        "You are The Gamification Architect. Welcome the player to their journey. \
+        STRICT RULES: Reply in exactly 2 short sentences. Do not hallucinate. Do not break character. Do not use lists.\n\n{}",
+        locale.reply_instruction()
+    )
 }
 
 fn update_narrative_display(
@@ -238,11 +238,15 @@ fn update_narrative_display(
     mut narrative_query: Query<&mut Text, With<NarrativeText>>,
     // Reset teacher is_speaking when AI response arrives (teacher.rs no longer consumes the channel)
     mut teacher_state: ResMut<crate::teacher::TeacherState>,
+    (locale, mut voice_writer): (Res<Locale>, EventWriter<VoiceLineEvent>),
 ) {
+    // LLM replies wait until a scripted conversation is off screen
+    if story_state.active_dialogue.is_some() { return; }
+
     if story_state.is_thinking {
         for mut text in &mut narrative_query {
             let dots = (time.elapsed_secs() * 5.0) as usize % 4;
-            let loading_str = format!("Thinking{}", ".".repeat(dots));
+            let loading_str = format!("{}{}", locale.t("story-thinking"), ".".repeat(dots));
             *text = Text::new(loading_str);
         }
     }
@@ -254,7 +258,26 @@ fn update_narrative_display(
         typewriter.full_text = content.clone();
         typewriter.revealed_chars = 0;
         typewriter.is_active = true;
+        typewriter.voiced_chars = None;
         typewriter.timer.reset();
+        voice_writer.send(VoiceLineEvent(content));
+    }
+}
+
+/// Names the speaker of the scripted line on screen, or else the last NPC
+/// the player walked up to
+fn update_speaker_name(
+    story_state: Res<StoryState>,
+    mut name_query: Query<&mut Text, With<SpeakerName>>,
+) {
+    if !story_state.is_changed() { return; }
+    let scripted = story_state.active_dialogue.as_ref().map(|d| d.speaker.as_str()).filter(|s| !s.is_empty());
+    let Some(name) = scripted.or(story_state.nearby_npc.as_ref().map(|npc| npc.name.as_str())) else { return };
+    let header = format!("🧙 {}", name);
+    for mut text in &mut name_query {
+        if text.0 != header {
+            *text = Text::new(header.clone());
+        }
     }
 }
 
@@ -263,6 +286,7 @@ fn typewriter_tick(
     mut typewriter: ResMut<TypewriterState>,
     mut narrative_query: Query<&mut Text, With<NarrativeText>>,
     story_state: Res<StoryState>,
+    settings: Res<AccessibilitySettings>,
 ) {
     if !typewriter.is_active && !story_state.is_typing_prompt { return; }
     
@@ -273,32 +297,48 @@ fn typewriter_tick(
         return;
     }
 
-    typewriter.timer.tick(time.delta());
-    if typewriter.timer.just_finished() {
-        typewriter.revealed_chars += 1;
-        if typewriter.revealed_chars >= typewriter.full_text.len() {
-            typewriter.is_active = false;
-            for mut text in &mut narrative_query {
-                if story_state.is_typing_prompt {
-                    *text = Text::new(format!("{}\n\n> {}▌", typewriter.full_text, story_state.player_input));
-                } else {
-                    *text = Text::new(typewriter.full_text.clone());
-                }
+    match (settings.text_speed.delay(), typewriter.voiced_chars) {
+        // Words appear as the teacher says them
+        (Some(_), Some(voiced)) => {
+            if voiced <= typewriter.revealed_chars { return; }
+            typewriter.revealed_chars = voiced;
+        }
+        (Some(delay), None) => {
+            let delay = std::time::Duration::from_secs_f32(delay);
+            if typewriter.timer.duration() != delay {
+                typewriter.timer.set_duration(delay);
             }
-        } else {
-            let visible: String = typewriter.full_text.chars().take(typewriter.revealed_chars).collect();
-            for mut text in &mut narrative_query {
-                *text = Text::new(format!("{}▌", visible));
+            typewriter.timer.tick(time.delta());
+            if !typewriter.timer.just_finished() { return; }
+            typewriter.revealed_chars += 1;
+        }
+        (None, _) => typewriter.revealed_chars = typewriter.full_text.len(),
+    }
+    if typewriter.revealed_chars >= typewriter.full_text.len() {
+        typewriter.is_active = false;
+        for mut text in &mut narrative_query {
+            if story_state.is_typing_prompt {
+                *text = Text::new(format!("{}\n\n> {}▌", typewriter.full_text, story_state.player_input));
+            } else {
+                *text = Text::new(typewriter.full_text.clone());
             }
         }
+    } else {
+        let visible: String = typewriter.full_text.chars().take(typewriter.revealed_chars).collect();
+        for mut text in &mut narrative_query {
+            *text = Text::new(format!("{}▌", visible));
+        }
     }
 }
 
 fn handle_typing_input(
     mut char_evr: EventReader<bevy::input::keyboard::KeyboardInput>,
     keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<Actions>,
     mut story_state: ResMut<StoryState>,
     ai_channel: Res<AiChannel>,
+    locale: Res<Locale>,
+    mut turn_writer: EventWriter<TurnEvent>,
 ) {
     if !story_state.is_typing_prompt { return; }
 
@@ -316,44 +356,52 @@ fn handle_typing_input(
         story_state.player_input.pop();
     }
 
-    if keys.just_pressed(KeyCode::Enter) && !story_state.player_input.is_empty() {
+    if actions.just_pressed(Action::Confirm) && !story_state.player_input.is_empty() {
         let input = story_state.player_input.clone();
         story_state.player_input.clear();
         story_state.is_typing_prompt = false;
         
         // Respond to the typed script
-        let response = format!("Excellent construction! You commanded: \"{}\". The environment has absorbed your logic.", input);
+        turn_writer.send(TurnEvent(Turn::new(story_state.player_name.clone(), input.clone())));
+        let response = locale.fmt("story-logic-accepted", &[("input", &input)]);
         let _ = ai_channel.sender.send(AiRequest::Text(response));
         story_state.is_thinking = true;
     }
 }
 
 fn handle_quiz_input(
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<Actions>,
     mut story_state: ResMut<StoryState>,
     mut syllabus: Option<ResMut<crate::syllabus::SyllabusResource>>,
-    ai_channel: Res<AiChannel>,
-    mut event_writer: EventWriter<crate::syllabus::QuestAdvancedEvent>,
+    (ai_channel, locale): (Res<AiChannel>, Res<Locale>),
+    (mut event_writer, mut sfx_writer): (EventWriter<crate::syllabus::QuestAdvancedEvent>, EventWriter<SfxEvent>),
+    mut assessment_writer: EventWriter<crate::mastery::AssessmentEvent>,
+    (mut review_writer, mut turn_writer): (EventWriter<crate::review::ReviewCardEvent>, EventWriter<TurnEvent>),
 ) {
     let Some(ref mut syl) = syllabus else { return };
     
-    let (_question, options, correct_index) = match syl.current_phase() {
+    let (question, options, correct_index) = match syl.current_phase() {
         crate::syllabus::QuestPhase::Quiz { question, options, correct_index, .. } => {
             (question.clone(), options.clone(), *correct_index)
         }
         _ => return,
     };
 
-    let mut selected = None;
-    if keys.just_pressed(KeyCode::Digit1) { selected = Some(0); }
-    if keys.just_pressed(KeyCode::Digit2) { selected = Some(1); }
-    if keys.just_pressed(KeyCode::Digit3) { selected = Some(2); }
+    let selected = actions.choice(InputContext::Quiz);
 
     if let Some(idx) = selected {
         if idx < options.len() {
+            turn_writer.send(TurnEvent(Turn::new(story_state.player_name.clone(), options[idx].clone())));
+            assessment_writer.send(crate::mastery::AssessmentEvent {
+                objectives: syl.quest_script.current_objectives().to_vec(),
+                correct: idx == correct_index,
+                source: "Quiz".to_string(),
+            });
+
             if idx == correct_index {
                 // Correct!
-                let response = "Correct! Your understanding of the sovereign grid is deepening.".to_string();
+                sfx_writer.send(SfxEvent::QuizCorrect);
+                let response = locale.t("story-quiz-correct");
                 let _ = ai_channel.sender.send(AiRequest::Text(response));
                 story_state.is_thinking = true;
                 
@@ -365,8 +413,16 @@ fn handle_quiz_input(
                     step_index: syl.quest_script.current_phase,
                 });
             } else {
-                // Incorrect
-                let response = "Not quite, Architect. Consider the core principles again. (Try another option)".to_string();
+                // Incorrect — queue the question for spaced review
+                sfx_writer.send(SfxEvent::QuizWrong);
+                review_writer.send(crate::review::ReviewCardEvent {
+                    content: crate::review::CardContent::Quiz {
+                        question: question.clone(),
+                        options: options.clone(),
+                        correct_index,
+                    },
+                });
+                let response = locale.t("story-quiz-incorrect");
                 let _ = ai_channel.sender.send(AiRequest::Text(response));
                 story_state.is_thinking = true;
             }
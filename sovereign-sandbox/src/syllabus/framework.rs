@@ -0,0 +1,144 @@
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::fmt;
+
+// ============================================================================
+// Instructional Frameworks — Gagné, 5E, Merrill, or syllabus-defined
+// ============================================================================
+
+/// One named event (step) of an instructional framework.
+#[derive(Debug, Deserialize, Clone)]
+pub struct FrameworkEvent {
+    pub key: String,
+    pub label: String,
+}
+
+/// An ordered set of instructional events, e.g. Gagné's Nine Events.
+#[derive(Debug, Deserialize, Clone)]
+pub struct InstructionalFramework {
+    pub id: String,
+    pub name: String,
+    pub events: Vec<FrameworkEvent>,
+}
+
+impl InstructionalFramework {
+    fn built_in(id: &str, name: &str, events: &[(&str, &str)]) -> Self {
+        Self {
+            id: id.to_string(),
+            name: name.to_string(),
+            events: events.iter().map(|(key, label)| FrameworkEvent {
+                key: key.to_string(),
+                label: label.to_string(),
+            }).collect(),
+        }
+    }
+
+    /// Gagné's Nine Events of Instruction (the legacy default)
+    pub fn gagne() -> Self {
+        Self::built_in("gagne", "Gagné's Nine Events", &[
+            ("gain_attention", "Gain Attention"),
+            ("inform_objectives", "Inform Objectives"),
+            ("recall_prior_knowledge", "Recall Prior Knowledge"),
+            ("present_content", "Present Content"),
+            ("provide_guidance", "Provide Guidance"),
+            ("elicit_performance", "Elicit Performance"),
+            ("provide_feedback", "Provide Feedback"),
+            ("assess_performance", "Assess Performance"),
+            ("enhance_retention", "Enhance Retention"),
+        ])
+    }
+
+    /// The BSCS 5E model
+    pub fn five_e() -> Self {
+        Self::built_in("5e", "The 5E Model", &[
+            ("engage", "Engage"),
+            ("explore", "Explore"),
+            ("explain", "Explain"),
+            ("elaborate", "Elaborate"),
+            ("evaluate", "Evaluate"),
+        ])
+    }
+
+    /// Merrill's First Principles of Instruction
+    pub fn merrill() -> Self {
+        Self::built_in("merrill", "Merrill's First Principles", &[
+            ("problem", "Problem-Centered"),
+            ("activation", "Activation"),
+            ("demonstration", "Demonstration"),
+            ("application", "Application"),
+            ("integration", "Integration"),
+        ])
+    }
+
+    /// Looks up one of the frameworks that ship with the game
+    pub fn by_id(id: &str) -> Option<Self> {
+        match id {
+            "gagne" => Some(Self::gagne()),
+            "5e" | "five_e" => Some(Self::five_e()),
+            "merrill" => Some(Self::merrill()),
+            _ => None,
+        }
+    }
+
+    pub fn event(&self, key: &str) -> Option<&FrameworkEvent> {
+        self.events.iter().find(|e| e.key == key)
+    }
+
+    pub fn event_at(&self, index: usize) -> Option<&FrameworkEvent> {
+        self.events.get(index)
+    }
+
+    pub fn index_of(&self, key: &str) -> Option<usize> {
+        self.events.iter().position(|e| e.key == key)
+    }
+
+    /// Human label for an event key, falling back to the key itself
+    pub fn label_for<'a>(&'a self, key: &'a str) -> &'a str {
+        self.event(key).map(|e| e.label.as_str()).unwrap_or(key)
+    }
+}
+
+// ============================================================================
+// Event Set — ordered map of event key → lesson text
+// ============================================================================
+
+/// The `[modules.events]` table. Keeps the authoring order of the TOML so
+/// frameworks without a declared order still play back as written.
+#[derive(Debug, Clone, Default)]
+pub struct EventSet {
+    entries: Vec<(String, String)>,
+}
+
+impl EventSet {
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.entries.iter().find(|(k, _)| k == key).map(|(_, v)| v.as_str())
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(k, _)| k.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for EventSet {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EventSetVisitor;
+
+        impl<'de> Visitor<'de> for EventSetVisitor {
+            type Value = EventSet;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a table of event keys to lesson text")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<EventSet, A::Error> {
+                let mut entries = Vec::new();
+                while let Some((key, value)) = map.next_entry::<String, String>()? {
+                    entries.push((key, value));
+                }
+                Ok(EventSet { entries })
+            }
+        }
+
+        deserializer.deserialize_map(EventSetVisitor)
+    }
+}
@@ -1,6 +1,12 @@
 use bevy::prelude::*;
 use serde::Deserialize;
+use std::collections::HashSet;
 use crate::inventory::ToolId;
+use crate::npc::{NpcDef, DEFAULT_NPC};
+use crate::i18n::{Language, Locale};
+
+pub mod framework;
+pub use framework::{EventSet, InstructionalFramework};
 
 // ============================================================================
 // Quest Phase State Machine
@@ -11,9 +17,12 @@ pub enum QuestPhase {
     /// Player must walk to a target. Triggered by proximity.
     Exploration { target: String, rewards: Option<Vec<ToolId>> },
     /// NPC auto-speaks. Dialogue plays on proximity.
-    Dialogue { gagne_step: usize, rewards: Option<Vec<ToolId>> },
+    /// `event` is a key into the module's event set; `label` comes from the framework.
+    Dialogue { event: String, label: String, rewards: Option<Vec<ToolId>> },
     /// Player must perform an action (use terminal, activate tool).
-    Task { description: String, completed: bool, rewards: Option<Vec<ToolId>> },
+    /// `target` is the trigger it's done at; without one, the trigger named in
+    /// the description.
+    Task { description: String, target: Option<String>, completed: bool, rewards: Option<Vec<ToolId>> },
     /// Player answers a reflection question (voice or choice).
     Reflection { question: String, answered: bool, rewards: Option<Vec<ToolId>> },
     /// Player must answer a multiple-choice question.
@@ -22,20 +31,36 @@ pub enum QuestPhase {
     Complete,
 }
 
+/// A trigger or NPC id as the player reads it, e.g. "Archive"
+pub fn place_name(id: &str, locale: &Locale) -> String {
+    locale.lookup(&format!("place-{}", id.to_lowercase())).unwrap_or_else(|| id.to_string())
+}
+
 impl QuestPhase {
     /// Human-readable icon + label for the UI
-    pub fn display_label(&self) -> String {
+    pub fn display_label(&self, locale: &Locale) -> String {
         match self {
-            QuestPhase::Exploration { target, .. } => format!("🗺️ Walk to the {}", target),
-            QuestPhase::Dialogue { gagne_step, .. } => format!("💬 {}", gagne_step_name(*gagne_step)),
+            QuestPhase::Exploration { target, .. } => {
+                locale.fmt("phase-goal-explore", &[("target", &place_name(target, locale))])
+            }
+            QuestPhase::Dialogue { label, .. } => format!("💬 {}", label),
             QuestPhase::Task { description, .. } => format!("⚡ {}", description),
             QuestPhase::Reflection { question, .. } => format!("🪞 {}", question),
             QuestPhase::Quiz { question, .. } => format!("❓ {}", question),
-            QuestPhase::Complete => "🏆 Complete!".to_string(),
+            QuestPhase::Complete => locale.t("phase-goal-complete"),
         }
     }
 
-    /// Short type label for the quest log
+    /// Whether this is a task done at the trigger `id`
+    pub fn is_task_at(&self, id: &str) -> bool {
+        match self {
+            QuestPhase::Task { target: Some(target), .. } => target == id,
+            QuestPhase::Task { description, .. } => description.contains(id),
+            _ => false,
+        }
+    }
+
+    /// Short type id, also the `$phase` dialogue variable
     pub fn phase_type_name(&self) -> &'static str {
         match self {
             QuestPhase::Exploration { .. } => "EXPLORE",
@@ -46,24 +71,61 @@ impl QuestPhase {
             QuestPhase::Complete => "DONE",
         }
     }
+
+    /// Short type label for the quest log
+    pub fn phase_type_label(&self, locale: &Locale) -> String {
+        locale.t(&format!("phase-{}", self.phase_type_name().to_lowercase()))
+    }
 }
 
-/// Maps Gagné step index to human name
-pub fn gagne_step_name(step: usize) -> &'static str {
-    match step {
-        0 => "Gain Attention",
-        1 => "Inform Objectives",
-        2 => "Recall Prior Knowledge",
-        3 => "Present Content",
-        4 => "Provide Guidance",
-        5 => "Elicit Performance",
-        6 => "Provide Feedback",
-        7 => "Assess Performance",
-        8 => "Enhance Retention",
-        _ => "Unknown",
+// ============================================================================
+// Learning Objectives
+// ============================================================================
+
+/// Bloom's taxonomy level (revised), lowest to highest
+#[derive(Debug, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BloomLevel {
+    Remember,
+    Understand,
+    Apply,
+    Analyze,
+    Evaluate,
+    Create,
+}
+
+impl BloomLevel {
+    pub fn name(&self) -> &'static str {
+        match self {
+            BloomLevel::Remember => "Remember",
+            BloomLevel::Understand => "Understand",
+            BloomLevel::Apply => "Apply",
+            BloomLevel::Analyze => "Analyze",
+            BloomLevel::Evaluate => "Evaluate",
+            BloomLevel::Create => "Create",
+        }
     }
 }
 
+/// A syllabus-level learning objective that phases and quiz items can assess
+#[derive(Debug, Deserialize, Clone)]
+pub struct LearningObjective {
+    pub id: String,
+    pub text: String,
+    #[serde(default)]
+    pub bloom: Option<BloomLevel>,
+}
+
+/// A pretest/posttest item. Answered without feedback, outside the quest flow.
+#[derive(Debug, Deserialize, Clone)]
+pub struct QuizItem {
+    pub question: String,
+    pub options: Vec<String>,
+    pub correct_index: usize,
+    #[serde(default)]
+    pub objectives: Vec<String>,
+}
+
 // ============================================================================
 // TOML Data Structures (unchanged for backward compat)
 // ============================================================================
@@ -73,6 +135,25 @@ pub struct Syllabus {
     pub title: String,
     #[allow(dead_code)]
     pub description: String,
+    /// Instructional framework id (`gagne`, `5e`, `merrill`, or a custom one
+    /// declared under `[[frameworks]]`). Defaults to Gagné.
+    #[serde(default)]
+    pub framework: Option<String>,
+    /// Custom frameworks declared by the syllabus itself
+    #[serde(default)]
+    pub frameworks: Vec<InstructionalFramework>,
+    /// Learning objectives, referenced by id from modules and phases
+    #[serde(default)]
+    pub objectives: Vec<LearningObjective>,
+    /// Given before play starts; skipped when empty
+    #[serde(default)]
+    pub pretest: Vec<QuizItem>,
+    /// Given after the last module, before victory; skipped when empty
+    #[serde(default)]
+    pub posttest: Vec<QuizItem>,
+    /// Characters that deliver phases, referenced by id
+    #[serde(default)]
+    pub npcs: Vec<NpcDef>,
     pub modules: Vec<Quest>,
 }
 
@@ -83,40 +164,54 @@ pub struct Quest {
     pub title: String,
     #[allow(dead_code)]
     pub description: String,
-    #[allow(dead_code)]
     pub objective: String,
-    pub events: GagneEvents,
+    /// Ids of the syllabus objectives this module addresses
+    #[serde(default)]
+    pub objectives: Vec<String>,
+    /// Lesson text per framework event, keyed by event key
+    pub events: EventSet,
     /// Optional phase script — if absent, auto-generated from events
     pub phases: Option<Vec<PhaseConfig>>,
+    /// Knowledge fragments placed in the world while this module is active
+    #[serde(default)]
+    pub fragments: Vec<FragmentDef>,
+    /// Zone this module plays in (a `.tmj` map under `assets/`)
+    pub map: Option<String>,
+}
+
+impl Quest {
+    pub fn map_path(&self) -> &str {
+        self.map.as_deref().unwrap_or(crate::map::DEFAULT_MAP)
+    }
 }
 
+/// A collectible knowledge fragment. Placed on an explicit tile, else on the
+/// map's `fragment` object of the same title, else at the centre of a room. The body supports light markup: `code`,
+/// *emphasis* and **strong**.
 #[derive(Debug, Deserialize, Clone)]
-pub struct GagneEvents {
-    pub gain_attention: String,
-    pub inform_objectives: String,
-    pub recall_prior_knowledge: String,
-    pub present_content: String,
-    pub provide_guidance: String,
-    pub elicit_performance: String,
-    pub provide_feedback: String,
-    pub assess_performance: String,
-    pub enhance_retention: String,
+pub struct FragmentDef {
+    pub title: String,
+    pub body: String,
+    #[serde(default = "default_fragment_xp")]
+    pub xp: u32,
+    /// Tile coordinates `[x, y]` (takes precedence over `room`)
+    pub tile: Option<[f32; 2]>,
+    /// Room name, e.g. "Terminal Lab"
+    pub room: Option<String>,
+    /// Objective id this fragment supports
+    pub objective: Option<String>,
+    /// Only appears once this phase (0-based index) has been completed
+    pub after_phase: Option<usize>,
 }
 
-impl GagneEvents {
-    pub fn get_step_text(&self, step: usize) -> Option<&str> {
-        match step {
-            0 => Some(&self.gain_attention),
-            1 => Some(&self.inform_objectives),
-            2 => Some(&self.recall_prior_knowledge),
-            3 => Some(&self.present_content),
-            4 => Some(&self.provide_guidance),
-            5 => Some(&self.elicit_performance),
-            6 => Some(&self.provide_feedback),
-            7 => Some(&self.assess_performance),
-            8 => Some(&self.enhance_retention),
-            _ => None,
-        }
+fn default_fragment_xp() -> u32 {
+    25
+}
+
+impl FragmentDef {
+    /// Stable id, unique across the syllabus
+    pub fn id(&self, quest: &Quest) -> String {
+        format!("{}/{}", quest.id, self.title)
     }
 }
 
@@ -125,12 +220,19 @@ pub struct PhaseConfig {
     #[serde(rename = "type")]
     pub phase_type: String,
     pub target: Option<String>,
+    /// Event key for dialogue phases (preferred over `gagne_step`)
+    pub event: Option<String>,
+    /// Legacy: index into the framework's event list
     pub gagne_step: Option<usize>,
     pub description: Option<String>,
     pub question: Option<String>,
     pub options: Option<Vec<String>>,
     pub correct_index: Option<usize>,
     pub rewards: Option<Vec<String>>,
+    /// Objective ids this phase assesses
+    pub objectives: Option<Vec<String>>,
+    /// NPC id delivering this phase (exploration, dialogue, reflection, quiz)
+    pub npc: Option<String>,
 }
 
 impl Syllabus {
@@ -138,6 +240,26 @@ impl Syllabus {
         toml::from_str(contents)
             .map_err(|e| format!("Failed to parse syllabus TOML: {}", e))
     }
+
+    /// Resolves the declared framework, preferring syllabus-defined ones
+    pub fn resolve_framework(&self) -> InstructionalFramework {
+        let id = self.framework.as_deref().unwrap_or("gagne");
+        if let Some(custom) = self.frameworks.iter().find(|f| f.id == id) {
+            return custom.clone();
+        }
+        InstructionalFramework::by_id(id).unwrap_or_else(|| {
+            warn!("Unknown instructional framework '{}', falling back to Gagné", id);
+            InstructionalFramework::gagne()
+        })
+    }
+
+    pub fn objective(&self, id: &str) -> Option<&LearningObjective> {
+        self.objectives.iter().find(|o| o.id == id)
+    }
+
+    pub fn npc(&self, id: &str) -> Option<&NpcDef> {
+        self.npcs.iter().find(|n| n.id == id)
+    }
 }
 
 // ============================================================================
@@ -147,12 +269,35 @@ impl Syllabus {
 #[derive(Clone, Debug)]
 pub struct QuestScript {
     pub phases: Vec<QuestPhase>,
+    /// Objective ids tagged on each phase (parallel to `phases`)
+    pub phase_objectives: Vec<Vec<String>>,
+    /// NPC named by each phase (parallel to `phases`)
+    pub phase_npcs: Vec<Option<String>>,
     pub current_phase: usize,
 }
 
 impl QuestScript {
     /// Build a QuestScript from a Quest definition
-    pub fn from_quest(quest: &Quest) -> Self {
+    pub fn from_quest(quest: &Quest, framework: &InstructionalFramework) -> Self {
+        let dialogue = |key: &str, rewards: Option<Vec<ToolId>>| QuestPhase::Dialogue {
+            event: key.to_string(),
+            label: framework.label_for(key).to_string(),
+            rewards,
+        };
+        // The first event of the framework, or of the module if the framework is empty
+        let first_event = framework.event_at(0).map(|e| e.key.clone())
+            .or_else(|| quest.events.keys().next().map(str::to_string))
+            .unwrap_or_default();
+
+        let phase_objectives = match quest.phases {
+            Some(ref configs) => configs.iter().map(|c| c.objectives.clone().unwrap_or_default()).collect(),
+            None => vec![],
+        };
+        let phase_npcs = match quest.phases {
+            Some(ref configs) => configs.iter().map(|c| c.npc.clone()).collect(),
+            None => vec![],
+        };
+
         let phases = if let Some(ref configs) = quest.phases {
             // Use explicit phase config from TOML
             configs.iter().map(|c| {
@@ -172,15 +317,19 @@ impl QuestScript {
 
                 match c.phase_type.as_str() {
                     "exploration" => QuestPhase::Exploration {
-                        target: c.target.clone().unwrap_or_else(|| "Teacher".to_string()),
-                        rewards,
-                    },
-                    "dialogue" => QuestPhase::Dialogue {
-                        gagne_step: c.gagne_step.unwrap_or(0),
+                        // Walking up to the phase's NPC, unless a target is given
+                        target: c.target.clone().or_else(|| c.npc.clone()).unwrap_or_else(|| DEFAULT_NPC.to_string()),
                         rewards,
                     },
+                    "dialogue" => {
+                        let key = c.event.clone()
+                            .or_else(|| c.gagne_step.and_then(|i| framework.event_at(i)).map(|e| e.key.clone()))
+                            .unwrap_or_else(|| first_event.clone());
+                        dialogue(&key, rewards)
+                    }
                     "task" => QuestPhase::Task {
                         description: c.description.clone().unwrap_or_else(|| "Complete the task".to_string()),
+                        target: c.target.clone(),
                         completed: false,
                         rewards,
                     },
@@ -196,24 +345,25 @@ impl QuestScript {
                         answered: false,
                         rewards,
                     },
-                    _ => QuestPhase::Dialogue { gagne_step: 0, rewards: None },
+                    _ => dialogue(&first_event, None),
                 }
             }).collect()
         } else {
-            // Auto-generate: exploration → 9 dialogues → complete
+            // Auto-generate: exploration → one dialogue per framework event → complete
             let mut phases = vec![
-                QuestPhase::Exploration { target: "Teacher".to_string(), rewards: None },
+                QuestPhase::Exploration { target: DEFAULT_NPC.to_string(), rewards: None },
             ];
-            for step in 0..9 {
-                if step == 4 {
+            for event in &framework.events {
+                if event.key == "provide_guidance" {
                     // Insert a task before "Provide Guidance"
                     phases.push(QuestPhase::Task {
                         description: "Walk to the Terminal and interact with it".to_string(),
+                        target: None,
                         completed: false,
                         rewards: None,
                     });
                 }
-                if step == 7 {
+                if event.key == "assess_performance" {
                     // Insert a reflection at "Assess Performance"
                     phases.push(QuestPhase::Reflection {
                         question: "Can you explain why local AI matters for student privacy?".to_string(),
@@ -221,7 +371,7 @@ impl QuestScript {
                         rewards: None,
                     });
                 } else {
-                    phases.push(QuestPhase::Dialogue { gagne_step: step, rewards: None });
+                    phases.push(dialogue(&event.key, None));
                 }
             }
             phases
@@ -229,10 +379,28 @@ impl QuestScript {
 
         Self {
             phases,
+            phase_objectives,
+            phase_npcs,
             current_phase: 0,
         }
     }
 
+    pub fn empty() -> Self {
+        Self { phases: vec![], phase_objectives: vec![], phase_npcs: vec![], current_phase: 0 }
+    }
+
+    /// Id of the NPC delivering the current phase
+    pub fn current_npc(&self) -> &str {
+        self.phase_npcs.get(self.current_phase)
+            .and_then(|npc| npc.as_deref())
+            .unwrap_or(DEFAULT_NPC)
+    }
+
+    /// Objective ids tagged on the current phase
+    pub fn current_objectives(&self) -> &[String] {
+        self.phase_objectives.get(self.current_phase).map(Vec::as_slice).unwrap_or(&[])
+    }
+
     pub fn current(&self) -> &QuestPhase {
         self.phases.get(self.current_phase).unwrap_or(&QuestPhase::Complete)
     }
@@ -267,26 +435,33 @@ impl QuestScript {
 #[derive(Resource, Clone)]
 pub struct SyllabusResource {
     pub syllabus: Syllabus,
+    /// The instructional framework this syllabus is written against
+    pub framework: InstructionalFramework,
     pub current_module_index: usize,
     /// Legacy field — kept for backward compat with inventory unlock checks
     pub current_event_step: usize,
     /// The new quest script state machine
     pub quest_script: QuestScript,
+    /// Ids of knowledge fragments already collected this run
+    pub collected_fragments: HashSet<String>,
 }
 
 impl SyllabusResource {
     pub fn new(syllabus: Syllabus) -> Self {
+        let framework = syllabus.resolve_framework();
         let quest_script = if let Some(quest) = syllabus.modules.first() {
-            QuestScript::from_quest(quest)
+            QuestScript::from_quest(quest, &framework)
         } else {
-            QuestScript { phases: vec![], current_phase: 0 }
+            QuestScript::empty()
         };
 
         Self {
             syllabus,
+            framework,
             current_module_index: 0,
             current_event_step: 0,
             quest_script,
+            collected_fragments: HashSet::new(),
         }
     }
 
@@ -294,6 +469,47 @@ impl SyllabusResource {
         self.syllabus.modules.get(self.current_module_index)
     }
 
+    /// Fragments of the current module that should be in the world right now:
+    /// phase-gated ones only after their phase, collected ones never.
+    pub fn active_fragments(&self) -> Vec<(String, &FragmentDef)> {
+        let Some(quest) = self.current_quest() else { return vec![] };
+        quest.fragments.iter()
+            .filter(|f| f.after_phase.is_none_or(|p| self.quest_script.current_phase > p))
+            .map(|f| (f.id(quest), f))
+            .filter(|(id, _)| !self.collected_fragments.contains(id))
+            .collect()
+    }
+
+    /// Fragments in every module, collected or not
+    pub fn total_fragments(&self) -> usize {
+        self.syllabus.modules.iter().map(|quest| quest.fragments.len()).sum()
+    }
+
+    /// Objectives addressed by the current module: its declared ids first,
+    /// then any ids tagged on its phases, without duplicates.
+    pub fn module_objectives(&self) -> Vec<&LearningObjective> {
+        let Some(quest) = self.current_quest() else { return vec![] };
+        let mut ids: Vec<&str> = quest.objectives.iter().map(String::as_str).collect();
+        for tags in &self.quest_script.phase_objectives {
+            for id in tags {
+                if !ids.contains(&id.as_str()) {
+                    ids.push(id);
+                }
+            }
+        }
+        ids.into_iter().filter_map(|id| self.syllabus.objective(id)).collect()
+    }
+
+    /// Restart the syllabus from the first module
+    pub fn reset(&mut self) {
+        self.current_module_index = 0;
+        self.current_event_step = 0;
+        self.collected_fragments.clear();
+        if let Some(first_quest) = self.syllabus.modules.first() {
+            self.quest_script = QuestScript::from_quest(first_quest, &self.framework);
+        }
+    }
+
 
 
     /// The current phase of the quest
@@ -314,8 +530,10 @@ impl SyllabusResource {
 
         let phase = self.quest_script.advance();
         // Sync legacy step counter
-        if let QuestPhase::Dialogue { gagne_step, .. } = phase {
-            self.current_event_step = *gagne_step;
+        if let QuestPhase::Dialogue { event, .. } = phase {
+            if let Some(step) = self.framework.index_of(event) {
+                self.current_event_step = step;
+            }
         }
         // Check if this module's script is complete
         if self.quest_script.is_complete() {
@@ -323,7 +541,7 @@ impl SyllabusResource {
             self.current_event_step = 0;
             // Load next module's script
             if let Some(next_quest) = self.syllabus.modules.get(self.current_module_index) {
-                self.quest_script = QuestScript::from_quest(next_quest);
+                self.quest_script = QuestScript::from_quest(next_quest, &self.framework);
             }
         }
         
@@ -369,15 +587,22 @@ pub struct SyllabusPlugin;
 impl Plugin for SyllabusPlugin {
     fn build(&self, app: &mut App) {
         app.add_event::<QuestAdvancedEvent>()
-           .add_systems(Update, check_syllabus_completion.run_if(in_state(crate::GameState::Playing)));
+           .add_systems(Update, (
+               check_syllabus_completion.run_if(in_state(crate::GameState::Playing)),
+               reload_syllabus_for_language
+                   .run_if(in_state(crate::GameState::Menu))
+                   .run_if(resource_changed::<Locale>.and(not(resource_added::<Locale>))),
+           ));
         
         // Load syllabus at startup using include_str! so it works in WASM
-        let syllabus_contents = include_str!("../../assets/syllabus/module_1.toml");
+        let language = app.world().resource::<Locale>().language();
+        let syllabus_contents = syllabus_source(language);
         
         match Syllabus::load_from_str(syllabus_contents) {
             Ok(syllabus) => {
                 info!("📚 Syllabus Loaded: {}", syllabus.title);
                 let resource = SyllabusResource::new(syllabus);
+                info!("🧭 Instructional framework: {}", resource.framework.name);
                 info!("🎮 Quest Script: {} phases for module 1", resource.quest_script.total_phases());
                 app.insert_resource(resource);
             }
@@ -389,13 +614,33 @@ impl Plugin for SyllabusPlugin {
     }
 }
 
+/// The syllabus file for `language`, compiled in like the English one
+pub fn syllabus_source(language: Language) -> &'static str {
+    match language {
+        Language::English => include_str!("../../assets/syllabus/module_1.toml"),
+        Language::Spanish => include_str!("../../assets/syllabus/module_1.es.toml"),
+    }
+}
+
+/// Swaps in the syllabus for a newly picked language. Only offered on the
+/// title screen, so there is no progress to carry over.
+fn reload_syllabus_for_language(mut commands: Commands, locale: Res<Locale>) {
+    match Syllabus::load_from_str(syllabus_source(locale.language())) {
+        Ok(syllabus) => {
+            info!("📚 Syllabus reloaded for {}: {}", locale.language().native_name(), syllabus.title);
+            commands.insert_resource(SyllabusResource::new(syllabus));
+        }
+        Err(e) => error!("Failed to load {} syllabus: {}", locale.language().code(), e),
+    }
+}
+
 fn check_syllabus_completion(
     syllabus: Option<Res<SyllabusResource>>,
     mut next_state: ResMut<NextState<crate::GameState>>,
 ) {
     if let Some(syl) = syllabus {
         if syl.current_module_index >= syl.syllabus.modules.len() {
-            next_state.set(crate::GameState::Victory);
+            next_state.set(crate::GameState::PostTest);
         }
     }
 }
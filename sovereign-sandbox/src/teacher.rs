@@ -1,5 +1,6 @@
 use bevy::prelude::*;
-use crate::ai::AiChannel;
+use crate::ai::{AiChannel, AiRequest};
+use crate::i18n::Locale;
 use crate::syllabus::{SyllabusResource, QuestPhase};
 use crate::scoring::PlayerScore;
 
@@ -53,6 +54,7 @@ impl Plugin for TeacherPlugin {
 fn teacher_nudge_system(
     time: Res<Time>,
     ai_channel: Res<AiChannel>,
+    locale: Res<Locale>,
     mut teacher_state: ResMut<TeacherState>,
     syllabus: Option<Res<SyllabusResource>>,
 ) {
@@ -72,14 +74,17 @@ fn teacher_nudge_system(
 
     if teacher_state.nudge_timer.just_finished() {
         if let Some(quest) = syl.current_quest() {
+            let QuestPhase::Exploration { ref target, .. } = *syl.current_phase() else { return };
+            let destination = syl.syllabus.npc(target).map(|n| n.name.as_str()).unwrap_or(target);
             let prompt = format!(
                 "ROLE: Pedagogical Orchestrator. \
                 STATUS: The Architect is drifting in the world during the exploration phase for quest '{}'. \
-                MANAGED FREE WILL: Provide a gentle, 1-sentence 'nudge' that acknowledges their freedom but pulls them toward the chalkboard (the Teacher's location). Keep it very brief. \
+                MANAGED FREE WILL: Provide a gentle, 1-sentence 'nudge' that acknowledges their freedom but pulls them toward {}. Keep it very brief. \
                 Call them 'Architect'.",
-                quest.title
+                quest.title,
+                destination
             );
-            let _ = ai_channel.sender.send(crate::ai::AiRequest::Text(prompt));
+            ask(&ai_channel, &locale, prompt);
             teacher_state.is_speaking = true;
             info!("💡 Teacher sending proactive nudge");
         }
@@ -112,22 +117,35 @@ struct LogicLensDisplay;
 #[derive(Component)]
 struct LogicLensScanline;
 
+/// Sends `prompt` to the model, asking for the answer in the player's language
+fn ask(ai_channel: &AiChannel, locale: &Locale, prompt: String) {
+    let _ = ai_channel.sender.send(AiRequest::Text(format!("{}\n\n{}", prompt, locale.reply_instruction())));
+}
+
 // ============================================================================
 // Auto-Dialogue System (Pokémon-style: NPC talks when you walk up)
 // ============================================================================
 
 fn auto_dialogue_on_proximity(
-    ai_channel: ResMut<AiChannel>,
+    (ai_channel, locale): (Res<AiChannel>, Res<Locale>),
     mut teacher_state: ResMut<TeacherState>,
     story_state: Res<crate::story_mode::StoryState>,
     mut syllabus: Option<ResMut<SyllabusResource>>,
     mut event_writer: EventWriter<crate::syllabus::QuestAdvancedEvent>,
+    mut last_npc: Local<Option<String>>,
 ) {
-    // Only trigger when player enters Teacher range
-    if !story_state.can_interact {
+    // Only trigger when player enters an NPC's range
+    let Some(ref npc) = story_state.nearby_npc else {
         // Reset the flag when player leaves range
         teacher_state.auto_dialogue_sent = false;
+        *last_npc = None;
         return;
+    };
+
+    // Walking straight from one NPC to another starts a new conversation
+    if last_npc.as_deref() != Some(npc.id.as_str()) {
+        *last_npc = Some(npc.id.clone());
+        teacher_state.auto_dialogue_sent = false;
     }
 
     // Don't re-trigger if already sent
@@ -136,46 +154,70 @@ fn auto_dialogue_on_proximity(
     }
 
     if let Some(ref mut syl) = syllabus {
+        // Phases delivered by another NPC get a greeting pointing the way
+        let speaker = syl.quest_script.current_npc().to_string();
+        let delivers = matches!(syl.current_phase(),
+            QuestPhase::Dialogue { .. } | QuestPhase::Reflection { .. } | QuestPhase::Quiz { .. });
+        if delivers && speaker != npc.id {
+            let speaker_name = syl.syllabus.npc(&speaker).map(|n| n.name.clone()).unwrap_or(speaker);
+            ask(&ai_channel, &locale, format!(
+                "ROLE: {}\n\
+                The Architect (the player) just walked up to you, but {} is the one they need to see next. \
+                Greet them in 1 sentence{} and send them on their way. Call them 'Architect'.",
+                npc.persona,
+                speaker_name,
+                npc.greeting.as_ref().map(|g| format!(" (in the spirit of: '{}')", g)).unwrap_or_default()
+            ));
+            teacher_state.is_speaking = true;
+            teacher_state.auto_dialogue_sent = true;
+            return;
+        }
+
         match syl.current_phase().clone() {
-            QuestPhase::Exploration { .. } => {
+            QuestPhase::Exploration { ref target, .. } => {
+                if *target != npc.id { return; }
                 // Player arrived! Advance past exploration and auto-speak
                 syl.advance_phase();
                 event_writer.send(crate::syllabus::QuestAdvancedEvent {
                     module_index: syl.current_module_index,
                     step_index: syl.quest_script.current_phase,
                 });
-                info!("🗺️ Exploration complete — player reached Teacher");
+                info!("🗺️ Exploration complete — player reached {}", npc.name);
                 teacher_state.auto_dialogue_sent = false; // Let the next phase (Dialogue) trigger
             }
-            QuestPhase::Dialogue { gagne_step, .. } => {
-                // Auto-speak the dialogue for this Gagné step
+            QuestPhase::Dialogue { ref event, ref label, .. } => {
+                // Auto-speak the dialogue for this framework event
                 if let Some(quest) = syl.current_quest() {
-                    let event_text = quest.events.get_step_text(gagne_step)
-                        .unwrap_or("Welcome, Architect.");
+                    let event_text = npc.event_text(event, &quest.events)
+                        .map(str::to_string)
+                        .unwrap_or_else(|| locale.t("teacher-default-lesson"));
 
                     let prompt = format!(
-                        "ROLE: You are the Gamification Architect, acting as a Pedagogical Orchestrator.\n\
+                        "ROLE: {} You are acting as a Pedagogical Orchestrator.\n\
                         CONTEXT: The Architect (the player) is currently on the quest '{}', and has just approached you.\n\
-                        CURRENT LESSON (Gagné Event '{}'): {}\n\n\
-                        INSTRUCTION: Deliver this lesson step to the Architect in 2 short sentences. Speak with a cyberpunk mentor tone. Focus intensely on the educational value of local, sovereign AI. Be concise.",
+                        CURRENT LESSON ({} — '{}'): {}\n\n\
+                        INSTRUCTION: Deliver this lesson step to the Architect in 2 short sentences, in character. Focus intensely on the educational value of local, sovereign AI. Be concise.",
+                        npc.persona,
                         quest.title,
-                        crate::syllabus::gagne_step_name(gagne_step),
+                        syl.framework.name,
+                        label,
                         event_text
                     );
 
-                    let _ = ai_channel.sender.send(crate::ai::AiRequest::Text(prompt));
+                    ask(&ai_channel, &locale, prompt);
                     teacher_state.is_speaking = true;
                     teacher_state.auto_dialogue_sent = true;
                 }
             }
             QuestPhase::Reflection { ref question, .. } => {
                 // Ask the reflection question
-                let _ = ai_channel.sender.send(crate::ai::AiRequest::Text(format!(
-                    "ROLE: Pedagogical Orchestrator. \
+                ask(&ai_channel, &locale, format!(
+                    "ROLE: {} Pedagogical Orchestrator. \
                     MANAGED FREE WILL: 'Yes-And' any student curiosity while maintaining the gravitational pull of this question. \
                     Ask the Architect this reflection question (2 sentences max): '{}'",
+                    npc.persona,
                     question
-                )));
+                ));
                 teacher_state.is_speaking = true;
                 teacher_state.auto_dialogue_sent = true;
             }
@@ -186,17 +228,18 @@ fn auto_dialogue_on_proximity(
                     .collect::<Vec<_>>().join("\n");
                 
                 let prompt = format!(
-                    "ROLE: Pedagogical Orchestrator. \
+                    "ROLE: {} Pedagogical Orchestrator. \
                     Ask the Architect this quiz question: '{}'\n\nOptions:\n{}\n\nInstruction: Present the question and options clearly. Call them 'Architect'. Limit your response to 2 short sentences plus the options.",
+                    npc.persona,
                     question,
                     options_str
                 );
-                let _ = ai_channel.sender.send(crate::ai::AiRequest::Text(prompt));
+                ask(&ai_channel, &locale, prompt);
                 teacher_state.is_speaking = true;
                 teacher_state.auto_dialogue_sent = true;
             }
             _ => {
-                // Task phases or Complete — Teacher stays silent
+                // Task phases or Complete — the NPC stays silent
             }
         }
     }
@@ -205,23 +248,27 @@ fn auto_dialogue_on_proximity(
 // ============================================================================
 // Manual Interaction (Press T — advances dialogue phases)
 // ============================================================================
+// Only the NPC delivering the current phase can advance it.
 
 fn teacher_interaction(
-    keys: Res<ButtonInput<KeyCode>>,
-    ai_channel: Res<AiChannel>,
+    actions: Res<crate::actions::Actions>,
+    (ai_channel, locale): (Res<AiChannel>, Res<Locale>),
     mut teacher_state: ResMut<TeacherState>,
     mut syllabus: Option<ResMut<SyllabusResource>>,
     story_state: Res<crate::story_mode::StoryState>,
     score: Res<PlayerScore>,
     mut event_writer: EventWriter<crate::syllabus::QuestAdvancedEvent>,
 ) {
-    if keys.just_pressed(KeyCode::KeyT) && story_state.can_interact {
+    let Some(ref npc) = story_state.nearby_npc else { return };
+    if actions.just_pressed(crate::actions::Action::Interact) {
         if let Some(ref mut syl) = syllabus {
+            if syl.quest_script.current_npc() != npc.id
+                && !matches!(syl.current_phase(), QuestPhase::Exploration { .. }) {
+                return;
+            }
             // Level Gate Check: Cannot advance to Module 2 without being Level 2
             if syl.current_module_index == 0 && syl.quest_script.current_phase == 5 && score.level < 2 {
-                let _ = ai_channel.sender.send(crate::ai::AiRequest::Text(
-                    "Architect, your understanding is still novice. You must engage the Glitch Slimes in the hall to grind until you reach Level 2 before we proceed.".to_string()
-                ));
+                let _ = ai_channel.sender.send(AiRequest::Text(locale.t("teacher-level-gate")));
                 teacher_state.is_speaking = true;
                 return;
             }
@@ -250,11 +297,13 @@ fn teacher_interaction(
                     info!("🪞 Reflection complete — advancing");
                 }
                 QuestPhase::Exploration { .. } => {
-                    // If standing near teacher, just prompt
-                    let _ = ai_channel.sender.send(crate::ai::AiRequest::Text(
-                        "ROLE: You are The Gamification Architect mentor NPC. \
-                        The player just walked up to you. Greet them warmly in 1-2 sentences, RPG style. \
-                        Call them 'Architect'.".to_string()
+                    // If standing near an NPC, just greet
+                    ask(&ai_channel, &locale, format!(
+                        "ROLE: {} \
+                        The player just walked up to you. Greet them warmly in 1-2 sentences, RPG style{}. \
+                        Call them 'Architect'.",
+                        npc.persona,
+                        npc.greeting.as_ref().map(|g| format!(" (in the spirit of: '{}')", g)).unwrap_or_default()
                     ));
                     teacher_state.is_speaking = true;
                 }
@@ -273,6 +322,7 @@ fn update_logic_lens(
     inventory: Res<crate::inventory::Inventory>,
     story_state: Res<crate::story_mode::StoryState>,
     syllabus: Option<Res<SyllabusResource>>,
+    locale: Res<Locale>,
     mut display_query: Query<(Entity, &mut Text), With<LogicLensDisplay>>,
 ) {
     let lens_active = inventory.active_tool == Some(crate::inventory::ToolId::LogicLens);
@@ -282,17 +332,17 @@ fn update_logic_lens(
         let context_text = if let Some(syl) = syllabus {
             let phase = syl.current_phase();
             if let Some(quest) = syl.current_quest() {
-                format!(
-                    "🔍 LOGIC LENS\nQUEST: {}\nPHASE: [{}] {}\nINTENT: Gagné Event Protocol",
-                    quest.title,
-                    phase.phase_type_name(),
-                    phase.display_label()
-                )
+                locale.fmt("lens-context", &[
+                    ("quest", &quest.title),
+                    ("phase", &phase.phase_type_label(&locale)),
+                    ("label", &phase.display_label(&locale)),
+                    ("framework", &syl.framework.name),
+                ])
             } else {
-                "🔍 LOGIC LENS\nSearching for intent...".to_string()
+                locale.t("lens-searching")
             }
         } else {
-            "🔍 LOGIC LENS\nNo syllabus context found.".to_string()
+            locale.t("lens-no-syllabus")
         };
         
         if let Ok((_entity, mut text)) = display_query.get_single_mut() {
@@ -317,7 +367,7 @@ fn update_logic_lens(
             )).with_children(|parent| {
                 // Header
                 parent.spawn((
-                    Text::new("SCANNING LOGIC..."),
+                    Text::new(locale.t("lens-scanning")),
                     TextFont {
                         font_size: 10.0,
                         ..default()
@@ -0,0 +1,205 @@
+use bevy::audio::Volume;
+use bevy::prelude::*;
+use std::sync::Arc;
+use crate::accessibility::AccessibilitySettings;
+use crate::actions::{Action, Actions};
+use crate::audio::{AudioBus, Bus};
+use crate::i18n::Locale;
+use crate::PauseState;
+use crate::narration::speakable;
+use crate::settings::GameSettings;
+use crate::story_mode::TypewriterState;
+use crate::tts::{FormantSynth, TtsBackend, Utterance};
+
+// ============================================================================
+// Teacher Voice — the teacher's answers read aloud
+// ============================================================================
+// Every AI answer the dialogue box shows arrives here as a `VoiceLineEvent`
+// and is spoken on the voice bus by the `TtsBackend` in `TeacherVoice` (the
+// formant synth, see `tts`). While it speaks, the typewriter reveals each word
+// as it's heard rather than on its own timer, and the sentence being spoken is
+// captioned above the command bar, since long answers overflow the box. Talk
+// (Space) cuts the voice off as it finishes the line, and the pause menu holds
+// it, mid-word, until the game resumes. Lines are voiced when the
+// `teacher_voice` accessibility setting is on and the voice bus isn't silent;
+// narration then leaves them to the voice.
+
+/// A line for the teacher to say, sent as it appears in the dialogue box
+#[derive(Event, Debug, Clone)]
+pub struct VoiceLineEvent(pub String);
+
+/// Whoever is doing the speaking
+#[derive(Resource)]
+pub struct TeacherVoice(pub Box<dyn TtsBackend>);
+
+impl Default for TeacherVoice {
+    fn default() -> Self {
+        Self(Box::new(FormantSynth::default()))
+    }
+}
+
+/// The line being spoken
+#[derive(Resource)]
+struct Speaking {
+    text: String,
+    utterance: Utterance,
+    sound: Entity,
+    elapsed: f32,
+}
+
+#[derive(Component)]
+struct VoiceCaption;
+
+/// Whether lines sent now will be heard
+pub fn voices_lines(accessibility: &AccessibilitySettings, settings: &GameSettings) -> bool {
+    accessibility.teacher_voice && settings.audio.volume(Bus::Voice) > 0.0
+}
+
+pub struct TeacherVoicePlugin;
+
+impl Plugin for TeacherVoicePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TeacherVoice>()
+           .add_event::<VoiceLineEvent>()
+           .add_systems(Startup, spawn_caption)
+           .add_systems(Update, (speak_lines, follow_voice, update_caption).chain());
+    }
+}
+
+fn speak_lines(
+    mut commands: Commands,
+    mut events: EventReader<VoiceLineEvent>,
+    (voice, locale): (Res<TeacherVoice>, Res<Locale>),
+    (accessibility, settings): (Res<AccessibilitySettings>, Res<GameSettings>),
+    mut sources: ResMut<Assets<AudioSource>>,
+    speaking: Option<Res<Speaking>>,
+    mut typewriter: ResMut<TypewriterState>,
+) {
+    // Only the newest line matters
+    let Some(VoiceLineEvent(text)) = events.read().last() else { return };
+    if let Some(speaking) = speaking {
+        stop(&mut commands, &speaking, &mut typewriter);
+    }
+    if !voices_lines(&accessibility, &settings) { return; }
+
+    let utterance = match voice.0.synthesize(text, locale.language().code()) {
+        Ok(utterance) if !utterance.words.is_empty() => utterance,
+        Ok(_) => return,
+        Err(e) => {
+            warn!("⚠️ Teacher voice failed: {}", e);
+            return;
+        }
+    };
+    let source = sources.add(AudioSource { bytes: Arc::from(utterance.to_wav()) });
+    let sound = commands.spawn((
+        AudioPlayer::new(source),
+        PlaybackSettings::DESPAWN.with_volume(Volume::new(settings.audio.volume(Bus::Voice))),
+        AudioBus(Bus::Voice),
+    )).id();
+    typewriter.voiced_chars = Some(0);
+    commands.insert_resource(Speaking { text: text.clone(), utterance, sound, elapsed: 0.0 });
+}
+
+/// Paces the typewriter by the voice, stops the voice when the line is
+/// skipped or replaced, and holds it while the game is paused
+fn follow_voice(
+    mut commands: Commands,
+    (time, pause): (Res<Time<Real>>, Option<Res<State<PauseState>>>),
+    actions: Res<Actions>,
+    speaking: Option<ResMut<Speaking>>,
+    sinks: Query<&AudioSink>,
+    mut typewriter: ResMut<TypewriterState>,
+) {
+    let Some(mut speaking) = speaking else { return };
+    let paused = pause.is_some_and(|p| *p.get() == PauseState::Paused);
+    if let Ok(sink) = sinks.get(speaking.sound) {
+        if paused != sink.is_paused() {
+            if paused { sink.pause() } else { sink.play() }
+        }
+    }
+    if paused { return; }
+
+    if actions.just_pressed(Action::Talk) || typewriter.full_text != speaking.text {
+        stop(&mut commands, &speaking, &mut typewriter);
+        return;
+    }
+
+    speaking.elapsed += time.delta_secs();
+    if speaking.elapsed >= speaking.utterance.duration() {
+        // Whatever is left is typed out as usual
+        typewriter.voiced_chars = None;
+        commands.remove_resource::<Speaking>();
+        return;
+    }
+    let heard = Some(speaking.utterance.chars_at(speaking.elapsed));
+    if typewriter.voiced_chars != heard {
+        typewriter.voiced_chars = heard;
+    }
+}
+
+fn stop(commands: &mut Commands, speaking: &Speaking, typewriter: &mut TypewriterState) {
+    if let Some(mut sound) = commands.get_entity(speaking.sound) {
+        sound.despawn();
+    }
+    typewriter.voiced_chars = None;
+    commands.remove_resource::<Speaking>();
+}
+
+/// The sentence of `text` that its `at`th character falls in
+fn sentence_at(text: &str, at: usize) -> String {
+    let chars: Vec<char> = text.chars().collect();
+    let at = at.saturating_sub(1).min(chars.len().saturating_sub(1));
+    let ends = |c: &char| matches!(c, '.' | '!' | '?' | '\n');
+    let start = chars[..at].iter().rposition(ends).map_or(0, |i| i + 1);
+    let end = chars[at..].iter().position(ends).map_or(chars.len(), |i| at + i + 1);
+    chars[start..end].iter().collect()
+}
+
+// ============================================================================
+// Caption — above the command bar, while the teacher speaks
+// ============================================================================
+
+fn spawn_caption(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(360.0),
+            left: Val::Percent(15.0),
+            right: Val::Percent(15.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        PickingBehavior::IGNORE,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(""),
+            TextFont { font_size: 18.0, ..default() },
+            TextColor(Color::srgb(1.0, 1.0, 1.0)),
+            TextLayout::new_with_justify(JustifyText::Center),
+            Node { padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)), ..default() },
+            BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+            Visibility::Hidden,
+            VoiceCaption,
+        ));
+    });
+}
+
+fn update_caption(
+    speaking: Option<Res<Speaking>>,
+    mut caption: Query<(&mut Text, &mut Visibility), With<VoiceCaption>>,
+) {
+    let line = speaking.map(|s| speakable(&sentence_at(&s.text, s.utterance.chars_at(s.elapsed))));
+    let line = line.filter(|line| !line.is_empty()).map(|line| format!("🔊 {}", line));
+
+    for (mut text, mut visibility) in &mut caption {
+        let shown = if line.is_some() { Visibility::Inherited } else { Visibility::Hidden };
+        if *visibility != shown {
+            *visibility = shown;
+        }
+        if let Some(line) = &line {
+            if text.0 != *line {
+                text.0 = line.clone();
+            }
+        }
+    }
+}
@@ -1,9 +1,12 @@
 use bevy::prelude::*;
 use crate::GameState;
+use crate::actions::{Action, Actions, InputContext};
+use crate::i18n::Locale;
 
 // ============================================================================
-// Title Screen — Menu → Boot → Playing
+// Title Screen — Menu → Boot → Playing, or Menu → Review
 // ============================================================================
+// The last menu option cycles the language; the screen is rebuilt in it.
 
 #[derive(Component)]
 struct TitleScreenRoot;
@@ -14,11 +17,33 @@ impl Plugin for TitleScreenPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(OnEnter(GameState::Menu), setup_title_screen)
            .add_systems(OnExit(GameState::Menu), despawn_title_screen)
-           .add_systems(Update, handle_title_input.run_if(in_state(GameState::Menu)));
+           .add_systems(Update, (
+               handle_title_input,
+               (despawn_title_screen, setup_title_screen).chain()
+                   .run_if(resource_changed::<Locale>.and(not(resource_added::<Locale>))),
+           ).run_if(in_state(GameState::Menu)));
     }
 }
 
-fn setup_title_screen(mut commands: Commands) {
+fn setup_title_screen(
+    mut commands: Commands,
+    deck: Res<crate::review::ReviewDeck>,
+    clock: Res<crate::review::ReviewClock>,
+    actions: Res<Actions>,
+    locale: Res<Locale>,
+) {
+    let review = actions.label(Action::Review);
+    let due = deck.due_ids(clock.0.now()).len();
+    let review_label = match (deck.cards.len(), due) {
+        (0, _) => String::new(),
+        (_, 0) => locale.fmt("title-review-none", &[("key", &review)]),
+        (_, n) => locale.fmt("title-review-due", &[("key", &review), ("count", &n)]),
+    };
+    let language_label = locale.fmt("title-language", &[
+        ("key", &actions.label(Action::Choice(language_choice(&deck) as u8))),
+        ("language", &locale.language().native_name()),
+    ]);
+
     // Full-screen background
     commands
         .spawn((
@@ -49,14 +74,15 @@ fn setup_title_screen(mut commands: Commands) {
 
             // Main title
             root.spawn((
-                Text::new("THE SOVEREIGN SANDBOX"),
+                Text::new(locale.t("title-name")),
                 TextFont { font_size: 52.0, ..default() },
                 TextColor(Color::srgb(0.75, 0.5, 1.0)), // Violet-400 equivalent
+                crate::narration::Narrated::default(),
             ));
 
             // Subtitle
             root.spawn((
-                Text::new("An AI Academy Adventure"),
+                Text::new(locale.t("title-subtitle")),
                 TextFont { font_size: 22.0, ..default() },
                 TextColor(Color::srgb(0.5, 0.6, 1.0)), // Indigo-300 equivalent
             ));
@@ -69,15 +95,36 @@ fn setup_title_screen(mut commands: Commands) {
 
             // START prompt (pulsing via animation would need a timer; static is fine)
             root.spawn((
-                Text::new("[ ENTER ]  Start Game"),
+                Text::new(locale.fmt("title-start", &[("key", &actions.label(Action::Confirm))])),
                 TextFont { font_size: 26.0, ..default() },
                 TextColor(Color::srgb(1.0, 1.0, 1.0)),
                 TitlePrompt,
+                crate::narration::Narrated::default(),
+            ));
+
+            // Spaced-repetition review
+            root.spawn((
+                Text::new(review_label),
+                TextFont { font_size: 18.0, ..default() },
+                TextColor(Color::srgb(0.0, 1.0, 0.5)),
+                crate::narration::Narrated::default(),
+            ));
+
+            // Language selector
+            root.spawn((
+                Text::new(language_label),
+                TextFont { font_size: 18.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.6, 1.0)),
+                crate::narration::Narrated::default(),
             ));
 
             // Controls hint
             root.spawn((
-                Text::new("WASD / Arrow Keys  — Move\n[T]  — Interact with objects\n[SPACE]  — Talk to the AI Architect\n[1][2][3]  — Dialogue choices"),
+                Text::new(locale.fmt("title-controls", &[
+                    ("interact", &actions.label(Action::Interact)),
+                    ("talk", &actions.label(Action::Talk)),
+                    ("choices", &actions.choices_label(3, &locale)),
+                ])),
                 TextFont { font_size: 14.0, ..default() },
                 TextColor(Color::srgb(0.5, 0.5, 0.5)),
                 Node {
@@ -99,7 +146,7 @@ fn setup_title_screen(mut commands: Commands) {
 
             // Credits
             root.spawn((
-                Text::new("Built with Rust & Bevy  |  The Local AI Architect  |  v0.2.0"),
+                Text::new(locale.t("title-credits")),
                 TextFont { font_size: 12.0, ..default() },
                 TextColor(Color::srgba(0.4, 0.4, 0.4, 0.8)),
             ));
@@ -109,20 +156,34 @@ fn setup_title_screen(mut commands: Commands) {
 #[derive(Component)]
 struct TitlePrompt;
 
+/// Index of the language option among the menu choices, after Review if offered
+fn language_choice(deck: &crate::review::ReviewDeck) -> usize {
+    if deck.cards.is_empty() { 1 } else { 2 }
+}
+
 fn handle_title_input(
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<Actions>,
     mut next_state: ResMut<NextState<GameState>>,
     time: Res<Time>,
+    settings: Res<crate::accessibility::AccessibilitySettings>,
     mut query: Query<&mut TextColor, With<TitlePrompt>>,
+    (deck, mut locale): (Res<crate::review::ReviewDeck>, ResMut<Locale>),
 ) {
     // Pulse the start prompt
-    let t = (time.elapsed_secs() * 2.5).sin() * 0.5 + 0.5;
+    let t = settings.pulse(time.elapsed_secs(), 2.5);
     for mut color in &mut query {
         *color = TextColor(Color::srgba(1.0, 1.0, 1.0, 0.4 + t * 0.6));
     }
 
-    if keys.just_pressed(KeyCode::Enter) || keys.just_pressed(KeyCode::Space) {
+    let choice = actions.choice(InputContext::Menu);
+    if choice == Some(language_choice(&deck)) {
+        let language = locale.language().next();
+        info!("🌐 Language: {}", language.native_name());
+        locale.set_language(language);
+    } else if choice == Some(0) || actions.just_pressed(Action::Confirm) || actions.just_pressed(Action::Talk) {
         next_state.set(GameState::Boot);
+    } else if choice == Some(1) || actions.just_pressed(Action::Review) {
+        next_state.set(GameState::Review);
     }
 }
 
@@ -0,0 +1,463 @@
+use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
+use crate::actions::{Action, Actions, Device, InputContext, OfferedChoices, VirtualInput, VirtualInputSet, MAX_CHOICES};
+use crate::story_mode::StoryState;
+use crate::i18n::Locale;
+
+// ============================================================================
+// Touch Controls — virtual joystick and context buttons for tablets
+// ============================================================================
+// The overlay stays hidden until the first touch, then replaces the keyboard
+// controls HUD. Touches are hit-tested against the overlay's own nodes (the UI
+// focus system only follows the first finger), so the stick and a button can
+// be held together. Buttons write `VirtualInput` and go through the same
+// context rules as keys. The options the current context offers
+// (`OfferedChoices`, the title menu's included) become tappable buttons;
+// puzzle tokens carry `TapChoice` and are tapped where they are. Text entry
+// opens an HTML input on the page, which brings up the on-screen keyboard.
+
+/// Fraction of the stick's radius that reads as centred
+const STICK_DEADZONE: f32 = 0.15;
+
+/// The stick is grabbed this far outside its base, for sloppy thumbs
+const STICK_GRAB_MARGIN: f32 = 1.5;
+
+/// Longest option text shown on a choice button
+const CHOICE_TEXT_LEN: usize = 40;
+
+const BUTTON_COLOR: Color = Color::srgba(0.1, 0.1, 0.15, 0.75);
+const BUTTON_HELD_COLOR: Color = Color::srgba(0.39, 0.40, 0.95, 0.85);
+
+/// Tapping this node picks option `n` of the context taking choices
+#[derive(Component)]
+pub struct TapChoice(pub u8);
+
+#[derive(Component)]
+struct TouchOverlay;
+
+#[derive(Component)]
+struct TouchStick;
+
+#[derive(Component)]
+struct TouchKnob;
+
+#[derive(Component)]
+struct TouchButton(Action);
+
+pub struct TouchPlugin;
+
+impl Plugin for TouchPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Startup, spawn_touch_overlay)
+           .add_systems(PreUpdate, (read_touches, sync_text_bridge).chain().in_set(VirtualInputSet))
+           .add_systems(Update, (
+               localize_touch_buttons.run_if(resource_changed::<Locale>),
+               update_touch_buttons,
+               update_stick,
+           ).chain());
+    }
+}
+
+/// Text on the on-screen button for `action`, if it has one
+fn button_text(action: Action, locale: &Locale) -> Option<String> {
+    let key = match action {
+        Action::Interact => "touch-interact",
+        Action::Attack => "touch-attack",
+        Action::Talk => "touch-talk",
+        Action::Map => "touch-map",
+        Action::Confirm => "touch-confirm",
+        Action::Cancel => "touch-back",
+        Action::Review => "touch-review",
+        Action::Pause => "touch-pause",
+        Action::Choice(n) => return Some((n + 1).to_string()),
+        _ => return None,
+    };
+    Some(locale.t(key))
+}
+
+/// Button names follow the language, on the buttons and in prompts
+fn localize_touch_buttons(
+    locale: Res<Locale>,
+    mut actions: ResMut<Actions>,
+    mut buttons: Query<(&TouchButton, &mut Text)>,
+) {
+    let mut names = std::collections::HashMap::new();
+    for (button, mut text) in &mut buttons {
+        let Some(name) = button_text(button.0, &locale) else { continue };
+        if !matches!(button.0, Action::Choice(_)) {
+            names.insert(button.0, name.clone());
+        }
+        text.0 = name;
+    }
+    actions.set_touch_names(names);
+}
+
+/// Whether the button for `action` belongs on screen right now
+fn button_shown(action: Action, context: InputContext, choices: usize) -> bool {
+    let walking = matches!(context, InputContext::World | InputContext::Dialogue | InputContext::Quiz);
+    match action {
+        Action::Choice(n) => context.takes_choices() && context != InputContext::Puzzle && (n as usize) < choices,
+        Action::Interact | Action::Attack | Action::Talk | Action::Map | Action::Pause => walking,
+        Action::Confirm => matches!(context, InputContext::Menu | InputContext::TextEntry | InputContext::Review),
+        Action::Cancel => matches!(context, InputContext::TextEntry | InputContext::Review | InputContext::Puzzle
+            | InputContext::Pause),
+        _ => false,
+    }
+}
+
+// ============================================================================
+// Overlay
+// ============================================================================
+
+fn spawn_touch_overlay(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            ..default()
+        },
+        Visibility::Hidden,
+        GlobalZIndex(300),
+        TouchOverlay,
+    )).with_children(|overlay| {
+        // Virtual joystick, bottom-left
+        overlay.spawn((
+            Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(30.0),
+                bottom: Val::Px(30.0),
+                width: Val::Px(140.0),
+                height: Val::Px(140.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.08)),
+            BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.3)),
+            BorderRadius::MAX,
+            TouchStick,
+        )).with_children(|stick| {
+            stick.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Px(40.0),
+                    top: Val::Px(40.0),
+                    width: Val::Px(56.0),
+                    height: Val::Px(56.0),
+                    ..default()
+                },
+                BackgroundColor(Color::srgba(1.0, 1.0, 1.0, 0.35)),
+                BorderRadius::MAX,
+                TouchKnob,
+            ));
+        });
+
+        // Context buttons, bottom-right
+        overlay.spawn(Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(30.0),
+            bottom: Val::Px(30.0),
+            flex_direction: FlexDirection::ColumnReverse,
+            align_items: AlignItems::End,
+            row_gap: Val::Px(12.0),
+            ..default()
+        }).with_children(|column| {
+            for action in [Action::Talk, Action::Interact, Action::Attack, Action::Map,
+                           Action::Pause, Action::Confirm, Action::Cancel] {
+                spawn_button(column, action, 22.0);
+            }
+        });
+
+        // Offered options, right-centre
+        overlay.spawn(Node {
+            position_type: PositionType::Absolute,
+            right: Val::Px(30.0),
+            top: Val::Percent(15.0),
+            max_width: Val::Percent(45.0),
+            flex_direction: FlexDirection::Column,
+            align_items: AlignItems::Stretch,
+            row_gap: Val::Px(10.0),
+            ..default()
+        }).with_children(|column| {
+            for n in 0..MAX_CHOICES {
+                spawn_button(column, Action::Choice(n), 18.0);
+            }
+        });
+    });
+}
+
+fn spawn_button(parent: &mut ChildBuilder, action: Action, font_size: f32) {
+    parent.spawn((
+        Text::new(""),
+        TextFont { font_size, ..default() },
+        TextColor(Color::WHITE),
+        Node {
+            padding: UiRect::axes(Val::Px(22.0), Val::Px(14.0)),
+            border: UiRect::all(Val::Px(2.0)),
+            ..default()
+        },
+        BackgroundColor(BUTTON_COLOR),
+        BorderColor(Color::srgba(1.0, 1.0, 1.0, 0.3)),
+        BorderRadius::all(Val::Px(12.0)),
+        Visibility::Hidden,
+        TouchButton(action),
+    ));
+}
+
+// ============================================================================
+// Input
+// ============================================================================
+
+fn node_rect(node: &ComputedNode, transform: &GlobalTransform) -> Rect {
+    Rect::from_center_size(transform.translation().truncate(), node.size())
+}
+
+/// Turns this frame's touches into virtual actions
+fn read_touches(
+    touches: Res<Touches>,
+    windows: Query<&Window, With<PrimaryWindow>>,
+    buttons: Query<(&TouchButton, &ComputedNode, &GlobalTransform, &InheritedVisibility)>,
+    taps: Query<(&TapChoice, &ComputedNode, &GlobalTransform, &InheritedVisibility)>,
+    sticks: Query<(&ComputedNode, &GlobalTransform, &InheritedVisibility), With<TouchStick>>,
+    mut stick_touch: Local<Option<u64>>,
+    mut input: ResMut<VirtualInput>,
+) {
+    let input = &mut *input;
+    input.touched = touches.any_just_pressed();
+
+    // Touch positions are logical; UI nodes are laid out in physical pixels
+    let scale = windows.get_single().map(|w| w.scale_factor()).unwrap_or(1.0);
+    let stick = sticks.get_single().ok().filter(|(_, _, visible)| visible.get()).map(|(n, t, _)| node_rect(n, t));
+
+    if stick_touch.is_some_and(|id| touches.get_pressed(id).is_none()) || stick.is_none() {
+        *stick_touch = None;
+    }
+    if let (None, Some(rect)) = (*stick_touch, stick) {
+        let grab = Rect::from_center_size(rect.center(), rect.size() * STICK_GRAB_MARGIN);
+        *stick_touch = touches.iter_just_pressed().find(|t| grab.contains(t.position() * scale)).map(|t| t.id());
+    }
+    if let (Some(touch), Some(rect)) = (stick_touch.and_then(|id| touches.get_pressed(id)), stick) {
+        let offset = (touch.position() * scale - rect.center()) / (rect.width() / 2.0);
+        let offset = Vec2::new(offset.x, -offset.y).clamp_length_max(1.0);
+        if offset.length() > STICK_DEADZONE {
+            input.stick = offset;
+        }
+    }
+
+    for touch in touches.iter().filter(|t| Some(t.id()) != *stick_touch) {
+        let position = touch.position() * scale;
+        let just = touches.just_pressed(touch.id());
+        for (button, node, transform, visible) in &buttons {
+            if !visible.get() || !node_rect(node, transform).contains(position) { continue; }
+            input.pressed.insert(button.0);
+            if just {
+                input.just_pressed.insert(button.0);
+            }
+        }
+        if !just { continue; }
+        for (tap, node, transform, visible) in &taps {
+            if visible.get() && node_rect(node, transform).contains(position) {
+                input.just_pressed.insert(Action::Choice(tap.0));
+            }
+        }
+    }
+}
+
+/// Mirrors the typing prompt into an HTML input while playing by touch
+fn sync_text_bridge(
+    context: Res<InputContext>,
+    actions: Res<Actions>,
+    mut story_state: ResMut<StoryState>,
+    mut input: ResMut<VirtualInput>,
+    mut open: Local<bool>,
+) {
+    let typing = *context == InputContext::TextEntry && actions.device() == Device::Touch;
+    if !typing {
+        if *open {
+            text_bridge::close();
+            *open = false;
+        }
+        return;
+    }
+    // Tapping the game takes focus from the input; tapping again gives it back
+    if !*open || input.touched {
+        text_bridge::open(&story_state.player_input);
+        *open = true;
+    }
+
+    if let Some(value) = text_bridge::value() {
+        if story_state.player_input != value {
+            story_state.player_input = value;
+        }
+    }
+    if text_bridge::take_submitted() {
+        input.just_pressed.insert(Action::Confirm);
+    }
+}
+
+// ============================================================================
+// Display
+// ============================================================================
+
+fn update_touch_buttons(
+    actions: Res<Actions>,
+    context: Res<InputContext>,
+    input: Res<VirtualInput>,
+    offered: Res<OfferedChoices>,
+    locale: Res<Locale>,
+    mut overlay: Query<&mut Visibility, (With<TouchOverlay>, Without<TouchButton>)>,
+    mut buttons: Query<(&TouchButton, &mut Visibility, &mut BackgroundColor, &mut Text)>,
+) {
+    let touch = actions.device() == Device::Touch;
+    for mut visibility in &mut overlay {
+        let next = if touch { Visibility::Inherited } else { Visibility::Hidden };
+        if *visibility != next {
+            *visibility = next;
+        }
+    }
+    if !touch { return; }
+
+    for (button, mut visibility, mut background, mut text) in &mut buttons {
+        let next = if button_shown(button.0, *context, offered.0.len()) {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *visibility != next {
+            *visibility = next;
+        }
+
+        let color = if input.pressed.contains(&button.0) { BUTTON_HELD_COLOR } else { BUTTON_COLOR };
+        if background.0 != color {
+            background.0 = color;
+        }
+
+        let label = match button.0 {
+            Action::Choice(n) => match offered.0.get(n as usize) {
+                Some(option) if option.chars().count() > CHOICE_TEXT_LEN => {
+                    format!("{}. {}…", n + 1, option.chars().take(CHOICE_TEXT_LEN).collect::<String>())
+                }
+                Some(option) => format!("{}. {}", n + 1, option),
+                None => continue,
+            },
+            Action::Confirm if *context == InputContext::TextEntry => locale.t("touch-send"),
+            Action::Confirm => locale.t("touch-confirm"),
+            _ => continue,
+        };
+        if text.0 != label {
+            text.0 = label;
+        }
+    }
+}
+
+fn update_stick(
+    input: Res<VirtualInput>,
+    sticks: Query<&ComputedNode, With<TouchStick>>,
+    mut knobs: Query<(&mut Node, &ComputedNode), With<TouchKnob>>,
+) {
+    let Ok(base) = sticks.get_single() else { return };
+    let Ok((mut knob, knob_size)) = knobs.get_single_mut() else { return };
+
+    // Layout sizes are physical; node offsets are logical
+    let scale = base.inverse_scale_factor();
+    let radius = base.size().x * scale / 2.0;
+    let half_knob = knob_size.size().x * scale / 2.0;
+    let left = Val::Px(radius - half_knob + input.stick.x * radius);
+    let top = Val::Px(radius - half_knob - input.stick.y * radius);
+    if knob.left != left || knob.top != top {
+        knob.left = left;
+        knob.top = top;
+    }
+}
+
+// ============================================================================
+// HTML Text Bridge — the page's own input brings up the on-screen keyboard
+// ============================================================================
+
+#[cfg(target_arch = "wasm32")]
+mod text_bridge {
+    use std::cell::Cell;
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::JsCast;
+    use web_sys::{HtmlInputElement, KeyboardEvent};
+
+    const INPUT_ID: &str = "sandbox-text-input";
+
+    thread_local! {
+        static SUBMITTED: Cell<bool> = const { Cell::new(false) };
+    }
+
+    /// The page's input, created on first use
+    fn input() -> Option<HtmlInputElement> {
+        let document = web_sys::window()?.document()?;
+        if let Some(existing) = document.get_element_by_id(INPUT_ID) {
+            return existing.dyn_into().ok();
+        }
+
+        let input: HtmlInputElement = document.create_element("input").ok()?.dyn_into().ok()?;
+        input.set_id(INPUT_ID);
+        input.set_type("text");
+        let _ = input.set_attribute("enterkeyhint", "send");
+        let _ = input.set_attribute("autocomplete", "off");
+        let _ = input.set_attribute(
+            "style",
+            "position:fixed;left:10%;width:80%;top:12%;z-index:10;display:none;\
+             font-size:18px;padding:10px;border-radius:8px;border:2px solid #6366f1;\
+             background:#0f0f1a;color:#fff;",
+        );
+
+        let on_key = Closure::<dyn FnMut(KeyboardEvent)>::new(|event: KeyboardEvent| {
+            if event.key() == "Enter" {
+                SUBMITTED.with(|submitted| submitted.set(true));
+            }
+        });
+        let _ = input.add_event_listener_with_callback("keydown", on_key.as_ref().unchecked_ref());
+        // Lives as long as the page
+        on_key.forget();
+
+        document.body()?.append_child(&input).ok()?;
+        Some(input)
+    }
+
+    pub fn open(text: &str) {
+        let Some(input) = input() else { return };
+        if input.style().get_property_value("display").ok().as_deref() == Some("none") {
+            input.set_value(text);
+        }
+        let _ = input.style().set_property("display", "block");
+        let _ = input.focus();
+    }
+
+    pub fn close() {
+        let Some(input) = input() else { return };
+        input.set_value("");
+        let _ = input.blur();
+        let _ = input.style().set_property("display", "none");
+        SUBMITTED.with(|submitted| submitted.set(false));
+    }
+
+    pub fn value() -> Option<String> {
+        input().map(|input| input.value())
+    }
+
+    /// Whether Enter was pressed in the input since the last call
+    pub fn take_submitted() -> bool {
+        SUBMITTED.with(|submitted| submitted.replace(false))
+    }
+}
+
+/// Native builds type straight into the game
+#[cfg(not(target_arch = "wasm32"))]
+mod text_bridge {
+    pub fn open(_text: &str) {}
+
+    pub fn close() {}
+
+    pub fn value() -> Option<String> {
+        None
+    }
+
+    pub fn take_submitted() -> bool {
+        false
+    }
+}
@@ -0,0 +1,648 @@
+use anyhow::Result;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::f32::consts::{PI, TAU};
+
+// ============================================================================
+// TTS — offline text-to-speech for the teacher's lines
+// ============================================================================
+// A `TtsBackend` turns a line into an `Utterance`: mono samples plus the time
+// each word starts, so the dialogue box can reveal words as they're heard.
+// `FormantSynth` is the built-in backend and needs no model files: letters
+// become rough phones (Spanish is read as it's spelled, English with rules for
+// silent e, long vowels and common digraphs), and each phone is a buzz from
+// the vocal folds and/or a hiss, shaped by three formant filters. Pitch falls
+// across a sentence and rises at a question mark. Robotic, but clear enough to
+// follow along with the text. Kept free of ECS types so lines can be rendered
+// and tested with no audio device.
+
+pub const SAMPLE_RATE: u32 = 22_050;
+
+/// Turns text into speech
+pub trait TtsBackend: Send + Sync {
+    /// Speaks `text` in `language` (`en`, `es`)
+    fn synthesize(&self, text: &str, language: &str) -> Result<Utterance>;
+}
+
+/// When a word starts being heard
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WordMark {
+    /// Seconds into the utterance
+    pub time: f32,
+    /// Characters of the text up to the end of the word
+    pub end: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Utterance {
+    /// Mono, -1..1
+    pub samples: Vec<f32>,
+    pub rate: u32,
+    pub words: Vec<WordMark>,
+    /// Characters in the text that was spoken
+    pub chars: usize,
+}
+
+impl Utterance {
+    pub fn duration(&self) -> f32 {
+        if self.rate == 0 { 0.0 } else { self.samples.len() as f32 / self.rate as f32 }
+    }
+
+    /// Characters of the text heard `t` seconds in: each word as it starts,
+    /// everything once the utterance is over
+    pub fn chars_at(&self, t: f32) -> usize {
+        if t >= self.duration() {
+            return self.chars;
+        }
+        self.words.iter().take_while(|w| w.time <= t).last().map_or(0, |w| w.end)
+    }
+
+    /// 16-bit mono PCM WAV, as Bevy's `AudioSource` and any player read it
+    pub fn to_wav(&self) -> Vec<u8> {
+        crate::sfx_synth::to_wav_at(&self.samples, self.rate)
+    }
+}
+
+// ============================================================================
+// Formant Synth
+// ============================================================================
+
+/// Resonator settings are refreshed this often, in samples
+const RETUNE: usize = 64;
+
+/// How long a parameter takes to glide most of the way to its target, in
+/// seconds; smooths phone boundaries into transitions
+const GLIDE: f32 = 0.012;
+
+/// Share of each glottal cycle the vocal folds are open
+const OPEN_PHASE: f32 = 0.6;
+
+/// Formant filter widths, in Hz
+const BANDWIDTHS: [f32; 3] = [80.0, 100.0, 140.0];
+
+/// Formant levels relative to the first
+const FORMANT_GAINS: [f32; 3] = [1.0, 0.55, 0.3];
+
+const PEAK: f32 = 0.8;
+
+#[derive(Debug, Clone, Copy)]
+pub struct FormantSynth {
+    /// Voice pitch at the start of a sentence, in Hz
+    pub pitch: f32,
+    /// Speaking rate; 1 is a steady classroom pace
+    pub speed: f32,
+}
+
+impl Default for FormantSynth {
+    fn default() -> Self {
+        Self { pitch: 120.0, speed: 1.0 }
+    }
+}
+
+impl TtsBackend for FormantSynth {
+    fn synthesize(&self, text: &str, language: &str) -> Result<Utterance> {
+        let (segments, words) = layout(text, language, self.speed.max(0.1));
+        Ok(Utterance {
+            samples: render(&segments, self.pitch.max(40.0)),
+            rate: SAMPLE_RATE,
+            words,
+            chars: text.chars().count(),
+        })
+    }
+}
+
+/// Rough phones; `Ae` to `Uh` are English's short vowels
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Sound {
+    A, E, I, O, U,
+    Ae, Eh, Ih, Ah, Uh,
+    P, B, T, D, K, G,
+    F, V, S, Z, Sh, Zh, Th, H, X,
+    Ch, J,
+    M, N, L, R, W, Y,
+}
+
+/// A stretch of sound with steady targets
+#[derive(Debug, Clone, Copy)]
+struct Segment {
+    length: f32,
+    /// `None` holds whatever the mouth was shaping (stop closures)
+    formants: Option<[f32; 3]>,
+    /// Buzz from the vocal folds, 0-1
+    voice: f32,
+    /// Hiss, 0-1, centred on `hiss` Hz
+    noise: f32,
+    hiss: f32,
+    /// Ends a sentence; `Some(true)` for a question
+    sentence_end: Option<bool>,
+}
+
+impl Segment {
+    fn silence(length: f32) -> Self {
+        Self { length, formants: None, voice: 0.0, noise: 0.0, hiss: 1000.0, sentence_end: None }
+    }
+
+    fn vowel(formants: [f32; 3]) -> Self {
+        Self { length: 0.1, formants: Some(formants), voice: 1.0, ..Self::silence(0.0) }
+    }
+
+    fn voiced(formants: [f32; 3], voice: f32) -> Self {
+        Self { length: 0.065, formants: Some(formants), voice, ..Self::silence(0.0) }
+    }
+
+    fn hiss(hiss: f32, noise: f32, voice: f32) -> Self {
+        Self { length: 0.085, voice, noise, hiss, ..Self::silence(0.0) }
+    }
+
+    /// A stop: the mouth closes, then bursts open
+    fn stop(hiss: f32, voiced: bool) -> [Self; 2] {
+        let closure = Self { voice: if voiced { 0.15 } else { 0.0 }, ..Self::silence(0.05) };
+        [closure, Self { length: 0.02, noise: 0.6, hiss, ..Self::silence(0.0) }]
+    }
+}
+
+fn segments_for(sound: Sound) -> Vec<Segment> {
+    use Sound::*;
+    match sound {
+        A => vec![Segment::vowel([700.0, 1250.0, 2500.0])],
+        E => vec![Segment::vowel([450.0, 1950.0, 2600.0])],
+        I => vec![Segment::vowel([300.0, 2250.0, 3000.0])],
+        O => vec![Segment::vowel([480.0, 900.0, 2500.0])],
+        U => vec![Segment::vowel([320.0, 800.0, 2300.0])],
+        Ae => vec![Segment::vowel([660.0, 1720.0, 2410.0])],
+        Eh => vec![Segment::vowel([550.0, 1770.0, 2490.0])],
+        Ih => vec![Segment::vowel([400.0, 1920.0, 2560.0])],
+        Ah => vec![Segment::vowel([730.0, 1090.0, 2440.0])],
+        Uh => vec![Segment::vowel([640.0, 1190.0, 2390.0])],
+        P => Segment::stop(1000.0, false).to_vec(),
+        B => Segment::stop(1000.0, true).to_vec(),
+        T => Segment::stop(4500.0, false).to_vec(),
+        D => Segment::stop(4000.0, true).to_vec(),
+        K => Segment::stop(2200.0, false).to_vec(),
+        G => Segment::stop(2000.0, true).to_vec(),
+        F => vec![Segment::hiss(7000.0, 0.18, 0.0)],
+        V => vec![Segment::hiss(6000.0, 0.12, 0.35)],
+        S => vec![Segment::hiss(5500.0, 0.35, 0.0)],
+        Z => vec![Segment::hiss(5500.0, 0.2, 0.35)],
+        Sh => vec![Segment::hiss(2800.0, 0.4, 0.0)],
+        Zh => vec![Segment::hiss(2800.0, 0.25, 0.35)],
+        Th => vec![Segment::hiss(6500.0, 0.12, 0.0)],
+        H => vec![Segment { length: 0.05, ..Segment::hiss(1500.0, 0.2, 0.0) }],
+        X => vec![Segment::hiss(1800.0, 0.45, 0.0)],
+        Ch => [segments_for(T), segments_for(Sh)].concat(),
+        J => [segments_for(D), segments_for(Zh)].concat(),
+        M => vec![Segment::voiced([280.0, 1100.0, 2300.0], 0.5)],
+        N => vec![Segment::voiced([280.0, 1700.0, 2600.0], 0.5)],
+        L => vec![Segment::voiced([360.0, 1300.0, 2700.0], 0.6)],
+        R => vec![Segment::voiced([420.0, 1300.0, 1600.0], 0.6)],
+        W => vec![Segment::voiced([300.0, 700.0, 2200.0], 0.6)],
+        Y => vec![Segment::voiced([280.0, 2200.0, 2900.0], 0.6)],
+    }
+}
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u')
+}
+
+/// Lowercase, accents dropped (ñ kept)
+fn fold(c: char) -> char {
+    match c.to_lowercase().next().unwrap_or(c) {
+        'á' | 'à' | 'â' | 'ä' => 'a',
+        'é' | 'è' | 'ê' | 'ë' => 'e',
+        'í' | 'ì' | 'î' | 'ï' => 'i',
+        'ó' | 'ò' | 'ô' | 'ö' => 'o',
+        'ú' | 'ù' | 'û' | 'ü' => 'u',
+        c => c,
+    }
+}
+
+/// Spanish is spelled as it sounds
+fn spanish(word: &[char]) -> Vec<Sound> {
+    use Sound::*;
+    let mut sounds = Vec::new();
+    let at = |i: usize| word.get(i).copied().unwrap_or(' ');
+    let mut i = 0;
+    while i < word.len() {
+        let (prev, c, next) = (if i > 0 { at(i - 1) } else { ' ' }, at(i), at(i + 1));
+        let soft = matches!(next, 'e' | 'i');
+        match c {
+            'a' => sounds.push(A),
+            'e' => sounds.push(E),
+            'i' => sounds.push(I),
+            'o' => sounds.push(O),
+            // Silent in que, qui, gue, gui
+            'u' if prev == 'q' || (prev == 'g' && soft) => {}
+            'u' => sounds.push(U),
+            'b' | 'v' => sounds.push(B),
+            'c' if next == 'h' => { sounds.push(Ch); i += 1; }
+            'c' if soft => sounds.push(S),
+            'c' | 'k' | 'q' => sounds.push(K),
+            'd' => sounds.push(D),
+            'f' => sounds.push(F),
+            'g' if soft => sounds.push(X),
+            'g' => sounds.push(G),
+            'h' => {}
+            'j' => sounds.push(X),
+            'l' if next == 'l' => { sounds.push(Y); i += 1; }
+            'l' => sounds.push(L),
+            'm' => sounds.push(M),
+            'n' => sounds.push(N),
+            'ñ' => sounds.extend([N, Y]),
+            'p' => sounds.push(P),
+            'r' if next == 'r' => { sounds.extend([R, R]); i += 1; }
+            'r' => sounds.push(R),
+            's' | 'z' => sounds.push(S),
+            't' => sounds.push(T),
+            'w' => sounds.push(W),
+            'x' => sounds.extend([K, S]),
+            'y' if is_vowel(next) => sounds.push(Y),
+            'y' => sounds.push(I),
+            _ => {}
+        }
+        i += 1;
+    }
+    sounds
+}
+
+/// Words English spelling rules get badly wrong
+fn english_exception(word: &str) -> Option<Vec<Sound>> {
+    use Sound::*;
+    Some(match word {
+        "a" => vec![Uh],
+        "i" => vec![A, I],
+        "the" => vec![Th, Uh],
+        "to" | "too" | "two" => vec![T, U],
+        "do" => vec![D, U],
+        "who" => vec![H, U],
+        "you" => vec![Y, U],
+        "your" | "you're" => vec![Y, O, R],
+        "of" => vec![Uh, V],
+        "is" => vec![Ih, Z],
+        "as" => vec![Ae, Z],
+        "has" => vec![H, Ae, Z],
+        "was" => vec![W, Uh, Z],
+        "are" => vec![Ah, R],
+        "one" => vec![W, Uh, N],
+        "what" => vec![W, Uh, T],
+        "said" => vec![S, Eh, D],
+        "have" => vec![H, Ae, V],
+        "give" => vec![G, Ih, V],
+        "live" => vec![L, Ih, V],
+        "come" => vec![K, Uh, M],
+        "some" => vec![S, Uh, M],
+        "done" => vec![D, Uh, N],
+        "there" | "their" => vec![Th, Eh, R],
+        "where" => vec![W, Eh, R],
+        "were" => vec![W, Uh, R],
+        _ => return None,
+    })
+}
+
+/// English, by rule: silent final e lengthens the vowel before it, short
+/// vowels otherwise, and the usual digraphs
+fn english(word: &[char]) -> Vec<Sound> {
+    use Sound::*;
+    let word: Vec<char> = word.iter().copied().filter(|c| c.is_ascii_alphabetic()).collect();
+    if let Some(sounds) = english_exception(&word.iter().collect::<String>()) {
+        return sounds;
+    }
+
+    let mut end = word.len();
+    let mut long = None;
+    // Silent e: "make", "time", "note"
+    if end > 2 && word[end - 1] == 'e' && !is_vowel(word[end - 2]) && word[..end - 2].iter().any(|c| is_vowel(*c)) {
+        long = word[..end - 2].iter().rposition(|c| is_vowel(*c));
+        end -= 1;
+    }
+    // Short words ending in a vowel say it long: "he", "go", "she"
+    if long.is_none() && end <= 3 && end > 1 && matches!(word[end - 1], 'e' | 'o') && !word[..end - 1].iter().any(|c| is_vowel(*c)) {
+        long = Some(end - 1);
+    }
+
+    let word = &word[..end];
+    let at = |i: usize| word.get(i).copied().unwrap_or(' ');
+    let mut sounds = Vec::new();
+    let mut i = 0;
+    while i < word.len() {
+        let (c, next) = (at(i), at(i + 1));
+        let pair: String = [c, next].iter().collect();
+        let digraph: Option<&[Sound]> = match pair.as_str() {
+            "th" => Some(&[Th]),
+            "sh" => Some(&[Sh]),
+            "ch" => Some(&[Ch]),
+            "ph" => Some(&[F]),
+            "ck" => Some(&[K]),
+            "wh" => Some(&[W]),
+            "qu" => Some(&[K, W]),
+            "ng" => Some(&[N]),
+            "ee" | "ea" | "ie" => Some(&[I]),
+            "oo" => Some(&[U]),
+            "ou" | "ow" => Some(&[A, U]),
+            "ai" | "ay" | "ei" | "ey" => Some(&[E, I]),
+            "oa" => Some(&[O, U]),
+            "oi" | "oy" => Some(&[O, I]),
+            "au" | "aw" => Some(&[Ah]),
+            _ => None,
+        };
+        if let Some(digraph) = digraph {
+            sounds.extend_from_slice(digraph);
+            i += 2;
+            continue;
+        }
+        // Doubled consonants are said once
+        if i > 0 && c == at(i - 1) && !is_vowel(c) {
+            i += 1;
+            continue;
+        }
+        let is_long = long == Some(i);
+        match c {
+            'a' if is_long => sounds.extend([E, I]),
+            'e' if is_long => sounds.push(I),
+            'i' if is_long => sounds.extend([A, I]),
+            'o' if is_long => sounds.extend([O, U]),
+            'u' if is_long => sounds.extend([Y, U]),
+            'a' => sounds.push(Ae),
+            'e' => sounds.push(Eh),
+            'i' => sounds.push(Ih),
+            'o' => sounds.push(Ah),
+            'u' => sounds.push(Uh),
+            'y' if i == 0 && is_vowel(next) => sounds.push(Y),
+            'y' if i + 1 == word.len() && i > 0 => sounds.push(I),
+            'y' => sounds.push(Ih),
+            'b' => sounds.push(B),
+            'c' if matches!(next, 'e' | 'i' | 'y') => sounds.push(S),
+            'c' | 'k' | 'q' => sounds.push(K),
+            'd' => sounds.push(D),
+            'f' => sounds.push(F),
+            'g' => sounds.push(G),
+            'h' => sounds.push(H),
+            'j' => sounds.push(J),
+            'l' => sounds.push(L),
+            'm' => sounds.push(M),
+            'n' => sounds.push(N),
+            'p' => sounds.push(P),
+            'r' => sounds.push(R),
+            // "is", "has", "was" aside, a final s after a voiced sound buzzes
+            's' if i + 1 == word.len() && i > 0 && !matches!(at(i - 1), 'p' | 't' | 'k' | 'f' | 'c') => sounds.push(Z),
+            's' => sounds.push(S),
+            't' => sounds.push(T),
+            'v' => sounds.push(V),
+            'w' => sounds.push(W),
+            'x' => sounds.extend([K, S]),
+            'z' => sounds.push(Z),
+            _ => {}
+        }
+        i += 1;
+    }
+    sounds
+}
+
+/// Digits are read one at a time, spelled the way Spanish would say them
+fn digit(d: char, language: &str) -> &'static str {
+    const ENGLISH: [&str; 10] = ["siro", "uan", "tu", "zri", "for", "faif", "siks", "seven", "eit", "nain"];
+    const SPANISH: [&str; 10] = ["cero", "uno", "dos", "tres", "cuatro", "cinco", "seis", "siete", "ocho", "nueve"];
+    let names = if language == "es" { SPANISH } else { ENGLISH };
+    d.to_digit(10).map_or("", |d| names[d as usize])
+}
+
+fn sounds(word: &str, language: &str) -> Vec<Sound> {
+    let letters: Vec<char> = word.chars().map(fold).collect();
+    if letters.iter().all(|c| c.is_ascii_digit()) {
+        let spoken: Vec<char> = letters.iter().flat_map(|d| digit(*d, language).chars()).collect();
+        return spanish(&spoken);
+    }
+    match language {
+        "es" => spanish(&letters),
+        _ => english(&letters),
+    }
+}
+
+/// Pause after a word, by the punctuation that follows it
+fn pause(punctuation: &str) -> Segment {
+    if punctuation.contains('?') {
+        Segment { sentence_end: Some(true), ..Segment::silence(0.4) }
+    } else if punctuation.contains(['.', '!', '\n']) {
+        Segment { sentence_end: Some(false), ..Segment::silence(0.4) }
+    } else if punctuation.contains([',', ';', ':', '-', '(', ')']) {
+        Segment::silence(0.2)
+    } else {
+        Segment::silence(0.06)
+    }
+}
+
+/// Lays `text` out as segments, noting where each word starts. Words are runs
+/// of letters (apostrophes included) or of digits.
+fn layout(text: &str, language: &str, speed: f32) -> (Vec<Segment>, Vec<WordMark>) {
+    let chars: Vec<char> = text.chars().collect();
+    let mut segments = vec![Segment::silence(0.05)];
+    let mut words = Vec::new();
+    let mut time = 0.05 / speed;
+    let mut i = 0;
+    while i < chars.len() {
+        if !chars[i].is_alphanumeric() {
+            i += 1;
+            continue;
+        }
+        let digits = chars[i].is_ascii_digit();
+        let start = i;
+        while i < chars.len() && (if digits { chars[i].is_ascii_digit() } else { chars[i].is_alphabetic() || chars[i] == '\'' }) {
+            i += 1;
+        }
+        let word: String = chars[start..i].iter().collect();
+        let spoken: Vec<Segment> = sounds(&word, language).into_iter().flat_map(segments_for).collect();
+        if spoken.is_empty() { continue; }
+
+        words.push(WordMark { time, end: i });
+        let gap_end = chars[i..].iter().position(|c| c.is_alphanumeric()).map_or(chars.len(), |n| i + n);
+        let gap: String = chars[i..gap_end].iter().collect();
+        for segment in spoken.into_iter().chain([pause(&gap)]) {
+            let segment = Segment { length: segment.length / speed, ..segment };
+            time += segment.length;
+            segments.push(segment);
+        }
+    }
+    if let Some(last) = segments.last_mut() {
+        last.sentence_end.get_or_insert(text.trim_end().ends_with('?'));
+    }
+    (segments, words)
+}
+
+/// A two-pole band-pass with unit gain at its centre
+#[derive(Default)]
+struct Resonator {
+    gain: f32,
+    b1: f32,
+    b2: f32,
+    x1: f32,
+    x2: f32,
+    y1: f32,
+    y2: f32,
+}
+
+impl Resonator {
+    fn tune(&mut self, freq: f32, bandwidth: f32) {
+        let rate = SAMPLE_RATE as f32;
+        let freq = freq.min(rate * 0.45);
+        let r = (-PI * bandwidth / rate).exp();
+        self.b1 = 2.0 * r * (TAU * freq / rate).cos();
+        self.b2 = -r * r;
+        self.gain = (1.0 - r * r) / 2.0;
+    }
+
+    fn process(&mut self, x: f32) -> f32 {
+        let y = self.gain * (x - self.x2) + self.b1 * self.y1 + self.b2 * self.y2;
+        (self.x2, self.x1) = (self.x1, x);
+        (self.y2, self.y1) = (self.y1, y);
+        y
+    }
+}
+
+/// Pitch over a sentence: a gentle fall, or a rise at the end of a question
+fn intonation(progress: f32, question: bool) -> f32 {
+    if question && progress > 0.7 {
+        0.95 + (progress - 0.7) / 0.3 * 0.35
+    } else {
+        1.1 - 0.25 * progress
+    }
+}
+
+fn render(segments: &[Segment], pitch: f32) -> Vec<f32> {
+    let rate = SAMPLE_RATE as f32;
+    let glide = 1.0 - (-1.0 / (GLIDE * rate)).exp();
+    let mut rng = StdRng::seed_from_u64(0);
+
+    // Sentences, as sample ranges, for the pitch contour
+    let mut sentences = Vec::new();
+    let (mut start, mut at) = (0, 0);
+    for segment in segments {
+        at += (segment.length * rate) as usize;
+        if let Some(question) = segment.sentence_end {
+            sentences.push((start, at.max(start + 1), question));
+            start = at;
+        }
+    }
+
+    let mut formants = [500.0, 1500.0, 2500.0];
+    let (mut voice, mut noise, mut hiss) = (0.0_f32, 0.0_f32, 1000.0_f32);
+    let mut filters: [Resonator; 3] = Default::default();
+    let mut hiss_filter = Resonator::default();
+    let (mut phase, mut last_pulse) = (0.0_f32, 0.0_f32);
+    let mut samples = Vec::with_capacity(at);
+    let mut sentence = 0;
+
+    for segment in segments {
+        let length = (segment.length * rate) as usize;
+        let target = segment.formants.unwrap_or(formants);
+        for _ in 0..length {
+            let n = samples.len();
+            while sentence + 1 < sentences.len() && n >= sentences[sentence].1 {
+                sentence += 1;
+            }
+            let (from, to, question) = sentences.get(sentence).copied().unwrap_or((0, 1, false));
+            let progress = ((n.saturating_sub(from)) as f32 / (to - from) as f32).min(1.0);
+
+            for (current, target) in formants.iter_mut().zip(target) {
+                *current += (target - *current) * glide;
+            }
+            voice += (segment.voice - voice) * glide;
+            noise += (segment.noise - noise) * glide;
+            hiss += (segment.hiss - hiss) * glide;
+            if n % RETUNE == 0 {
+                for ((filter, freq), bandwidth) in filters.iter_mut().zip(formants).zip(BANDWIDTHS) {
+                    filter.tune(freq, bandwidth);
+                }
+                hiss_filter.tune(hiss, hiss * 0.4);
+            }
+
+            // Glottal pulse, differentiated as the lips radiate it
+            phase = (phase + pitch * intonation(progress, question) / rate).fract();
+            let pulse = if phase < OPEN_PHASE { 0.5 * (1.0 - (TAU * phase / OPEN_PHASE).cos()) } else { 0.0 };
+            let source = (pulse - last_pulse) * 8.0;
+            last_pulse = pulse;
+
+            let buzz: f32 = filters.iter_mut().zip(FORMANT_GAINS).map(|(f, gain)| f.process(source) * gain).sum();
+            let white = rng.gen_range(-1.0..1.0);
+            samples.push(buzz * voice + hiss_filter.process(white) * noise);
+        }
+    }
+
+    let peak = samples.iter().fold(0.0_f32, |peak, s| peak.max(s.abs()));
+    if peak > 0.0 {
+        for sample in &mut samples {
+            *sample *= PEAK / peak;
+        }
+    }
+    samples
+}
+
+#[cfg(test)]
+mod tests {
+    // The teacher's voice rendered to WAV, no audio device needed: lines come out
+    // as audible speech of a sensible length, word timings follow the text, and
+    // the WAV reads back as written.
+    use super::*;
+
+    const LINE: &str = "Welcome, Architect! Every loop needs an exit. Can you find it?";
+
+    fn rms(samples: &[f32]) -> f32 {
+        (samples.iter().map(|s| s * s).sum::<f32>() / samples.len().max(1) as f32).sqrt()
+    }
+
+    #[test]
+    fn speaks_at_a_classroom_pace() {
+        let utterance = FormantSynth::default().synthesize(LINE, "en").expect("speech");
+        assert_eq!(utterance.rate, SAMPLE_RATE);
+        assert_eq!(utterance.words.len(), 11);
+        // Eleven words, two pauses for full stops: roughly 100-250 words a minute
+        let seconds = utterance.duration();
+        assert!((2.5..=7.0).contains(&seconds), "{:.2}s", seconds);
+        assert!(rms(&utterance.samples) > 0.05, "too quiet: {}", rms(&utterance.samples));
+        assert!(utterance.samples.iter().all(|s| s.abs() <= 1.0));
+    }
+
+    #[test]
+    fn words_are_marked_in_order() {
+        let utterance = FormantSynth::default().synthesize(LINE, "en").expect("speech");
+        let ends: Vec<usize> = utterance.words.iter().map(|w| w.end).collect();
+        assert_eq!(ends[0], "Welcome".len());
+        assert_eq!(ends[1], "Welcome, Architect".len());
+        assert!(utterance.words.windows(2).all(|w| w[0].time < w[1].time && w[0].end < w[1].end));
+
+        assert_eq!(utterance.chars_at(0.0), 0);
+        assert_eq!(utterance.chars_at(utterance.words[2].time), ends[2]);
+        assert_eq!(utterance.chars_at(utterance.duration()), LINE.chars().count());
+    }
+
+    #[test]
+    fn faster_speech_is_shorter() {
+        let normal = FormantSynth::default().synthesize(LINE, "en").expect("speech");
+        let fast = FormantSynth { speed: 1.5, ..FormantSynth::default() }.synthesize(LINE, "en").expect("speech");
+        let ratio = normal.duration() / fast.duration();
+        assert!((1.4..=1.6).contains(&ratio), "ratio {:.2}", ratio);
+    }
+
+    #[test]
+    fn reads_spanish_digits_and_skips_symbols() {
+        let utterance = FormantSynth::default().synthesize("🧙 ¿Cuántos bucles hay? 42 — ñandú", "es").expect("speech");
+        // "Cuántos", "bucles", "hay", "42", "ñandú"
+        assert_eq!(utterance.words.len(), 5);
+        assert!(utterance.duration() > 1.5);
+        // Nothing to say is silence, not an error
+        let empty = FormantSynth::default().synthesize("🧙 …", "en").expect("speech");
+        assert!(empty.words.is_empty() && rms(&empty.samples) == 0.0);
+    }
+
+    #[test]
+    fn renders_wav() {
+        let utterance = FormantSynth::default().synthesize(LINE, "en").expect("speech");
+        let path = std::env::temp_dir().join("sovereign-tts-line.wav");
+        std::fs::write(&path, utterance.to_wav()).expect("write wav");
+
+        let mut reader = hound::WavReader::open(&path).expect("read wav");
+        let spec = reader.spec();
+        assert_eq!((spec.channels, spec.sample_rate, spec.bits_per_sample), (1, SAMPLE_RATE, 16));
+        let samples: Vec<i16> = reader.samples::<i16>().map(|s| s.expect("sample")).collect();
+        assert_eq!(samples.len(), utterance.samples.len());
+        assert_eq!(samples[1000], crate::sfx_synth::to_i16(utterance.samples[1000]));
+    }
+}
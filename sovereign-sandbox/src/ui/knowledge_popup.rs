@@ -5,9 +5,14 @@ use bevy::prelude::*;
 /// Event emitted when a knowledge fragment is collected.
 #[derive(Event)]
 pub struct KnowledgeCollectedEvent {
+    /// Syllabus fragment id
+    pub id: String,
     pub title: String,
+    /// Body text, in rich-text markup
     pub content: String,
     pub xp: u32,
+    /// Objective id this fragment supports, if any
+    pub objective: Option<String>,
 }
 
 /// Component marking the popup UI root entity.
@@ -35,9 +40,14 @@ fn spawn_knowledge_popup(
     mut commands: Commands,
     mut ev_reader: EventReader<KnowledgeCollectedEvent>,
     mut popup_active: ResMut<PopupActive>,
+    syllabus: Option<Res<crate::syllabus::SyllabusResource>>,
+    locale: Res<crate::i18n::Locale>,
 ) {
     for ev in ev_reader.read() {
         popup_active.0 = true;
+        let objective_text = ev.objective.as_deref().and_then(|id| {
+            syllabus.as_ref()?.syllabus.objective(id).map(|o| o.text.clone())
+        });
 
         // Full-screen semi-transparent overlay
         commands
@@ -74,7 +84,7 @@ fn spawn_knowledge_popup(
                     .with_children(|panel| {
                         // Header badge
                         panel.spawn((
-                            Text::new("📜  KNOWLEDGE FRAGMENT"),
+                            Text::new(locale.t("fragment-header")),
                             TextFont { font_size: 22.0, ..default() },
                             TextColor(Color::srgb(0.75, 0.5, 1.0)), // Violet
                         ));
@@ -97,15 +107,25 @@ fn spawn_knowledge_popup(
                         ));
 
                         // Content text
-                        panel.spawn((
-                            Text::new(ev.content.clone()),
-                            TextFont { font_size: 15.0, ..default() },
-                            TextColor(Color::srgb(0.75, 0.75, 0.75)),
+                        super::rich_text::spawn_rich_text(
+                            panel,
+                            &ev.content,
+                            15.0,
+                            Color::srgb(0.75, 0.75, 0.75),
                             Node {
                                 max_width: Val::Px(420.0),
                                 ..default()
                             },
-                        ));
+                        );
+
+                        // Linked objective
+                        if let Some(ref text) = objective_text {
+                            panel.spawn((
+                                Text::new(format!("🎯 {}", text)),
+                                TextFont { font_size: 13.0, ..default() },
+                                TextColor(Color::srgb(1.0, 0.9, 0.5)),
+                            ));
+                        }
 
                         // XP reward
                         panel.spawn((
@@ -126,7 +146,7 @@ fn spawn_knowledge_popup(
 
                         // Dismiss prompt
                         panel.spawn((
-                            Text::new("[Press any key to continue]"),
+                            Text::new(locale.t("press-any-key-bracketed")),
                             TextFont { font_size: 13.0, ..default() },
                             TextColor(Color::srgba(0.6, 0.6, 0.6, 0.8)),
                         ));
@@ -138,11 +158,11 @@ fn spawn_knowledge_popup(
 /// Dismisses the popup when any key is pressed.
 fn dismiss_knowledge_popup(
     mut commands: Commands,
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<crate::actions::Actions>,
     popup_query: Query<Entity, With<KnowledgePopupRoot>>,
     mut popup_active: ResMut<PopupActive>,
 ) {
-    if !popup_query.is_empty() && keys.get_just_pressed().next().is_some() {
+    if !popup_query.is_empty() && actions.any_just_pressed() {
         for entity in popup_query.iter() {
             commands.entity(entity).despawn_recursive();
         }
@@ -0,0 +1,384 @@
+// Minimap — fog-of-war overview of the current zone — Bevy 0.15
+//
+// Drawn from the active map's rooms; only rooms in
+// `PlayerScore.rooms_discovered` are shown. Map (TAB) switches between the corner
+// minimap and a full-screen map for planning routes.
+
+use bevy::prelude::*;
+use crate::GameState;
+use crate::actions::{Action, Actions};
+use crate::game_world::{ActiveMap, KnowledgeFragment, Player, QuestTrigger, TILE};
+use crate::inventory::{Inventory, ToolId};
+use crate::map::{MapData, MapRoom};
+use crate::scoring::PlayerScore;
+use crate::syllabus::SyllabusResource;
+use crate::npc::Npc;
+
+/// Largest canvas sizes, in pixels
+const MINIMAP_MAX: Vec2 = Vec2::new(200.0, 140.0);
+const FULL_MAP_MAX: Vec2 = Vec2::new(1100.0, 560.0);
+
+/// Whether the full-screen map is open
+#[derive(Resource, Default)]
+pub struct MapView {
+    pub full: bool,
+}
+
+/// What the canvas currently shows, recomputed when the zone, the
+/// discovered rooms or the view change
+#[derive(Resource, Default)]
+struct MinimapLayout {
+    zone: String,
+    /// Bottom-left corner and size of the zone, in tiles
+    lo: Vec2,
+    size: Vec2,
+    canvas: Vec2,
+    full: bool,
+    discovered: Vec<MapRoom>,
+}
+
+impl MinimapLayout {
+    /// Position on the canvas as percentages from the top-left
+    fn percent(&self, world: Vec2) -> Vec2 {
+        let tile = world / TILE;
+        Vec2::new(
+            (tile.x - self.lo.x) / self.size.x * 100.0,
+            (self.lo.y + self.size.y - tile.y) / self.size.y * 100.0,
+        )
+    }
+
+    fn is_discovered(&self, world: Vec2) -> bool {
+        self.discovered.iter().any(|r| r.contains(world / TILE))
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MarkerKind {
+    Player,
+    Objective,
+    Npc,
+    Fragment,
+}
+
+impl MarkerKind {
+    fn size(&self) -> f32 {
+        match self {
+            MarkerKind::Player | MarkerKind::Objective => 10.0,
+            MarkerKind::Npc | MarkerKind::Fragment => 7.0,
+        }
+    }
+
+    fn color(&self) -> Color {
+        match self {
+            MarkerKind::Player => Color::srgb(1.0, 1.0, 1.0),
+            MarkerKind::Objective => Color::srgb(0.75, 0.5, 1.0), // Violet
+            MarkerKind::Npc => Color::srgb(0.0, 1.0, 1.0),        // Cyan
+            MarkerKind::Fragment => Color::srgb(1.0, 0.85, 0.0),  // Gold
+        }
+    }
+}
+
+/// A dot on the canvas, following `target` (or the player / objective)
+#[derive(Component)]
+struct MinimapMarker {
+    kind: MarkerKind,
+    target: Option<Entity>,
+}
+
+#[derive(Component)]
+struct MinimapRoot;
+
+#[derive(Component)]
+struct MinimapTitle;
+
+#[derive(Component)]
+struct MinimapCanvas;
+
+#[derive(Component)]
+struct MinimapRoomNode;
+
+type RootQuery<'w, 's> = Query<'w, 's, (&'static mut Node, &'static mut BackgroundColor, &'static mut Visibility), With<MinimapRoot>>;
+
+pub struct MinimapPlugin;
+
+impl Plugin for MinimapPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MapView>()
+           .init_resource::<MinimapLayout>()
+           .add_systems(Startup, setup_minimap)
+           .add_systems(Update, (
+               toggle_full_map.run_if(in_state(GameState::Playing)),
+               update_layout,
+               apply_view,
+               draw_rooms,
+               spawn_markers,
+               position_markers,
+           ).chain());
+    }
+}
+
+fn setup_minimap(mut commands: Commands) {
+    // Full-window container: anchors the panel bottom-right (above the
+    // controls HUD) or centres it over a dimmed screen
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            ..default()
+        },
+        BackgroundColor(Color::NONE),
+        Visibility::Hidden,
+        GlobalZIndex(40),
+        MinimapRoot,
+    )).with_children(|root| {
+        root.spawn((
+            Node {
+                padding: UiRect::all(Val::Px(8.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(4.0),
+                border: UiRect::all(Val::Px(1.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.05, 0.85)),
+            BorderColor(Color::srgb(0.3, 0.3, 0.3)),
+        )).with_children(|panel| {
+            panel.spawn((
+                Text::new(""),
+                TextFont { font_size: 11.0, ..default() },
+                TextColor(Color::srgb(1.0, 0.75, 0.0)),
+                MinimapTitle,
+            ));
+            panel.spawn((
+                Node {
+                    overflow: Overflow::clip(),
+                    ..default()
+                },
+                BackgroundColor(Color::srgb(0.02, 0.02, 0.04)),
+                MinimapCanvas,
+            )).with_children(|canvas| {
+                for kind in [MarkerKind::Player, MarkerKind::Objective] {
+                    spawn_marker(canvas, kind, None);
+                }
+            });
+        });
+    });
+}
+
+fn spawn_marker(canvas: &mut ChildBuilder, kind: MarkerKind, target: Option<Entity>) {
+    let size = kind.size();
+    canvas.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Px(size),
+            height: Val::Px(size),
+            margin: UiRect { left: Val::Px(-size / 2.0), top: Val::Px(-size / 2.0), ..default() },
+            ..default()
+        },
+        BackgroundColor(kind.color()),
+        BorderRadius::MAX,
+        Visibility::Hidden,
+        ZIndex(1),
+        MinimapMarker { kind, target },
+    ));
+}
+
+fn toggle_full_map(
+    actions: Res<Actions>,
+    mut view: ResMut<MapView>,
+) {
+    if actions.just_pressed(Action::Map) || (view.full && actions.just_pressed(Action::Cancel)) {
+        view.full = !view.full;
+    }
+}
+
+fn update_layout(
+    active_map: Option<Res<ActiveMap>>,
+    maps: Res<Assets<MapData>>,
+    score: Res<PlayerScore>,
+    view: Res<MapView>,
+    mut layout: ResMut<MinimapLayout>,
+    mut last: Local<Option<(String, usize, bool)>>,
+) {
+    let Some(map) = active_map.as_ref().and_then(|m| m.data(&maps)) else { return };
+    let signature = (map.name.clone(), score.rooms_discovered.len(), view.full);
+    if last.as_ref() == Some(&signature) { return; }
+    *last = Some(signature);
+
+    // Zone bounds are the union of its rooms
+    let Some(first) = map.rooms.first() else { return };
+    let (lo, hi) = map.rooms.iter().fold((first.min, first.min + first.size), |(lo, hi), r| {
+        (lo.min(r.min), hi.max(r.min + r.size))
+    });
+    let size = (hi - lo).as_vec2().max(Vec2::ONE);
+    let lo = lo.as_vec2() - 0.5;
+
+    let max = if view.full { FULL_MAP_MAX } else { MINIMAP_MAX };
+    let scale = (max.x / size.x).min(max.y / size.y);
+
+    *layout = MinimapLayout {
+        zone: map.name.clone(),
+        lo,
+        size,
+        canvas: size * scale,
+        full: view.full,
+        discovered: map.rooms.iter()
+            .filter(|r| score.rooms_discovered.contains(&map.room_key(r)))
+            .cloned()
+            .collect(),
+    };
+}
+
+/// Moves the panel between the corner and the middle of the screen, and
+/// hides it outside of play
+fn apply_view(
+    state: Res<State<GameState>>,
+    layout: Res<MinimapLayout>,
+    actions: Res<Actions>,
+    locale: Res<crate::i18n::Locale>,
+    mut root_query: RootQuery,
+    mut title_query: Query<&mut Text, With<MinimapTitle>>,
+) {
+    let Ok((mut node, mut bg, mut visibility)) = root_query.get_single_mut() else { return };
+    let playing = *state.get() == GameState::Playing && layout.size != Vec2::ZERO;
+    *visibility = if playing { Visibility::Inherited } else { Visibility::Hidden };
+    if !layout.is_changed() { return; }
+
+    if layout.full {
+        node.justify_content = JustifyContent::Center;
+        node.align_items = AlignItems::Center;
+        node.padding = UiRect::default();
+        bg.0 = Color::srgba(0.0, 0.0, 0.0, 0.8);
+    } else {
+        node.justify_content = JustifyContent::FlexEnd;
+        node.align_items = AlignItems::FlexEnd;
+        node.padding = UiRect { right: Val::Px(12.0), bottom: Val::Px(220.0), ..default() };
+        bg.0 = Color::NONE;
+    }
+
+    for mut text in &mut title_query {
+        *text = if layout.full {
+            Text::new(locale.fmt("map-title-full", &[("zone", &layout.zone), ("key", &actions.label(Action::Map))]))
+        } else {
+            Text::new(format!("🗺️ {}", layout.zone))
+        };
+    }
+}
+
+fn draw_rooms(
+    mut commands: Commands,
+    layout: Res<MinimapLayout>,
+    mut canvas_query: Query<(Entity, &mut Node), With<MinimapCanvas>>,
+    room_nodes: Query<Entity, With<MinimapRoomNode>>,
+) {
+    if !layout.is_changed() { return; }
+    let Ok((canvas, mut canvas_node)) = canvas_query.get_single_mut() else { return };
+
+    canvas_node.width = Val::Px(layout.canvas.x);
+    canvas_node.height = Val::Px(layout.canvas.y);
+
+    for entity in &room_nodes {
+        commands.entity(entity).despawn_recursive();
+    }
+
+    // Undiscovered rooms stay dark
+    commands.entity(canvas).with_children(|canvas| {
+        for room in &layout.discovered {
+            let top_left = Vec2::new(room.min.x as f32 - 0.5, (room.min.y + room.size.y) as f32 - 0.5);
+            let pos = layout.percent(top_left * TILE);
+            let size = room.size.as_vec2() / layout.size * 100.0;
+            canvas.spawn((
+                Node {
+                    position_type: PositionType::Absolute,
+                    left: Val::Percent(pos.x),
+                    top: Val::Percent(pos.y),
+                    width: Val::Percent(size.x),
+                    height: Val::Percent(size.y),
+                    border: UiRect::all(Val::Px(1.0)),
+                    justify_content: JustifyContent::Center,
+                    align_items: AlignItems::Center,
+                    ..default()
+                },
+                BackgroundColor(room.color.with_alpha(0.25)),
+                BorderColor(room.color),
+                MinimapRoomNode,
+            )).with_children(|node| {
+                if layout.full {
+                    node.spawn((
+                        Text::new(room.name.clone()),
+                        TextFont { font_size: 12.0, ..default() },
+                        TextColor(room.color),
+                    ));
+                }
+            });
+        }
+    });
+}
+
+/// Gives every NPC and fragment in the zone a marker, and drops markers
+/// whose entity is gone (a collected fragment, or a zone swapped out)
+fn spawn_markers(
+    mut commands: Commands,
+    canvas_query: Query<Entity, With<MinimapCanvas>>,
+    new_npcs: Query<Entity, Added<Npc>>,
+    new_fragments: Query<Entity, Added<KnowledgeFragment>>,
+    markers: Query<(Entity, &MinimapMarker)>,
+    world: Query<(), With<Transform>>,
+) {
+    for (entity, marker) in &markers {
+        if marker.target.is_some_and(|target| !world.contains(target)) {
+            commands.entity(entity).despawn_recursive();
+        }
+    }
+
+    let Ok(canvas) = canvas_query.get_single() else { return };
+    if new_npcs.is_empty() && new_fragments.is_empty() { return; }
+
+    commands.entity(canvas).with_children(|canvas| {
+        for entity in &new_npcs {
+            spawn_marker(canvas, MarkerKind::Npc, Some(entity));
+        }
+        for entity in &new_fragments {
+            spawn_marker(canvas, MarkerKind::Fragment, Some(entity));
+        }
+    });
+}
+
+fn position_markers(
+    layout: Res<MinimapLayout>,
+    inventory: Res<Inventory>,
+    syllabus: Option<Res<SyllabusResource>>,
+    mut markers: Query<(&MinimapMarker, &mut Node, &mut Visibility)>,
+    world: Query<&Transform, Without<MinimapMarker>>,
+    player_query: Query<Entity, With<Player>>,
+    trigger_query: Query<(&Transform, &QuestTrigger)>,
+) {
+    let compass = inventory.active_tool == Some(ToolId::OllamaCompass);
+    let player = player_query.get_single().ok().and_then(|e| world.get(e).ok());
+    let objective = syllabus.and_then(|syl| crate::navigation::objective_position(&syl, &trigger_query));
+
+    for (marker, mut node, mut visibility) in &mut markers {
+        let pos = match (marker.kind, marker.target) {
+            (MarkerKind::Player, _) => player.map(|tf| tf.translation.truncate()),
+            (MarkerKind::Objective, _) => objective,
+            (_, Some(target)) => world.get(target).ok().map(|tf| tf.translation.truncate()),
+            (_, None) => None,
+        };
+
+        // The Compass reveals fragments; NPCs only show once found
+        let shown = pos.filter(|p| match marker.kind {
+            MarkerKind::Player | MarkerKind::Objective => true,
+            MarkerKind::Npc => layout.is_discovered(*p),
+            MarkerKind::Fragment => compass,
+        });
+        let Some(pos) = shown else {
+            *visibility = Visibility::Hidden;
+            continue;
+        };
+
+        let percent = layout.percent(pos);
+        node.left = Val::Percent(percent.x);
+        node.top = Val::Percent(percent.y);
+        *visibility = Visibility::Inherited;
+    }
+}
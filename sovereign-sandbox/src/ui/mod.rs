@@ -1,2 +1,5 @@
 pub mod knowledge_popup;
+pub mod minimap;
+pub mod pause_menu;
+pub mod rich_text;
 pub mod victory_screen;
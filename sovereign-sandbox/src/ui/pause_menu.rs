@@ -0,0 +1,501 @@
+use bevy::ecs::system::SystemParam;
+use bevy::prelude::*;
+use crate::PauseState;
+use crate::accessibility::{AccessibilitySettings, TextSpeed, UI_SCALE_RANGE};
+use crate::actions::{Action, Actions, InputContext, Rebinding, MAX_CHOICES};
+use crate::ai::moshi::MoshiVoice;
+use crate::audio::Bus;
+use crate::i18n::Locale;
+use crate::narration::{Narrated, Priority};
+use crate::settings::{AiBackend, DisplayMode, GameSettings};
+use crate::ui::minimap::MapView;
+
+// ============================================================================
+// Pause Menu — settings while the game stands still
+// ============================================================================
+// Pause moves to `PauseState::Paused` inside Playing and pauses virtual time,
+// so everything ticked from `Res<Time>` (the mission clock, teacher nudges,
+// slime spawns and wandering, the typewriter) holds still underneath. The
+// menu is a few pages of numbered options offered through the input context
+// like any other choices: picking a setting steps it forward, and left/right
+// step the focused one either way. Settings save themselves when changed.
+
+/// Step for the UI scale setting
+const UI_SCALE_STEP: f32 = 0.25;
+
+/// Actions the controls page can rebind; the rest stay on the controls HUD
+const REBINDABLE: [Action; 8] = [
+    Action::Interact, Action::Attack, Action::Talk, Action::Map, Action::Breadcrumbs, Action::Pause, Action::Speak,
+    Action::Command,
+];
+
+const OPTION_COLOR: Color = Color::srgb(0.8, 0.8, 0.8);
+const FOCUS_COLOR: Color = Color::srgb(1.0, 0.75, 0.0);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+enum Page {
+    #[default]
+    Main,
+    Audio,
+    Mute,
+    Display,
+    Controls,
+    Accessibility,
+    Ai,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Entry {
+    Resume,
+    Open(Page),
+    Back,
+    MasterVolume,
+    Volume(Bus),
+    Mute(Bus),
+    WindowMode,
+    Resizable,
+    ScaleWithWindow,
+    Rebind(Action),
+    ResetBindings,
+    Shake,
+    Particles,
+    ReducedMotion,
+    TextSpeed,
+    UiScale,
+    HighContrast,
+    DyslexiaFont,
+    Narration,
+    TeacherVoice,
+    Backend(AiBackend),
+}
+
+impl Page {
+    fn entries(&self) -> Vec<Entry> {
+        match self {
+            Page::Main => vec![
+                Entry::Resume,
+                Entry::Open(Page::Audio),
+                Entry::Open(Page::Display),
+                Entry::Open(Page::Controls),
+                Entry::Open(Page::Accessibility),
+                Entry::Open(Page::Ai),
+            ],
+            Page::Audio => std::iter::once(Entry::MasterVolume)
+                .chain(Bus::ALL.map(Entry::Volume))
+                .chain([Entry::Open(Page::Mute), Entry::Back])
+                .collect(),
+            Page::Mute => Bus::ALL.map(Entry::Mute).into_iter().chain([Entry::Back]).collect(),
+            Page::Display => vec![Entry::WindowMode, Entry::Resizable, Entry::ScaleWithWindow, Entry::Back],
+            Page::Controls => REBINDABLE.iter().map(|a| Entry::Rebind(*a))
+                .chain([Entry::ResetBindings, Entry::Back])
+                .collect(),
+            Page::Accessibility => vec![
+                Entry::Shake, Entry::Particles, Entry::ReducedMotion, Entry::TextSpeed, Entry::UiScale,
+                Entry::HighContrast, Entry::DyslexiaFont, Entry::Narration, Entry::TeacherVoice, Entry::Back,
+            ],
+            Page::Ai => vec![Entry::Backend(AiBackend::Lessons), Entry::Backend(AiBackend::Moshi), Entry::Back],
+        }
+    }
+
+    fn title(&self) -> &'static str {
+        match self {
+            Page::Main => "pause-title",
+            Page::Audio => "pause-audio",
+            Page::Mute => "pause-mute",
+            Page::Display => "pause-display",
+            Page::Controls => "pause-controls",
+            Page::Accessibility => "pause-accessibility",
+            Page::Ai => "pause-ai",
+        }
+    }
+
+    /// Where Back and Cancel go
+    fn parent(&self) -> Page {
+        match self {
+            Page::Mute => Page::Audio,
+            _ => Page::Main,
+        }
+    }
+}
+
+/// The page on show and its options, which `actions` offers as choices
+#[derive(Resource, Default)]
+pub struct PauseMenu {
+    page: Page,
+    pub options: Vec<String>,
+}
+
+#[derive(Component)]
+struct PauseMenuRoot;
+
+#[derive(Component)]
+struct PauseTitle;
+
+#[derive(Component)]
+struct PauseHint;
+
+/// Row `n` of the current page; clicking it picks the option
+#[derive(Component)]
+struct PauseRow(usize);
+
+pub struct PauseMenuPlugin;
+
+impl Plugin for PauseMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<PauseMenu>()
+           .add_systems(Update, open_pause_menu.run_if(in_state(PauseState::Running)))
+           .add_systems(Update, (handle_pause_input, refresh_pause_menu).chain().run_if(in_state(PauseState::Paused)))
+           .add_systems(OnEnter(PauseState::Paused), (pause_game, spawn_pause_menu))
+           .add_systems(OnExit(PauseState::Paused), (resume_game, despawn_pause_menu));
+    }
+}
+
+/// Pause opens the menu when nothing else is using Cancel
+fn open_pause_menu(
+    actions: Res<Actions>,
+    context: Res<InputContext>,
+    view: Res<MapView>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+) {
+    let free = matches!(*context, InputContext::World | InputContext::Dialogue | InputContext::Quiz);
+    if actions.just_pressed(Action::Pause) && free && !view.full {
+        next_pause.set(PauseState::Paused);
+    }
+}
+
+fn pause_game(mut time: ResMut<Time<Virtual>>, mut menu: ResMut<PauseMenu>) {
+    time.pause();
+    menu.page = Page::Main;
+    info!("⏸️ Paused");
+}
+
+fn resume_game(mut time: ResMut<Time<Virtual>>, mut rebinding: ResMut<Rebinding>) {
+    time.unpause();
+    rebinding.0 = None;
+    info!("▶️ Resumed");
+}
+
+// ============================================================================
+// Options
+// ============================================================================
+
+/// Everything the options show and change
+#[derive(SystemParam)]
+struct Options<'w> {
+    settings: ResMut<'w, GameSettings>,
+    accessibility: ResMut<'w, AccessibilitySettings>,
+    actions: ResMut<'w, Actions>,
+    rebinding: ResMut<'w, Rebinding>,
+    moshi: Option<Res<'w, MoshiVoice>>,
+    locale: Res<'w, Locale>,
+}
+
+impl Options<'_> {
+    fn label(&self, entry: Entry) -> String {
+        let locale = &self.locale;
+        let on_off = |on: bool| locale.t(if on { "pause-on" } else { "pause-off" });
+        let percent = |value: f32| locale.fmt("pause-percent", &[("value", &format!("{:.0}", value * 100.0))]);
+        let (name, value) = match entry {
+            Entry::Resume => return locale.t("pause-resume"),
+            Entry::Open(page) => return locale.t(page.title()),
+            Entry::Back => return locale.t("pause-back"),
+            Entry::ResetBindings => return locale.t("pause-reset-bindings"),
+            Entry::Rebind(action) if self.rebinding.0 == Some(action) => {
+                return locale.fmt("pause-rebind-waiting", &[("name", &locale.t(action_name(action)))]);
+            }
+            Entry::Rebind(action) => (action_name(action), self.actions.label(action)),
+            Entry::Backend(backend) => {
+                let mut name = locale.t(backend_name(backend));
+                if backend == AiBackend::Moshi && self.moshi.is_none() {
+                    name = locale.fmt("pause-not-loaded", &[("name", &name)]);
+                }
+                let mark = if self.settings.ai_backend == backend { "●" } else { "○" };
+                return format!("{} {}", mark, name);
+            }
+            Entry::MasterVolume => ("pause-master-volume", percent(self.settings.audio.master)),
+            Entry::Volume(bus) if self.settings.audio.is_muted(bus) => {
+                (bus_name(bus), locale.fmt("pause-muted", &[("value", &percent(self.settings.audio.level(bus)))]))
+            }
+            Entry::Volume(bus) => (bus_name(bus), percent(self.settings.audio.level(bus))),
+            Entry::Mute(bus) => (bus_name(bus), locale.t(if self.settings.audio.is_muted(bus) { "pause-muted-on" } else { "pause-muted-off" })),
+            Entry::WindowMode => ("pause-window", locale.t(match self.settings.display.mode {
+                DisplayMode::Windowed => "pause-windowed",
+                DisplayMode::Fullscreen => "pause-fullscreen",
+            })),
+            Entry::Resizable => ("pause-resizable", on_off(self.settings.display.resizable)),
+            Entry::ScaleWithWindow => ("pause-scale-with-window", on_off(self.settings.display.scale_ui_with_window)),
+            Entry::Shake => ("pause-shake", percent(self.accessibility.shake)),
+            Entry::Particles => ("pause-particles", percent(self.accessibility.particles)),
+            Entry::ReducedMotion => ("pause-reduced-motion", on_off(self.accessibility.reduced_motion)),
+            Entry::TextSpeed => ("pause-text-speed", locale.t(match self.accessibility.text_speed {
+                TextSpeed::Slow => "pause-speed-slow",
+                TextSpeed::Normal => "pause-speed-normal",
+                TextSpeed::Fast => "pause-speed-fast",
+                TextSpeed::Instant => "pause-speed-instant",
+            })),
+            Entry::UiScale => ("pause-ui-scale", format!("{:.2}×", self.accessibility.ui_scale)),
+            Entry::HighContrast => ("pause-high-contrast", on_off(self.accessibility.high_contrast)),
+            Entry::DyslexiaFont => ("pause-dyslexia-font", on_off(self.accessibility.dyslexia_font)),
+            Entry::Narration => ("pause-narration", on_off(self.accessibility.narration)),
+            Entry::TeacherVoice => ("pause-teacher-voice", on_off(self.accessibility.teacher_voice)),
+        };
+        locale.fmt("pause-setting", &[("name", &locale.t(name)), ("value", &value)])
+    }
+
+    /// Moves a setting one step; `dir` is 1 for picked or right, -1 for left
+    fn step(&mut self, entry: Entry, dir: i32) {
+        match entry {
+            Entry::MasterVolume => step_fraction(&mut self.settings.audio.master, dir),
+            Entry::Volume(bus) => step_fraction(self.settings.audio.level_mut(bus), dir),
+            Entry::Mute(bus) => self.settings.audio.toggle_mute(bus),
+            Entry::WindowMode => {
+                let mode = self.settings.display.mode;
+                self.settings.display.mode = cycle(&[DisplayMode::Windowed, DisplayMode::Fullscreen], mode, dir);
+            }
+            Entry::Resizable => toggle(&mut self.settings.display.resizable),
+            Entry::ScaleWithWindow => toggle(&mut self.settings.display.scale_ui_with_window),
+            Entry::Backend(backend) => self.settings.ai_backend = backend,
+            Entry::Rebind(action) if dir > 0 => self.rebinding.0 = Some(action),
+            Entry::ResetBindings if dir > 0 => self.actions.reset_bindings(),
+            Entry::Shake => step_fraction(&mut self.accessibility.shake, dir),
+            Entry::Particles => step_fraction(&mut self.accessibility.particles, dir),
+            Entry::ReducedMotion => toggle(&mut self.accessibility.reduced_motion),
+            Entry::TextSpeed => {
+                let speeds = [TextSpeed::Slow, TextSpeed::Normal, TextSpeed::Fast, TextSpeed::Instant];
+                let speed = self.accessibility.text_speed;
+                self.accessibility.text_speed = cycle(&speeds, speed, dir);
+            }
+            Entry::UiScale => step_ui_scale(&mut self.accessibility.ui_scale, dir),
+            Entry::HighContrast => toggle(&mut self.accessibility.high_contrast),
+            Entry::DyslexiaFont => toggle(&mut self.accessibility.dyslexia_font),
+            Entry::Narration => toggle(&mut self.accessibility.narration),
+            Entry::TeacherVoice => toggle(&mut self.accessibility.teacher_voice),
+            _ => {}
+        }
+    }
+}
+
+fn action_name(action: Action) -> &'static str {
+    match action {
+        Action::Interact => "controls-interact",
+        Action::Attack => "controls-attack",
+        Action::Talk => "controls-talk",
+        Action::Map => "controls-map",
+        Action::Breadcrumbs => "controls-breadcrumbs",
+        Action::Speak => "controls-speak",
+        Action::Command => "controls-command",
+        _ => "controls-pause",
+    }
+}
+
+fn bus_name(bus: Bus) -> &'static str {
+    match bus {
+        Bus::Music => "pause-music-volume",
+        Bus::Sfx => "pause-sfx-volume",
+        Bus::Voice => "pause-voice-volume",
+        Bus::Ambient => "pause-ambient-volume",
+    }
+}
+
+fn backend_name(backend: AiBackend) -> &'static str {
+    match backend {
+        AiBackend::Lessons => "pause-backend-lessons",
+        AiBackend::Moshi => "pause-backend-moshi",
+    }
+}
+
+fn toggle(flag: &mut bool) {
+    *flag = !*flag;
+}
+
+/// Tenths from 0 to 1, wrapping round
+fn step_fraction(value: &mut f32, dir: i32) {
+    *value = ((*value * 10.0).round() as i32 + dir).rem_euclid(11) as f32 / 10.0;
+}
+
+/// Quarter steps across `UI_SCALE_RANGE`, wrapping round
+fn step_ui_scale(value: &mut f32, dir: i32) {
+    let (min, max) = UI_SCALE_RANGE;
+    let steps = ((max - min) / UI_SCALE_STEP).round() as i32 + 1;
+    let index = ((*value - min) / UI_SCALE_STEP).round() as i32;
+    *value = min + (index + dir).rem_euclid(steps) as f32 * UI_SCALE_STEP;
+}
+
+fn cycle<T: Copy + PartialEq>(all: &[T], current: T, dir: i32) -> T {
+    let index = all.iter().position(|v| *v == current).unwrap_or(0) as i32;
+    all[(index + dir).rem_euclid(all.len() as i32) as usize]
+}
+
+// ============================================================================
+// Input
+// ============================================================================
+
+fn handle_pause_input(
+    mut menu: ResMut<PauseMenu>,
+    mut options: Options,
+    rows: Query<(&Interaction, &PauseRow), Changed<Interaction>>,
+    mut next_pause: ResMut<NextState<PauseState>>,
+) {
+    let actions = &options.actions;
+    let clicked = rows.iter().find(|(i, _)| **i == Interaction::Pressed).map(|(_, row)| row.0);
+    let picked = actions.choice(InputContext::Pause).or(clicked);
+    let nudge = if actions.just_pressed(Action::MoveRight) {
+        1
+    } else if actions.just_pressed(Action::MoveLeft) {
+        -1
+    } else {
+        0
+    };
+    let focused = actions.focused().filter(|_| nudge != 0);
+
+    // Cancel backs out a page at a time; Pause closes from anywhere (unless
+    // it's on the same button as Confirm, which then picks)
+    if actions.just_pressed(Action::Cancel) {
+        if menu.page == Page::Main {
+            next_pause.set(PauseState::Running);
+        } else {
+            menu.page = menu.page.parent();
+        }
+        return;
+    }
+    if actions.just_pressed(Action::Pause) && !actions.just_pressed(Action::Confirm) {
+        next_pause.set(PauseState::Running);
+        return;
+    }
+
+    let entries = menu.page.entries();
+    let (entry, dir) = match (picked, focused) {
+        (Some(i), _) => (entries.get(i), 1),
+        (None, Some(i)) => (entries.get(i), nudge),
+        _ => return,
+    };
+    match entry.copied() {
+        Some(Entry::Resume) if dir > 0 => next_pause.set(PauseState::Running),
+        Some(Entry::Open(page)) if dir > 0 => menu.page = page,
+        Some(Entry::Back) if dir > 0 => menu.page = menu.page.parent(),
+        Some(entry) => options.step(entry, dir),
+        None => {}
+    }
+}
+
+// ============================================================================
+// Overlay
+// ============================================================================
+
+fn spawn_pause_menu(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            width: Val::Percent(100.0),
+            height: Val::Percent(100.0),
+            position_type: PositionType::Absolute,
+            justify_content: JustifyContent::Center,
+            align_items: AlignItems::Center,
+            ..default()
+        },
+        BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.75)),
+        GlobalZIndex(200),
+        PauseMenuRoot,
+    )).with_children(|overlay| {
+        overlay.spawn((
+            Node {
+                width: Val::Px(520.0),
+                padding: UiRect::all(Val::Px(28.0)),
+                flex_direction: FlexDirection::Column,
+                row_gap: Val::Px(8.0),
+                border: UiRect::all(Val::Px(2.0)),
+                ..default()
+            },
+            BackgroundColor(Color::srgb(0.05, 0.05, 0.1)),
+            BorderColor(Color::srgb(1.0, 0.75, 0.0)),
+            BorderRadius::all(Val::Px(8.0)),
+        )).with_children(|panel| {
+            panel.spawn((
+                Text::new(""),
+                TextFont { font_size: 32.0, ..default() },
+                TextColor(Color::srgb(1.0, 0.75, 0.0)),
+                Node { margin: UiRect::bottom(Val::Px(12.0)), ..default() },
+                Narrated(Priority::Assertive),
+                PauseTitle,
+            ));
+            for n in 0..MAX_CHOICES as usize {
+                panel.spawn((
+                    Text::new(""),
+                    TextFont { font_size: 20.0, ..default() },
+                    TextColor(OPTION_COLOR),
+                    Node { display: Display::None, ..default() },
+                    Button,
+                    PauseRow(n),
+                ));
+            }
+            panel.spawn((
+                Text::new(""),
+                TextFont { font_size: 13.0, ..default() },
+                TextColor(Color::srgb(0.5, 0.5, 0.5)),
+                Node { margin: UiRect::top(Val::Px(12.0)), ..default() },
+                PauseHint,
+            ));
+        });
+    });
+}
+
+fn despawn_pause_menu(mut commands: Commands, roots: Query<Entity, With<PauseMenuRoot>>) {
+    for entity in &roots {
+        commands.entity(entity).despawn_recursive();
+    }
+}
+
+/// Puts the current page's options on the rows and in `PauseMenu`
+#[allow(clippy::type_complexity)]
+fn refresh_pause_menu(
+    mut menu: ResMut<PauseMenu>,
+    options: Options,
+    mut title: Query<&mut Text, (With<PauseTitle>, Without<PauseRow>, Without<PauseHint>)>,
+    mut hint: Query<&mut Text, (With<PauseHint>, Without<PauseRow>)>,
+    mut rows: Query<(&PauseRow, &mut Text, &mut TextColor, &mut Node, &Interaction)>,
+) {
+    let labels: Vec<String> = menu.page.entries().into_iter().map(|e| options.label(e)).collect();
+    if menu.options != labels {
+        menu.options = labels;
+    }
+
+    let heading = options.locale.t(menu.page.title());
+    for mut text in &mut title {
+        if text.0 != heading {
+            text.0 = heading.clone();
+        }
+    }
+
+    let actions = &options.actions;
+    let hint_text = options.locale.fmt("pause-hint", &[
+        ("choices", &actions.choices_label(menu.options.len(), &options.locale)),
+        ("left", &actions.label(Action::MoveLeft)),
+        ("right", &actions.label(Action::MoveRight)),
+        ("back", &actions.label(Action::Cancel)),
+    ]);
+    for mut text in &mut hint {
+        if text.0 != hint_text {
+            text.0 = hint_text.clone();
+        }
+    }
+
+    for (row, mut text, mut color, mut node, interaction) in &mut rows {
+        let Some(label) = menu.options.get(row.0) else {
+            if node.display != Display::None {
+                node.display = Display::None;
+            }
+            continue;
+        };
+        if node.display != Display::Flex {
+            node.display = Display::Flex;
+        }
+        let focused = actions.focused() == Some(row.0) || *interaction == Interaction::Hovered;
+        let content = format!("{} {}. {}", if focused { "▶" } else { " " }, row.0 + 1, label);
+        if text.0 != content {
+            text.0 = content;
+        }
+        let wanted = if focused { FOCUS_COLOR } else { OPTION_COLOR };
+        if color.0 != wanted {
+            color.0 = wanted;
+        }
+    }
+}
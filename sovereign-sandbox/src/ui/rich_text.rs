@@ -0,0 +1,186 @@
+// Rich Text — minimal markup for syllabus-authored content — Bevy 0.15
+//
+// Supports `code`, *emphasis* and **strong**. There is only one font face
+// shipped, so styles are distinguished by color.
+
+use bevy::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanStyle {
+    Plain,
+    Emphasis,
+    Strong,
+    Code,
+}
+
+impl SpanStyle {
+    fn color(&self, base: Color) -> Color {
+        match self {
+            SpanStyle::Plain => base,
+            SpanStyle::Emphasis => Color::srgb(0.75, 0.5, 1.0), // Violet
+            SpanStyle::Strong => Color::srgb(1.0, 1.0, 1.0),
+            SpanStyle::Code => Color::srgb(0.0, 1.0, 0.5), // Terminal green
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token {
+    Char(char),
+    /// `` ` ``, `**` or `*`
+    Marker(SpanStyle),
+}
+
+impl Token {
+    fn text(&self) -> String {
+        match self {
+            Token::Char(c) => c.to_string(),
+            Token::Marker(SpanStyle::Code) => "`".to_string(),
+            Token::Marker(SpanStyle::Strong) => "**".to_string(),
+            Token::Marker(_) => "*".to_string(),
+        }
+    }
+}
+
+/// Characters and markers; asterisks inside a closed `code` span are text
+fn tokenize(markup: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut code = false;
+    let mut chars = markup.chars().peekable();
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '`' if code || chars.clone().take_while(|c| *c != '\n').any(|c| c == '`') => {
+                code = !code;
+                Token::Marker(SpanStyle::Code)
+            }
+            '*' if !code && chars.peek() == Some(&'*') => {
+                chars.next();
+                Token::Marker(SpanStyle::Strong)
+            }
+            '*' if !code => Token::Marker(SpanStyle::Emphasis),
+            c => Token::Char(c),
+        };
+        tokens.push(token);
+    }
+    tokens
+}
+
+/// Splits markup into styled runs. A marker with no partner later on its
+/// line is kept as text, so a stray `*` doesn't style the rest of the line.
+pub fn parse(markup: &str) -> Vec<(String, SpanStyle)> {
+    let tokens = tokenize(markup);
+    let mut runs = Vec::new();
+    let mut buf = String::new();
+    let (mut strong, mut emphasis, mut code) = (false, false, false);
+
+    let style = |strong: bool, emphasis: bool, code: bool| {
+        if code { SpanStyle::Code }
+        else if strong { SpanStyle::Strong }
+        else if emphasis { SpanStyle::Emphasis }
+        else { SpanStyle::Plain }
+    };
+    let closes = |rest: &[Token], marker: Token| {
+        rest.iter().take_while(|t| **t != Token::Char('\n')).any(|t| *t == marker)
+    };
+
+    for (i, token) in tokens.iter().enumerate() {
+        let Token::Marker(kind) = *token else {
+            buf.push_str(&token.text());
+            continue;
+        };
+        let open = match kind {
+            SpanStyle::Code => code,
+            SpanStyle::Strong => strong,
+            _ => emphasis,
+        };
+        if !open && !closes(&tokens[i + 1..], *token) {
+            buf.push_str(&token.text());
+            continue;
+        }
+
+        if !buf.is_empty() {
+            runs.push((std::mem::take(&mut buf), style(strong, emphasis, code)));
+        }
+        match kind {
+            SpanStyle::Code => code = !code,
+            SpanStyle::Strong => strong = !strong,
+            _ => emphasis = !emphasis,
+        }
+    }
+    if !buf.is_empty() {
+        runs.push((buf, style(strong, emphasis, code)));
+    }
+    runs
+}
+
+/// The text with all markup removed
+pub fn plain(markup: &str) -> String {
+    parse(markup).into_iter().map(|(run, _)| run).collect()
+}
+
+/// Spawns a text entity whose spans follow the markup in `markup`.
+pub fn spawn_rich_text(
+    parent: &mut ChildBuilder,
+    markup: &str,
+    font_size: f32,
+    base_color: Color,
+    node: Node,
+) {
+    parent
+        .spawn((
+            Text::new(""),
+            TextFont { font_size, ..default() },
+            TextColor(base_color),
+            node,
+        ))
+        .with_children(|text| {
+            for (run, style) in parse(markup) {
+                text.spawn((
+                    TextSpan::new(run),
+                    TextFont { font_size, ..default() },
+                    TextColor(style.color(base_color)),
+                ));
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use SpanStyle::*;
+
+    fn check(markup: &str, expected: &[(&str, SpanStyle)]) {
+        let runs = parse(markup);
+        let runs: Vec<(&str, SpanStyle)> = runs.iter().map(|(run, style)| (run.as_str(), *style)).collect();
+        assert_eq!(runs, expected, "{:?}", markup);
+    }
+
+    #[test]
+    fn styles_closed_markers() {
+        check("plain", &[("plain", Plain)]);
+        check("Run `ollama run llama3` for *local* and **private** AI", &[
+            ("Run ", Plain), ("ollama run llama3", Code), (" for ", Plain), ("local", Emphasis),
+            (" and ", Plain), ("private", Strong), (" AI", Plain),
+        ]);
+        assert_eq!(plain("a *b* **c** `d`"), "a b c d");
+    }
+
+    #[test]
+    fn nests_markers() {
+        check("**strong *both* strong**", &[("strong ", Strong), ("both", Strong), (" strong", Strong)]);
+        check("*see `cargo run` here*", &[("see ", Emphasis), ("cargo run", Code), (" here", Emphasis)]);
+        // Asterisks in code are code
+        check("`a * b` *c*", &[("a * b", Code), (" ", Plain), ("c", Emphasis)]);
+    }
+
+    #[test]
+    fn unclosed_markers_are_text() {
+        check("5 * 3 = 15", &[("5 * 3 = 15", Plain)]);
+        check("**almost", &[("**almost", Plain)]);
+        check("a ` b *c*", &[("a ` b ", Plain), ("c", Emphasis)]);
+        check("*one* and *two", &[("one", Emphasis), (" and *two", Plain)]);
+        // A marker doesn't close on a later line
+        check("*first\nsecond*", &[("*first\nsecond*", Plain)]);
+        assert_eq!(plain("**a*"), "**a*");
+    }
+}
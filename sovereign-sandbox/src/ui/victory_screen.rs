@@ -1,6 +1,9 @@
 use bevy::prelude::*;
 use crate::GameState;
 use crate::syllabus::SyllabusResource;
+use crate::actions::{Action, Actions};
+use crate::narration::{Narrated, Priority};
+use crate::i18n::Locale;
 
 pub struct VictoryScreenPlugin;
 
@@ -15,7 +18,42 @@ impl Plugin for VictoryScreenPlugin {
 #[derive(Component)]
 struct VictoryScreenEntity;
 
-fn setup_victory_screen(mut commands: Commands) {
+fn setup_victory_screen(
+    mut commands: Commands,
+    syllabus: Option<Res<SyllabusResource>>,
+    tracker: Res<crate::mastery::MasteryTracker>,
+    actions: Res<Actions>,
+    results: Res<crate::assessment::TestResults>,
+    locale: Res<Locale>,
+) {
+    // Per-objective mastery summary (also exported by the mastery plugin)
+    let mastery_lines: Vec<String> = syllabus.iter()
+        .flat_map(|syl| syl.syllabus.objectives.iter())
+        .map(|o| {
+            let pct = tracker.get(&o.id).map(|m| m.mastery() * 100.0).unwrap_or(0.0);
+            let mark = if tracker.is_mastered(&o.id) { "☑" } else { "☐" };
+            format!("{} {} — {:.0}%", mark, o.text, pct)
+        })
+        .collect();
+
+    // Pre/post learning gain, only when both tests were given
+    let mut gain_lines = Vec::new();
+    if let (Some(pre), Some(post)) = (results.pretest_score(), results.posttest_score()) {
+        let gain = results.overall_gain()
+            .map(|g| format!("{:+.2}", g))
+            .unwrap_or_else(|| locale.t("victory-gain-na"));
+        gain_lines.push(locale.fmt("victory-gain", &[
+            ("pre", &format!("{:.0}", pre * 100.0)),
+            ("post", &format!("{:.0}", post * 100.0)),
+            ("gain", &gain),
+        ]));
+        for o in syllabus.iter().flat_map(|syl| syl.syllabus.objectives.iter()) {
+            if let Some(g) = results.objective_gain(&o.id) {
+                gain_lines.push(locale.fmt("victory-objective-gain", &[("objective", &o.text), ("gain", &format!("{:+.2}", g))]));
+            }
+        }
+    }
+
     commands.spawn((
         Node {
             width: Val::Percent(100.0),
@@ -31,49 +69,76 @@ fn setup_victory_screen(mut commands: Commands) {
     ))
     .with_children(|parent| {
         parent.spawn((
-            Text::new("MODULE COMPLETE"),
+            Text::new(locale.t("victory-title")),
             TextFont {
                 font_size: 60.0,
                 ..default()
             },
             TextColor(Color::srgb(0.5, 0.0, 1.0)), // Fuchsia/Violet
+            Narrated(Priority::Assertive),
         ));
         
         parent.spawn((
-            Text::new("You have successfully architected the Sovereign Sandbox.\nThe Textbook Trap has been dismantled."),
+            Text::new(locale.t("victory-body")),
             TextFont {
                 font_size: 24.0,
                 ..default()
             },
             TextColor(Color::srgb(0.8, 0.8, 0.8)),
             TextLayout::new_with_justify(JustifyText::Center),
+            Narrated::default(),
         ));
 
+        if !mastery_lines.is_empty() {
+            parent.spawn((
+                Text::new(mastery_lines.join("\n")),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(1.0, 0.9, 0.5)),
+                Narrated::default(),
+            ));
+        }
+
+        if !gain_lines.is_empty() {
+            parent.spawn((
+                Text::new(gain_lines.join("\n")),
+                TextFont {
+                    font_size: 16.0,
+                    ..default()
+                },
+                TextColor(Color::srgb(0.5, 0.9, 1.0)),
+                Narrated::default(),
+            ));
+        }
+
         parent.spawn((
-            Text::new("Press [ENTER] to Restart"),
+            Text::new(locale.fmt("victory-restart", &[("key", &actions.label(Action::Confirm))])),
             TextFont {
                 font_size: 16.0,
                 ..default()
             },
             TextColor(Color::srgb(0.4, 0.4, 0.4)),
+            Narrated::default(),
         ));
     });
 }
 
 fn handle_victory_input(
-    keys: Res<ButtonInput<KeyCode>>,
+    actions: Res<Actions>,
     mut next_state: ResMut<NextState<GameState>>,
     mut syllabus: Option<ResMut<SyllabusResource>>,
+    mut results: ResMut<crate::assessment::TestResults>,
+    mut mastery: ResMut<crate::mastery::MasteryTracker>,
 ) {
-    if keys.just_pressed(KeyCode::Enter) {
+    if actions.just_pressed(Action::Confirm) {
         // Reset syllabus progress
         if let Some(ref mut syl) = syllabus {
-            syl.current_module_index = 0;
-            syl.current_event_step = 0;
-            if let Some(first_quest) = syl.syllabus.modules.first() {
-                syl.quest_script = crate::syllabus::QuestScript::from_quest(first_quest);
-            }
+            syl.reset();
         }
+        results.clear();
+        mastery.clear();
         next_state.set(GameState::Menu); // Or Title Screen, we'll use Title Screen (Menu)
     }
 }
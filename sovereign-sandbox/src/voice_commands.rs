@@ -0,0 +1,403 @@
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use crate::actions::{Action, Actions, InputContext, OfferedChoices, VirtualInput, VirtualInputSet};
+use crate::command_grammar::{Candidates, Grammar, Intent, Kind, Slot, GRAMMAR};
+use crate::game_world::{ActiveMap, Player, TILE};
+use crate::i18n::Locale;
+use crate::inventory::{Inventory, ToolId};
+use crate::map::{MapData, MapRoom};
+use crate::narration::NarrationEvent;
+use crate::navigation::NavGrid;
+use crate::npc::Npc;
+use crate::story_mode::TypewriterState;
+
+// ============================================================================
+// Voice Commands — say or type what to do
+// ============================================================================
+// Text from speech (see `speech`) or from the command line (`/`) goes through
+// the grammar in `command_grammar` and comes out as the same actions the keys
+// press, through `VirtualInput`, so the input context still decides who gets
+// them. Besides pressing actions it can:
+//   * switch a tool by name ("open the compass", "hide the lens")
+//   * pick an offered option ("the second one", "answer recursion")
+//   * walk to a room or an NPC along an A* route ("take me to the archive"),
+//     steering the virtual stick; "talk to the archivist" interacts on arrival
+//   * repeat the line in the dialogue box
+// Walking stops when a movement key is pressed, another context takes input,
+// or the player stops getting closer to the next tile.
+
+/// Tools the grammar can switch
+const TOGGLEABLE: [ToolId; 3] = [ToolId::OllamaCompass, ToolId::LogicLens, ToolId::FeedbackMirror];
+
+/// Close enough to a waypoint to head for the next, in tiles
+const WAYPOINT_RADIUS: f32 = 0.3;
+
+/// Give up walking after this long without getting closer
+const STALL_SECS: f32 = 1.5;
+
+/// How long a command's outcome stays on screen
+const FEEDBACK_SECS: f32 = 3.0;
+
+/// A command to interpret, typed or spoken
+#[derive(Event, Debug, Clone)]
+pub struct CommandText(pub String);
+
+/// The command line, open while typing a command
+#[derive(Resource, Default)]
+pub struct CommandLine {
+    pub open: bool,
+    pub input: String,
+}
+
+#[derive(Resource)]
+struct VoiceGrammar(Grammar);
+
+/// A walk in progress, in tiles
+#[derive(Resource, Default)]
+struct Autopilot {
+    path: Vec<IVec2>,
+    /// Pressed on arrival
+    then: Option<Action>,
+    /// Closest the player has come to the next waypoint
+    closest: f32,
+    stalled: f32,
+}
+
+impl Autopilot {
+    fn stop(&mut self) {
+        self.path.clear();
+        self.then = None;
+    }
+}
+
+/// What became of the last command
+#[derive(Resource, Default)]
+struct CommandFeedback {
+    message: Option<String>,
+    timer: Timer,
+}
+
+impl CommandFeedback {
+    fn show(&mut self, message: String, narration: &mut EventWriter<NarrationEvent>) {
+        narration.send(NarrationEvent::polite(message.clone()));
+        self.message = Some(message);
+        self.timer = Timer::from_seconds(FEEDBACK_SECS, TimerMode::Once);
+    }
+}
+
+#[derive(Component)]
+struct CommandBar;
+
+type NpcQuery<'w, 's> = Query<'w, 's, (&'static Transform, &'static Npc)>;
+
+// ============================================================================
+// Plugin
+// ============================================================================
+
+pub struct VoiceCommandPlugin;
+
+impl Plugin for VoiceCommandPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_event::<CommandText>()
+           .init_resource::<CommandLine>()
+           .init_resource::<Autopilot>()
+           .init_resource::<CommandFeedback>()
+           .add_systems(Startup, (load_grammar, spawn_command_bar))
+           .add_systems(PreUpdate, (interpret_commands, drive_autopilot).chain().in_set(VirtualInputSet))
+           .add_systems(Update, (edit_command_line, update_command_bar).chain());
+    }
+}
+
+fn load_grammar(mut commands: Commands) {
+    let grammar = match Grammar::parse(GRAMMAR) {
+        Ok(grammar) => grammar,
+        Err(e) => {
+            error!("Voice command grammar unreadable: {}", e);
+            return;
+        }
+    };
+    for name in grammar.actions().filter(|name| action_named(name).is_none()) {
+        warn!("⚠️ Voice command grammar presses unknown action {:?}", name);
+    }
+    commands.insert_resource(VoiceGrammar(grammar));
+}
+
+/// An action by the name the bindings file uses, e.g. "Map"
+fn action_named(name: &str) -> Option<Action> {
+    serde_json::from_value(serde_json::Value::String(name.to_string())).ok()
+}
+
+// ============================================================================
+// Command Line — `/` opens it, Enter runs it, Esc closes it
+// ============================================================================
+
+fn edit_command_line(
+    mut key_events: EventReader<KeyboardInput>,
+    keys: Res<ButtonInput<KeyCode>>,
+    (actions, context): (Res<Actions>, Res<InputContext>),
+    mut line: ResMut<CommandLine>,
+    mut submitted: EventWriter<CommandText>,
+) {
+    if !line.open {
+        key_events.clear();
+        if *context == InputContext::World && actions.just_pressed(Action::Command) {
+            line.open = true;
+            line.input.clear();
+        }
+        return;
+    }
+
+    for event in key_events.read().filter(|e| e.state.is_pressed()) {
+        if let Key::Character(c) = &event.logical_key {
+            if c.chars().count() == 1 {
+                line.input.push_str(c.as_str());
+            }
+        }
+    }
+    if keys.just_pressed(KeyCode::Backspace) {
+        line.input.pop();
+    }
+
+    if actions.just_pressed(Action::Confirm) {
+        let input = std::mem::take(&mut line.input);
+        line.open = false;
+        if !input.trim().is_empty() {
+            submitted.send(CommandText(input));
+        }
+    } else if actions.just_pressed(Action::Cancel) {
+        line.open = false;
+        line.input.clear();
+    }
+}
+
+// ============================================================================
+// Interpreting
+// ============================================================================
+
+/// Everything a slot can name right now, with where each one is
+struct Surroundings {
+    candidates: Candidates,
+    rooms: Vec<MapRoom>,
+    npcs: Vec<Vec2>,
+}
+
+fn surroundings(
+    locale: &Locale,
+    offered: &OfferedChoices,
+    map: Option<&MapData>,
+    npcs: &NpcQuery,
+) -> Surroundings {
+    let tools = TOGGLEABLE.iter()
+        .map(|tool| vec![tool.name(locale), tool.key().trim_start_matches("tool-").replace('-', " ")])
+        .collect();
+    let rooms: Vec<MapRoom> = map.map(|m| m.rooms.clone()).unwrap_or_default();
+    Surroundings {
+        candidates: Candidates {
+            tools,
+            options: offered.0.clone(),
+            places: rooms.iter().map(|room| vec![room.name.clone()]).collect(),
+            npcs: npcs.iter().map(|(_, npc)| vec![npc.id.clone(), npc.name.clone()]).collect(),
+        },
+        rooms,
+        npcs: npcs.iter().map(|(tf, _)| tf.translation.truncate()).collect(),
+    }
+}
+
+fn interpret_commands(
+    mut texts: EventReader<CommandText>,
+    grammar: Option<Res<VoiceGrammar>>,
+    (locale, offered, inventory): (Res<Locale>, Res<OfferedChoices>, Res<Inventory>),
+    (active_map, maps): (Option<Res<ActiveMap>>, Res<Assets<MapData>>),
+    (nav_grid, player, npcs): (Res<NavGrid>, Query<&Transform, With<Player>>, NpcQuery),
+    (mut input, mut autopilot, mut typewriter): (ResMut<VirtualInput>, ResMut<Autopilot>, ResMut<TypewriterState>),
+    (mut feedback, mut narration): (ResMut<CommandFeedback>, EventWriter<NarrationEvent>),
+) {
+    let Some(grammar) = grammar else {
+        texts.clear();
+        return;
+    };
+    for CommandText(text) in texts.read() {
+        let map = active_map.as_ref().and_then(|m| m.data(&maps));
+        let around = surroundings(&locale, &offered, map, &npcs);
+        let Some(intent) = grammar.0.interpret(text, &around.candidates) else {
+            info!("🗣️ Not a command: {}", text);
+            feedback.show(locale.fmt("command-not-understood", &[("text", &text.trim())]), &mut narration);
+            continue;
+        };
+        info!("🗣️ {:?} ← {}", intent, text);
+
+        match intent {
+            Intent { kind: Kind::Tool, target: Some(i), active, .. } => {
+                let tool = TOGGLEABLE[i];
+                if !inventory.has_tool(tool) {
+                    feedback.show(locale.fmt("command-no-tool", &[("tool", &tool.name(&locale))]), &mut narration);
+                } else if active.is_none_or(|on| on != (inventory.active_tool == Some(tool))) {
+                    input.just_pressed.insert(Action::ToggleTool(tool));
+                }
+            }
+            Intent { kind: Kind::Choose, target: Some(i), .. } => {
+                input.just_pressed.insert(Action::Choice(i as u8));
+            }
+            Intent { kind: Kind::Press, action: Some(name), .. } => {
+                if let Some(action) = action_named(&name) {
+                    input.just_pressed.insert(action);
+                }
+            }
+            Intent { kind: kind @ (Kind::Walk | Kind::Talk), slot: Some(slot), target: Some(i), .. } => {
+                let Ok(player_tf) = player.get_single() else { continue };
+                let grid = &nav_grid.0;
+                let start = grid.world_to_tile(player_tf.translation.truncate());
+                let (goal, name) = match slot {
+                    Slot::Place => (open_tile_in(&around.rooms[i], grid), around.rooms[i].name.clone()),
+                    _ => (grid.world_to_tile(around.npcs[i]), around.candidates.npcs[i][1].clone()),
+                };
+                let Some(mut path) = grid.find_path(start, goal) else {
+                    feedback.show(locale.fmt("command-no-route", &[("place", &name)]), &mut narration);
+                    continue;
+                };
+                path.remove(0);
+                // Stop beside someone rather than on top of them
+                if slot == Slot::Npc {
+                    path.pop();
+                }
+                *autopilot = Autopilot {
+                    path,
+                    then: (kind == Kind::Talk).then_some(Action::Interact),
+                    closest: f32::INFINITY,
+                    stalled: 0.0,
+                };
+                feedback.show(locale.fmt("command-walking", &[("place", &name)]), &mut narration);
+            }
+            Intent { kind: Kind::Repeat, .. } => {
+                if typewriter.full_text.is_empty() { continue; }
+                typewriter.revealed_chars = 0;
+                typewriter.is_active = true;
+                typewriter.timer.reset();
+                // The same line again isn't narrated on its own
+                narration.send(NarrationEvent::assertive(typewriter.full_text.clone()));
+            }
+            intent => warn!("⚠️ Voice command without a target: {:?}", intent),
+        }
+    }
+}
+
+/// The walkable tile of `room` nearest its centre
+fn open_tile_in(room: &MapRoom, grid: &crate::spatial::TileGrid) -> IVec2 {
+    let center = room.center();
+    (0..room.size.x)
+        .flat_map(|x| (0..room.size.y).map(move |y| room.min + IVec2::new(x, y)))
+        .map(|tile| grid.world_to_tile(tile.as_vec2() * TILE))
+        .filter(|tile| !grid.is_solid(*tile))
+        .min_by(|a, b| a.as_vec2().distance_squared(center).total_cmp(&b.as_vec2().distance_squared(center)))
+        .unwrap_or_else(|| grid.world_to_tile(center * TILE))
+}
+
+// ============================================================================
+// Autopilot — walks the route by steering the virtual stick
+// ============================================================================
+
+fn drive_autopilot(
+    time: Res<Time>,
+    (actions, context): (Res<Actions>, Res<InputContext>),
+    (nav_grid, player): (Res<NavGrid>, Query<&Transform, With<Player>>),
+    locale: Res<Locale>,
+    mut autopilot: ResMut<Autopilot>,
+    mut input: ResMut<VirtualInput>,
+    (mut feedback, mut narration): (ResMut<CommandFeedback>, EventWriter<NarrationEvent>),
+) {
+    if autopilot.path.is_empty() && autopilot.then.is_none() { return; }
+
+    let took_over = [Action::MoveUp, Action::MoveDown, Action::MoveLeft, Action::MoveRight, Action::Cancel]
+        .into_iter()
+        .any(|action| actions.pressed(action));
+    let Ok(player_tf) = player.get_single() else { return };
+    if took_over || *context != InputContext::World {
+        autopilot.stop();
+        return;
+    }
+
+    let grid = &nav_grid.0;
+    let position = player_tf.translation.truncate();
+    while let Some(next) = autopilot.path.first() {
+        if position.distance(grid.tile_to_world(*next)) > WAYPOINT_RADIUS * grid.tile_size() { break; }
+        autopilot.path.remove(0);
+        autopilot.closest = f32::INFINITY;
+        autopilot.stalled = 0.0;
+    }
+    let Some(next) = autopilot.path.first().map(|t| grid.tile_to_world(*t)) else {
+        if let Some(action) = autopilot.then.take() {
+            input.just_pressed.insert(action);
+        }
+        return;
+    };
+
+    let distance = position.distance(next);
+    if distance < autopilot.closest - 1.0 {
+        autopilot.closest = distance;
+        autopilot.stalled = 0.0;
+    } else {
+        autopilot.stalled += time.delta_secs();
+        if autopilot.stalled > STALL_SECS {
+            autopilot.stop();
+            feedback.show(locale.t("command-stuck"), &mut narration);
+            return;
+        }
+    }
+    input.stick = (next - position).normalize_or_zero();
+}
+
+// ============================================================================
+// Command Bar — above the dialogue box, while typing and just after
+// ============================================================================
+
+fn spawn_command_bar(mut commands: Commands) {
+    commands.spawn((
+        Node {
+            position_type: PositionType::Absolute,
+            bottom: Val::Px(300.0),
+            width: Val::Percent(100.0),
+            justify_content: JustifyContent::Center,
+            ..default()
+        },
+        PickingBehavior::IGNORE,
+    )).with_children(|parent| {
+        parent.spawn((
+            Text::new(""),
+            TextFont { font_size: 16.0, ..default() },
+            TextColor(Color::srgb(0.0, 1.0, 0.6)),
+            Node { padding: UiRect::axes(Val::Px(12.0), Val::Px(6.0)), ..default() },
+            BackgroundColor(Color::srgba(0.05, 0.05, 0.05, 0.85)),
+            Visibility::Hidden,
+            CommandBar,
+        ));
+    });
+}
+
+fn update_command_bar(
+    time: Res<Time<Real>>,
+    locale: Res<Locale>,
+    line: Res<CommandLine>,
+    mut feedback: ResMut<CommandFeedback>,
+    mut bar: Query<(&mut Text, &mut Visibility), With<CommandBar>>,
+) {
+    if feedback.timer.tick(time.delta()).just_finished() {
+        feedback.message = None;
+    }
+    let shown = if line.open {
+        Some(format!("{}\n/ {}▌", locale.t("command-prompt"), line.input))
+    } else {
+        feedback.message.clone()
+    };
+
+    for (mut text, mut visibility) in &mut bar {
+        let wanted = if shown.is_some() { Visibility::Inherited } else { Visibility::Hidden };
+        if *visibility != wanted {
+            *visibility = wanted;
+        }
+        if let Some(shown) = &shown {
+            if text.0 != *shown {
+                text.0 = shown.clone();
+            }
+        }
+    }
+}